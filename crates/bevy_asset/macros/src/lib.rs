@@ -5,7 +5,7 @@
 use bevy_macro_utils::BevyManifest;
 use proc_macro::{Span, TokenStream};
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, Data, DeriveInput, Path};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Path};
 
 pub(crate) fn bevy_asset_path() -> Path {
     BevyManifest::default().get_path("bevy_asset")
@@ -123,3 +123,135 @@ fn derive_dependency_visitor_internal(
         }
     })
 }
+
+const ASSET_ATTRIBUTE: &str = "asset";
+
+/// How a field of an `AssetCollection` is loaded.
+enum CollectionField {
+    Path(syn::LitStr),
+    /// A folder, with the `LoadFolderSettings` builder calls of its filters.
+    Folder(syn::LitStr, Vec<proc_macro2::TokenStream>),
+}
+
+fn parse_collection_field(field: &syn::Field) -> Result<Option<CollectionField>, syn::Error> {
+    let mut path = None;
+    let mut folder = None;
+    let mut folder_settings = Vec::new();
+    let mut settings_span = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident(ASSET_ATTRIBUTE))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("path") || meta.path.is_ident("folder") {
+                if path.is_some() || folder.is_some() {
+                    return Err(meta.error("an asset can only have one `path` or `folder`"));
+                }
+                let value: syn::LitStr = meta.value()?.parse()?;
+                if meta.path.is_ident("path") {
+                    path = Some(value);
+                } else {
+                    folder = Some(value);
+                }
+                return Ok(());
+            }
+            if meta.path.is_ident("glob") {
+                let glob: syn::LitStr = meta.value()?.parse()?;
+                folder_settings.push(quote!(.with_glob(#glob)));
+            } else if meta.path.is_ident("extension") {
+                let extension: syn::LitStr = meta.value()?.parse()?;
+                folder_settings.push(quote!(.with_extension(#extension)));
+            } else if meta.path.is_ident("recursive") {
+                let recursive: syn::LitBool = meta.value()?.parse()?;
+                folder_settings.push(quote!(.with_recursive(#recursive)));
+            } else {
+                return Err(
+                    meta.error("expected `path`, `folder`, `glob`, `extension` or `recursive`")
+                );
+            }
+            settings_span.get_or_insert(meta.path.span());
+            Ok(())
+        })?;
+    }
+    match (path, folder, settings_span) {
+        (_, Some(folder), _) => Ok(Some(CollectionField::Folder(folder, folder_settings))),
+        (_, None, Some(span)) => Err(syn::Error::new(
+            span,
+            "`glob`, `extension` and `recursive` can only be used with `folder`",
+        )),
+        (Some(path), None, None) => Ok(Some(CollectionField::Path(path))),
+        (None, None, None) => Ok(None),
+    }
+}
+
+#[proc_macro_derive(AssetCollection, attributes(asset))]
+pub fn derive_asset_collection(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let bevy_asset_path: Path = bevy_asset_path();
+    match derive_asset_collection_internal(&ast, &bevy_asset_path) {
+        Ok(asset_collection) => TokenStream::from(asset_collection),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn derive_asset_collection_internal(
+    ast: &DeriveInput,
+    bevy_asset_path: &Path,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let Data::Struct(data_struct) = &ast.data else {
+        return Err(syn::Error::new(
+            Span::call_site().into(),
+            "AssetCollection derive only works on structs",
+        ));
+    };
+
+    let mut field_loaders = Vec::new();
+    let mut field_visitors = Vec::new();
+    for (i, field) in data_struct.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index::from(i)),
+        };
+        let loader = match parse_collection_field(field)? {
+            Some(CollectionField::Path(path)) => quote!(asset_server.load(#path)),
+            Some(CollectionField::Folder(path, settings)) if settings.is_empty() => {
+                quote!(asset_server.load_folder(#path))
+            }
+            Some(CollectionField::Folder(path, settings)) => quote! {
+                asset_server.load_folder_with_settings(
+                    #path,
+                    #bevy_asset_path::LoadFolderSettings::default() #(#settings)*,
+                )
+            },
+            None => {
+                field_loaders.push(quote!(#member: ::core::default::Default::default()));
+                continue;
+            }
+        };
+        field_loaders.push(quote!(#member: #loader));
+        field_visitors.push(quote!(visit(#bevy_asset_path::UntypedAssetId::from(&self.#member));));
+    }
+
+    // prevent unused variable warnings in case there are no assets
+    let (asset_server, visit) = if field_visitors.is_empty() {
+        (quote! { _asset_server }, quote! { _visit })
+    } else {
+        (quote! { asset_server }, quote! { visit })
+    };
+
+    Ok(quote! {
+        impl #impl_generics #bevy_asset_path::AssetCollection for #struct_name #type_generics #where_clause {
+            fn load(#asset_server: &#bevy_asset_path::AssetServer) -> Self {
+                Self { #(#field_loaders,)* }
+            }
+
+            fn visit_assets(&self, #visit: &mut impl FnMut(#bevy_asset_path::UntypedAssetId)) {
+                #(#field_visitors)*
+            }
+        }
+    })
+}
@@ -0,0 +1,188 @@
+use crate::{AssetLoadError, AssetPath, AssetServer, RecursiveDependencyLoadState, UntypedAssetId};
+use alloc::sync::Arc;
+use bevy_ecs::prelude::*;
+use core::marker::PhantomData;
+
+/// A group of assets that is loaded together, such as all of the assets needed by a level.
+///
+/// This is usually derived on a struct of [`Handle`](crate::Handle) fields, each annotated with
+/// the path to load it from:
+///
+/// ```
+/// # use bevy_asset::{AssetCollection, Handle, LoadedFolder};
+/// # use bevy_ecs::prelude::*;
+/// # #[derive(bevy_asset::Asset, bevy_reflect::TypePath)]
+/// # struct Image;
+/// #[derive(AssetCollection, Resource)]
+/// struct GameAssets {
+///     #[asset(path = "player.png")]
+///     player: Handle<Image>,
+///     /// Loads all of the assets in the `levels` folder.
+///     #[asset(folder = "levels")]
+///     levels: Handle<LoadedFolder>,
+///     /// Folders can be filtered with `glob`, `extension` and `recursive`, which can be repeated.
+///     /// See `LoadFolderSettings`.
+///     #[asset(folder = "ui", glob = "icon_*", extension = "png", recursive = false)]
+///     icons: Handle<LoadedFolder>,
+///     /// Fields without an `asset` attribute are set to their default value and ignored.
+///     selected_level: usize,
+/// }
+/// ```
+///
+/// Registering the collection with [`AssetApp::init_asset_collection`](crate::AssetApp::init_asset_collection)
+/// starts loading it when the app starts, inserts it as a resource, and sends an
+/// [`AssetCollectionLoaded`] event once all of its assets are done loading. The loading progress
+/// can be queried at any time with [`AssetCollection::progress`].
+pub trait AssetCollection: Send + Sync + Sized + 'static {
+    /// Starts loading all of the assets of the collection with the given `asset_server`.
+    fn load(asset_server: &AssetServer) -> Self;
+
+    /// Calls `visit` with the id of each asset of the collection.
+    fn visit_assets(&self, visit: &mut impl FnMut(UntypedAssetId));
+
+    /// Returns the loading progress of the assets of the collection, including their recursive
+    /// dependencies and, for folders, the assets of the folder.
+    fn progress(&self, asset_server: &AssetServer) -> AssetCollectionProgress {
+        let mut progress = AssetCollectionProgress::default();
+        self.visit_assets(&mut |id| {
+            progress.total += 1;
+            match asset_server.recursive_dependency_load_state(id) {
+                RecursiveDependencyLoadState::Loaded => progress.loaded += 1,
+                RecursiveDependencyLoadState::Failed(error) => {
+                    progress.failures.push(AssetCollectionFailure {
+                        id,
+                        path: asset_server.get_path(id).map(AssetPath::into_owned),
+                        error,
+                    });
+                }
+                RecursiveDependencyLoadState::NotLoaded | RecursiveDependencyLoadState::Loading => {
+                }
+            }
+        });
+        progress
+    }
+}
+
+/// The loading progress of an [`AssetCollection`], returned by [`AssetCollection::progress`].
+#[derive(Clone, Debug, Default)]
+pub struct AssetCollectionProgress {
+    /// The number of assets in the collection.
+    pub total: usize,
+    /// The number of assets that have been loaded along with all of their dependencies.
+    pub loaded: usize,
+    /// The assets that failed to load, or that have a dependency that failed to load.
+    pub failures: Vec<AssetCollectionFailure>,
+}
+
+impl AssetCollectionProgress {
+    /// Returns the number of assets that failed to load.
+    pub fn failed(&self) -> usize {
+        self.failures.len()
+    }
+
+    /// Returns `true` if all of the assets are done loading, successfully or not.
+    pub fn is_finished(&self) -> bool {
+        self.loaded + self.failed() == self.total
+    }
+
+    /// Returns `true` if all of the assets have been loaded successfully.
+    pub fn is_loaded(&self) -> bool {
+        self.loaded == self.total
+    }
+
+    /// Returns the fraction of the assets that are done loading, between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        (self.loaded + self.failed()) as f32 / self.total as f32
+    }
+}
+
+/// An asset of an [`AssetCollection`] that failed to load.
+#[derive(Clone, Debug)]
+pub struct AssetCollectionFailure {
+    /// The id of the asset of the collection.
+    pub id: UntypedAssetId,
+    /// The path the asset was loaded from.
+    pub path: Option<AssetPath<'static>>,
+    /// The error of the asset, or of the dependency of the asset that failed to load.
+    pub error: Arc<AssetLoadError>,
+}
+
+/// An event sent when all of the assets of the [`AssetCollection`] `C` are done loading, registered
+/// with [`AssetApp::init_asset_collection`](crate::AssetApp::init_asset_collection).
+///
+/// It is sent again whenever the assets of the `C` resource change and are done loading. Changes to
+/// the other fields of the collection don't send it again.
+#[derive(Event)]
+pub struct AssetCollectionLoaded<C: AssetCollection> {
+    /// The final progress of the collection, with the assets that failed to load.
+    pub progress: AssetCollectionProgress,
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C: AssetCollection> Clone for AssetCollectionLoaded<C> {
+    fn clone(&self) -> Self {
+        Self {
+            progress: self.progress.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C: AssetCollection> core::fmt::Debug for AssetCollectionLoaded<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AssetCollectionLoaded")
+            .field("progress", &self.progress)
+            .finish()
+    }
+}
+
+impl<C: AssetCollection> AssetCollectionLoaded<C> {
+    /// Returns `true` if all of the assets of the collection have been loaded successfully.
+    pub fn is_loaded(&self) -> bool {
+        self.progress.is_loaded()
+    }
+}
+
+/// Starts loading the [`AssetCollection`] `C` and inserts it as a resource.
+pub(crate) fn load_asset_collection<C: AssetCollection + Resource>(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.insert_resource(C::load(&asset_server));
+}
+
+/// Sends an [`AssetCollectionLoaded`] event once the assets of the [`AssetCollection`] `C` are done
+/// loading.
+pub(crate) fn track_asset_collection<C: AssetCollection + Resource>(
+    collection: Option<Res<C>>,
+    asset_server: Res<AssetServer>,
+    mut tracked: Local<Vec<UntypedAssetId>>,
+    mut finished: Local<bool>,
+    mut events: EventWriter<AssetCollectionLoaded<C>>,
+) {
+    let Some(collection) = collection else {
+        return;
+    };
+    if collection.is_changed() {
+        let mut ids = Vec::new();
+        collection.visit_assets(&mut |id| ids.push(id));
+        if ids != *tracked {
+            *tracked = ids;
+            *finished = false;
+        }
+    }
+    if *finished {
+        return;
+    }
+    let progress = collection.progress(&asset_server);
+    if progress.is_finished() {
+        *finished = true;
+        events.send(AssetCollectionLoaded {
+            progress,
+            marker: PhantomData,
+        });
+    }
+}
@@ -1,6 +1,8 @@
 use crate as bevy_asset;
-use crate::{Asset, UntypedHandle};
+use crate::{Asset, AssetPath, UntypedHandle};
 use bevy_reflect::TypePath;
+use core::any::TypeId;
+use std::path::Path;
 
 /// A "loaded folder" containing handles for all assets stored in a given [`AssetPath`].
 ///
@@ -10,3 +12,195 @@ pub struct LoadedFolder {
     #[dependency]
     pub handles: Vec<UntypedHandle>,
 }
+
+/// Settings that filter which files of a folder are loaded by
+/// [`AssetServer::load_folder_with_settings`](crate::AssetServer::load_folder_with_settings).
+///
+/// By default, all files of the folder and its subfolders that have an [`AssetLoader`](crate::AssetLoader)
+/// are loaded. A file is only loaded if it passes every filter that is set:
+///
+/// ```
+/// # use bevy_asset::LoadFolderSettings;
+/// // all `.png` and `.jpg` files directly in the folder, except for the ones starting with `_`
+/// let settings = LoadFolderSettings::default()
+///     .with_recursive(false)
+///     .with_glob("[!_]*")
+///     .with_extension("png")
+///     .with_extension("jpg");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LoadFolderSettings {
+    /// Whether the files of subfolders are loaded. Defaults to `true`.
+    pub recursive: bool,
+    /// Glob patterns matched against the paths of files relative to the loaded folder, using `/`
+    /// as a separator. If not empty, only files matching one of the patterns are loaded.
+    ///
+    /// `?` matches any character but `/`, `*` matches any number of characters but `/`, `**/`
+    /// matches any number of folders, and `[abc]`, `[a-z]` and `[!abc]` match one of (or none of)
+    /// the given characters.
+    pub globs: Vec<String>,
+    /// File extensions, without the leading `.`. If not empty, only files with one of the
+    /// extensions are loaded. Extensions made of multiple parts, like `cool.ron`, are supported.
+    pub extensions: Vec<String>,
+    /// The [`TypeId`]s of [`Asset`] types. If not empty, only files whose loader produces one of
+    /// these types are loaded.
+    pub asset_types: Vec<TypeId>,
+}
+
+impl Default for LoadFolderSettings {
+    fn default() -> Self {
+        Self {
+            recursive: true,
+            globs: Vec::new(),
+            extensions: Vec::new(),
+            asset_types: Vec::new(),
+        }
+    }
+}
+
+impl LoadFolderSettings {
+    /// Sets whether the files of subfolders are loaded.
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Only loads files matching the given glob `pattern`, or one of the other globs.
+    /// See [`LoadFolderSettings::globs`].
+    pub fn with_glob(mut self, pattern: impl Into<String>) -> Self {
+        self.globs.push(pattern.into());
+        self
+    }
+
+    /// Only loads files with the given `extension`, or one of the other extensions.
+    pub fn with_extension(mut self, extension: impl Into<String>) -> Self {
+        self.extensions.push(extension.into());
+        self
+    }
+
+    /// Only loads files containing assets of type `A`, or one of the other asset types.
+    pub fn with_asset_type<A: Asset>(mut self) -> Self {
+        self.asset_types.push(TypeId::of::<A>());
+        self
+    }
+
+    /// Returns `true` if the file at `path`, relative to the loaded folder, passes the glob and
+    /// extension filters.
+    pub(crate) fn matches_file(&self, path: &Path) -> bool {
+        if !self.recursive && path.components().count() > 1 {
+            return false;
+        }
+        if !self.globs.is_empty() {
+            let path = path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/")
+                .chars()
+                .collect::<Vec<_>>();
+            let matches = |glob: &String| glob_matches(&glob.chars().collect::<Vec<_>>(), &path);
+            if !self.globs.iter().any(matches) {
+                return false;
+            }
+        }
+        if !self.extensions.is_empty() {
+            let Some(full_extension) = path
+                .file_name()
+                .and_then(|name| AssetPath::from_path(Path::new(name)).get_full_extension())
+            else {
+                return false;
+            };
+            let mut extensions = core::iter::once(full_extension.as_str())
+                .chain(AssetPath::iter_secondary_extensions(&full_extension));
+            if !extensions.any(|extension| self.extensions.iter().any(|e| e == extension)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Returns `true` if assets of the given type pass the asset type filter.
+    pub(crate) fn matches_asset_type(&self, type_id: TypeId) -> bool {
+        self.asset_types.is_empty() || self.asset_types.contains(&type_id)
+    }
+}
+
+fn glob_matches(pattern: &[char], path: &[char]) -> bool {
+    match pattern {
+        [] => path.is_empty(),
+        ['*', '*', '/', rest @ ..] => {
+            glob_matches(rest, path)
+                || path
+                    .iter()
+                    .enumerate()
+                    .any(|(i, c)| *c == '/' && glob_matches(rest, &path[i + 1..]))
+        }
+        ['*', '*', rest @ ..] => (0..=path.len()).any(|i| glob_matches(rest, &path[i..])),
+        ['*', rest @ ..] => (0..=path.len())
+            .take_while(|&i| i == 0 || path[i - 1] != '/')
+            .any(|i| glob_matches(rest, &path[i..])),
+        ['?', rest @ ..] => {
+            matches!(path.first(), Some(c) if *c != '/') && glob_matches(rest, &path[1..])
+        }
+        ['[', rest @ ..] => {
+            let Some(end) = rest.iter().skip(1).position(|c| *c == ']').map(|i| i + 1) else {
+                return path.first() == Some(&'[') && glob_matches(rest, &path[1..]);
+            };
+            let Some(c) = path.first().filter(|c| **c != '/') else {
+                return false;
+            };
+            let (negated, class) = match &rest[..end] {
+                ['!', class @ ..] => (true, class),
+                class => (false, class),
+            };
+            let mut matches = false;
+            let mut i = 0;
+            while i < class.len() {
+                if i + 2 < class.len() && class[i + 1] == '-' {
+                    matches |= (class[i]..=class[i + 2]).contains(c);
+                    i += 3;
+                } else {
+                    matches |= class[i] == *c;
+                    i += 1;
+                }
+            }
+            matches != negated && glob_matches(&rest[end + 1..], &path[1..])
+        }
+        [c, rest @ ..] => path.first() == Some(c) && glob_matches(rest, &path[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LoadFolderSettings;
+    use std::path::Path;
+
+    #[test]
+    fn matches_file() {
+        let matches =
+            |settings: &LoadFolderSettings, path: &str| settings.matches_file(Path::new(path));
+
+        let all = LoadFolderSettings::default();
+        assert!(matches(&all, "a.png"));
+        assert!(matches(&all, "sub/a.png"));
+        assert!(!matches(&all.clone().with_recursive(false), "sub/a.png"));
+
+        let globs = LoadFolderSettings::default()
+            .with_glob("*.png")
+            .with_glob("levels/**/[0-9]?.ron");
+        assert!(matches(&globs, "a.png"));
+        assert!(!matches(&globs, "sub/a.png"));
+        assert!(matches(&globs, "levels/1a.ron"));
+        assert!(matches(&globs, "levels/world/2b.ron"));
+        assert!(!matches(&globs, "levels/ab.ron"));
+        assert!(!matches(&globs, "levels/1/.ron"));
+
+        let extensions = LoadFolderSettings::default()
+            .with_glob("[!_]*")
+            .with_extension("ron");
+        assert!(matches(&extensions, "a.cool.ron"));
+        assert!(!matches(&extensions, "_a.cool.ron"));
+        assert!(!matches(&extensions, "a.png"));
+        assert!(!matches(&extensions, "ron"));
+    }
+}
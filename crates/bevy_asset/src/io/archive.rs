@@ -0,0 +1,760 @@
+//! Packing assets into a single archive file, and reading them back with an [`AssetReader`].
+//!
+//! Shipping thousands of loose files is slow to install and to scan. An asset archive stores the
+//! bytes of every asset and asset meta of a source in one file, along with an index of their
+//! locations. Archives are typically written from the output of the
+//! [`AssetProcessor`](crate::processor::AssetProcessor) with
+//! [`AssetProcessor::write_archive`](crate::processor::AssetProcessor::write_archive), and read
+//! in release builds by registering an [`AssetSourceBuilder::archive`] source.
+//!
+//! # Format
+//!
+//! All integers are little-endian.
+//!
+//! | Section | Contents                                                                      |
+//! |---------|-------------------------------------------------------------------------------|
+//! | Header  | [`ARCHIVE_MAGIC`], version (`u32`), alignment (`u32`)                            |
+//! | Data    | The (optionally compressed) bytes of each entry, each starting at an offset that is a multiple of the alignment |
+//! | Index   | The number of entries (`u32`), followed by each entry's kind (`u8`), compression (`u8`), path length (`u32`), UTF-8 path, offset, stored length and uncompressed length (`u64`s) |
+//! | Trailer | Index offset and length (`u64`s)                                               |
+//!
+//! The index is located by the trailer so that archives can be written in a single pass.
+//!
+//! Paths are stored relative to the root of the source, with `/` separators.
+//!
+//! [`AssetSourceBuilder::archive`]: crate::io::AssetSourceBuilder::archive
+
+use crate::io::{
+    memory::Value, AssetReader, AssetReaderError, ErasedAssetReader, MissingAssetSourceError,
+    MissingProcessedAssetReaderError, PathStream, Reader, SliceReader, VecReader,
+};
+use alloc::sync::Arc;
+use async_lock::OnceCell;
+use bevy_utils::{HashMap, HashSet};
+use derive_more::derive::{Display, Error, From};
+use futures_io::AsyncWrite;
+use futures_lite::{AsyncWriteExt, StreamExt};
+use std::path::{Path, PathBuf};
+
+/// The bytes every asset archive starts with.
+pub const ARCHIVE_MAGIC: [u8; 8] = *b"BEVYPAK\0";
+
+/// The version of the archive format written by [`AssetArchiveWriter`].
+pub const ARCHIVE_VERSION: u32 = 1;
+
+/// The size of the archive header, in bytes.
+const HEADER_LEN: u64 = 16;
+
+/// The size of the archive trailer, in bytes.
+const TRAILER_LEN: u64 = 16;
+
+/// The zeros written between entries to align them.
+const PADDING: [u8; 64] = [0; 64];
+
+/// How the bytes of an archive entry are stored.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ArchiveCompression {
+    /// The bytes are stored as-is.
+    #[default]
+    None,
+    /// The bytes are compressed with DEFLATE.
+    Deflate,
+}
+
+impl ArchiveCompression {
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Deflate => 1,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::None),
+            1 => Some(Self::Deflate),
+            _ => None,
+        }
+    }
+}
+
+/// Whether an archive entry holds the bytes of an asset or of its meta.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ArchiveEntryKind {
+    /// The bytes of an asset.
+    Asset,
+    /// The bytes of an asset's meta.
+    Meta,
+}
+
+/// Settings used by [`AssetArchiveWriter`] to store entries.
+#[derive(Clone, Debug)]
+pub struct AssetArchiveSettings {
+    /// The compression applied to entries by default.
+    ///
+    /// Entries that don't get smaller when compressed are stored uncompressed.
+    pub compression: ArchiveCompression,
+    /// The compression level, from 0 (fastest) to 10 (smallest).
+    pub compression_level: u8,
+    /// The alignment of the offset of each entry's bytes within the archive. Must be a power of two.
+    ///
+    /// Aligned uncompressed entries can be handed out without copying by readers that map or hold the
+    /// whole archive in memory.
+    pub alignment: u32,
+}
+
+impl Default for AssetArchiveSettings {
+    fn default() -> Self {
+        Self {
+            compression: ArchiveCompression::Deflate,
+            compression_level: 6,
+            alignment: 16,
+        }
+    }
+}
+
+/// An error that occurs while writing or reading an asset archive.
+#[derive(Error, Display, Debug, From)]
+pub enum AssetArchiveError {
+    /// Encountered an I/O error.
+    #[display("Encountered an I/O error: {_0}")]
+    Io(std::io::Error),
+    /// Failed to read the assets to pack.
+    #[display("Failed to read assets to pack: {_0}")]
+    AssetReader(AssetReaderError),
+    /// The asset source to pack doesn't exist.
+    #[display("{_0}")]
+    MissingAssetSource(MissingAssetSourceError),
+    /// The asset source to pack has no processed [`AssetReader`].
+    #[display("{_0}")]
+    MissingProcessedAssetReader(MissingProcessedAssetReaderError),
+    /// The data doesn't start with [`ARCHIVE_MAGIC`].
+    #[display("Not an asset archive")]
+    InvalidMagic,
+    /// The archive was written with an unknown version of the format.
+    #[display("Unsupported asset archive version {_0}")]
+    #[error(ignore)]
+    #[from(ignore)]
+    UnsupportedVersion(u32),
+    /// The archive is truncated or otherwise malformed.
+    #[display("Corrupt asset archive: {_0}")]
+    #[error(ignore)]
+    #[from(ignore)]
+    Corrupt(&'static str),
+    /// An entry couldn't be decompressed.
+    #[display("Failed to decompress `{}`", _0.display())]
+    #[error(ignore)]
+    #[from(ignore)]
+    Decompress(PathBuf),
+}
+
+impl From<AssetArchiveError> for AssetReaderError {
+    fn from(value: AssetArchiveError) -> Self {
+        match value {
+            AssetArchiveError::Io(error) => error.into(),
+            AssetArchiveError::AssetReader(error) => error,
+            error => std::io::Error::new(std::io::ErrorKind::InvalidData, error.to_string()).into(),
+        }
+    }
+}
+
+/// Writes an asset archive from the bytes of assets and asset metas.
+///
+/// Entries are compressed and written to the output as they are added, so that only the index is
+/// kept in memory. The archive is complete once [`finish`](Self::finish) has written the index.
+///
+/// ```
+/// # use bevy_asset::io::archive::{ArchiveAssetReader, AssetArchiveSettings, AssetArchiveWriter};
+/// # use bevy_asset::io::{AssetReader, Reader};
+/// # use std::path::Path;
+/// # bevy_tasks::block_on(async {
+/// let mut writer = AssetArchiveWriter::new(Vec::new(), AssetArchiveSettings::default());
+/// writer.add_asset("textures/player.png", b"...").await.unwrap();
+/// let archive = writer.finish().await.unwrap();
+///
+/// let reader = ArchiveAssetReader::from_bytes(archive);
+/// let mut bytes = Vec::new();
+/// let mut asset = reader.read(Path::new("textures/player.png")).await.unwrap();
+/// asset.read_to_end(&mut bytes).await.unwrap();
+/// assert_eq!(bytes, b"...");
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct AssetArchiveWriter<W> {
+    writer: W,
+    settings: AssetArchiveSettings,
+    /// The number of bytes written so far.
+    position: u64,
+    /// The number of entries written so far.
+    entries: u32,
+    /// The index entries of the entries written so far.
+    index: Vec<u8>,
+}
+
+impl<W: AsyncWrite + Unpin> AssetArchiveWriter<W> {
+    /// Creates an archive writer storing its entries in `writer` with the given `settings`.
+    pub fn new(writer: W, settings: AssetArchiveSettings) -> Self {
+        assert!(
+            settings.alignment.is_power_of_two(),
+            "asset archive alignment must be a power of two"
+        );
+        Self {
+            writer,
+            settings,
+            position: 0,
+            entries: 0,
+            index: Vec::new(),
+        }
+    }
+
+    /// Adds the `bytes` of the asset at `path`.
+    pub async fn add_asset(
+        &mut self,
+        path: impl AsRef<Path>,
+        bytes: &[u8],
+    ) -> Result<(), AssetArchiveError> {
+        self.add_entry(ArchiveEntryKind::Asset, path, None, bytes)
+            .await
+    }
+
+    /// Adds the `bytes` of the meta of the asset at `path`.
+    pub async fn add_meta(
+        &mut self,
+        path: impl AsRef<Path>,
+        bytes: &[u8],
+    ) -> Result<(), AssetArchiveError> {
+        self.add_entry(ArchiveEntryKind::Meta, path, None, bytes)
+            .await
+    }
+
+    /// Adds an entry, overriding the default compression of the settings if `compression` is set.
+    pub async fn add_entry(
+        &mut self,
+        kind: ArchiveEntryKind,
+        path: impl AsRef<Path>,
+        compression: Option<ArchiveCompression>,
+        bytes: &[u8],
+    ) -> Result<(), AssetArchiveError> {
+        let compressed = match compression.unwrap_or(self.settings.compression) {
+            ArchiveCompression::None => None,
+            ArchiveCompression::Deflate => Some(miniz_oxide::deflate::compress_to_vec(
+                bytes,
+                self.settings.compression_level,
+            ))
+            .filter(|compressed| compressed.len() < bytes.len()),
+        };
+        let (compression, stored) = match &compressed {
+            Some(compressed) => (ArchiveCompression::Deflate, compressed.as_slice()),
+            None => (ArchiveCompression::None, bytes),
+        };
+
+        self.write_header().await?;
+        let offset = self
+            .position
+            .next_multiple_of(u64::from(self.settings.alignment));
+        while self.position < offset {
+            let len = (offset - self.position).min(PADDING.len() as u64);
+            self.write(&PADDING[..len as usize]).await?;
+        }
+        self.write(stored).await?;
+
+        let path = archive_path(path.as_ref());
+        self.index.push(match kind {
+            ArchiveEntryKind::Asset => 0,
+            ArchiveEntryKind::Meta => 1,
+        });
+        self.index.push(compression.to_byte());
+        self.index
+            .extend_from_slice(&(path.len() as u32).to_le_bytes());
+        self.index.extend_from_slice(path.as_bytes());
+        self.index.extend_from_slice(&offset.to_le_bytes());
+        self.index
+            .extend_from_slice(&(stored.len() as u64).to_le_bytes());
+        self.index
+            .extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+        self.entries += 1;
+        Ok(())
+    }
+
+    /// Adds every asset and asset meta found in the directory at `path` of `reader`, recursively.
+    pub async fn add_directory(
+        &mut self,
+        reader: &dyn ErasedAssetReader,
+        path: &Path,
+    ) -> Result<(), AssetArchiveError> {
+        if reader.is_directory(path).await? {
+            let mut paths = reader.read_directory(path).await?;
+            while let Some(child_path) = paths.next().await {
+                Box::pin(self.add_directory(reader, &child_path)).await?;
+            }
+            return Ok(());
+        }
+
+        let mut bytes = Vec::new();
+        reader.read(path).await?.read_to_end(&mut bytes).await?;
+        self.add_asset(path, &bytes).await?;
+        match reader.read_meta_bytes(path).await {
+            Ok(meta) => self.add_meta(path, &meta).await,
+            Err(AssetReaderError::NotFound(_)) => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Writes the index of the archive, completing it, and returns the underlying writer.
+    pub async fn finish(mut self) -> Result<W, AssetArchiveError> {
+        self.write_header().await?;
+        let offset = self.position;
+        let index = core::mem::take(&mut self.index);
+        self.write(&self.entries.to_le_bytes()).await?;
+        self.write(&index).await?;
+        let len = self.position - offset;
+        self.write(&offset.to_le_bytes()).await?;
+        self.write(&len.to_le_bytes()).await?;
+        self.writer.flush().await?;
+        Ok(self.writer)
+    }
+
+    /// Writes the header of the archive, if it wasn't written yet.
+    async fn write_header(&mut self) -> Result<(), AssetArchiveError> {
+        if self.position == 0 {
+            self.write(&ARCHIVE_MAGIC).await?;
+            self.write(&ARCHIVE_VERSION.to_le_bytes()).await?;
+            self.write(&self.settings.alignment.to_le_bytes()).await?;
+        }
+        Ok(())
+    }
+
+    async fn write(&mut self, bytes: &[u8]) -> Result<(), AssetArchiveError> {
+        self.writer.write_all(bytes).await?;
+        self.position += bytes.len() as u64;
+        Ok(())
+    }
+}
+
+/// Converts `path` to the `/`-separated form stored in archives.
+fn archive_path(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// The location of an entry's bytes within an archive.
+#[derive(Copy, Clone, Debug)]
+struct ArchiveEntry {
+    compression: ArchiveCompression,
+    offset: u64,
+    stored_len: u64,
+    len: u64,
+}
+
+/// The parsed index of an archive.
+#[derive(Debug, Default)]
+struct ArchiveIndex {
+    assets: HashMap<PathBuf, ArchiveEntry>,
+    metas: HashMap<PathBuf, ArchiveEntry>,
+    /// The assets and subdirectories of each directory.
+    directories: HashMap<PathBuf, Vec<PathBuf>>,
+}
+
+impl ArchiveIndex {
+    /// Checks the header of an archive.
+    fn parse_header(header: &[u8]) -> Result<(), AssetArchiveError> {
+        let mut header = ByteCursor(header);
+        if header.take(ARCHIVE_MAGIC.len())? != ARCHIVE_MAGIC {
+            return Err(AssetArchiveError::InvalidMagic);
+        }
+        let version = header.u32()?;
+        if version != ARCHIVE_VERSION {
+            return Err(AssetArchiveError::UnsupportedVersion(version));
+        }
+        let _alignment = header.u32()?;
+        Ok(())
+    }
+
+    /// Parses the trailer of an archive, returning the offset and length of its index.
+    fn parse_trailer(trailer: &[u8]) -> Result<(u64, u64), AssetArchiveError> {
+        let mut trailer = ByteCursor(trailer);
+        Ok((trailer.u64()?, trailer.u64()?))
+    }
+
+    fn parse(index: &[u8]) -> Result<Self, AssetArchiveError> {
+        let mut index = ByteCursor(index);
+        let mut parsed = Self::default();
+        let mut directories = HashMap::<PathBuf, HashSet<PathBuf>>::default();
+        directories.insert(PathBuf::new(), HashSet::default());
+
+        for _ in 0..index.u32()? {
+            let kind = index.u8()?;
+            let compression = ArchiveCompression::from_byte(index.u8()?)
+                .ok_or(AssetArchiveError::Corrupt("unknown compression"))?;
+            let path_len = index.u32()? as usize;
+            let path = core::str::from_utf8(index.take(path_len)?)
+                .map_err(|_| AssetArchiveError::Corrupt("path is not valid UTF-8"))?;
+            let path = PathBuf::from(path);
+            let entry = ArchiveEntry {
+                compression,
+                offset: index.u64()?,
+                stored_len: index.u64()?,
+                len: index.u64()?,
+            };
+
+            match kind {
+                0 => {
+                    let mut child = path.clone();
+                    while let Some(parent) = child.parent() {
+                        directories
+                            .entry(parent.to_owned())
+                            .or_default()
+                            .insert(child.clone());
+                        child = parent.to_owned();
+                    }
+                    parsed.assets.insert(path, entry);
+                }
+                1 => {
+                    parsed.metas.insert(path, entry);
+                }
+                _ => return Err(AssetArchiveError::Corrupt("unknown entry kind")),
+            }
+        }
+
+        parsed.directories = directories
+            .into_iter()
+            .map(|(path, children)| (path, children.into_iter().collect()))
+            .collect();
+        Ok(parsed)
+    }
+}
+
+/// Reads little-endian integers and byte slices from the front of a slice.
+struct ByteCursor<'a>(&'a [u8]);
+
+impl<'a> ByteCursor<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], AssetArchiveError> {
+        if self.0.len() < len {
+            return Err(AssetArchiveError::Corrupt("unexpected end of data"));
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8, AssetArchiveError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, AssetArchiveError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, AssetArchiveError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+/// Where the bytes of an archive are read from.
+enum ArchiveStorage {
+    Memory(Value),
+    #[cfg(not(target_arch = "wasm32"))]
+    File {
+        path: PathBuf,
+        /// The archive file, opened the first time it is read and kept open, and its length.
+        file: OnceCell<(async_lock::Mutex<async_fs::File>, u64)>,
+    },
+}
+
+/// An [`AssetReader`] serving the assets and asset metas of an archive written by an
+/// [`AssetArchiveWriter`].
+///
+/// The index of the archive is read the first time an asset is requested. Directories are listed
+/// from the paths of the assets in the archive.
+///
+/// Clones of a reader share the same archive, along with its open file and parsed index.
+#[derive(Clone)]
+pub struct ArchiveAssetReader {
+    storage: Arc<ArchiveStorage>,
+    index: Arc<OnceCell<ArchiveIndex>>,
+}
+
+impl ArchiveAssetReader {
+    /// Creates a reader for the archive file at `path`, relative to the base path of the
+    /// [`FileAssetReader`](crate::io::file::FileAssetReader).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        let path = super::file::FileAssetReader::get_base_path().join(path);
+        Self {
+            storage: Arc::new(ArchiveStorage::File {
+                path,
+                file: OnceCell::new(),
+            }),
+            index: Arc::default(),
+        }
+    }
+
+    /// Creates a reader for an archive held in memory, such as one embedded with [`include_bytes`].
+    pub fn from_bytes(bytes: impl Into<Value>) -> Self {
+        Self {
+            storage: Arc::new(ArchiveStorage::Memory(bytes.into())),
+            index: Arc::default(),
+        }
+    }
+
+    async fn index(&self) -> Result<&ArchiveIndex, AssetArchiveError> {
+        self.index
+            .get_or_try_init(|| async {
+                ArchiveIndex::parse_header(&self.read_range(0, HEADER_LEN).await?)?;
+                let archive_len = self.len().await?;
+                let trailer_offset = archive_len
+                    .checked_sub(TRAILER_LEN)
+                    .filter(|offset| *offset >= HEADER_LEN)
+                    .ok_or(AssetArchiveError::Corrupt("unexpected end of data"))?;
+                let trailer = self.read_range(trailer_offset, TRAILER_LEN).await?;
+                let (offset, len) = ArchiveIndex::parse_trailer(&trailer)?;
+                ArchiveIndex::parse(&self.read_range(offset, len).await?)
+            })
+            .await
+    }
+
+    /// Returns the length of the archive, in bytes.
+    async fn len(&self) -> Result<u64, AssetArchiveError> {
+        match &*self.storage {
+            ArchiveStorage::Memory(Value::Vec(bytes)) => Ok(bytes.len() as u64),
+            ArchiveStorage::Memory(Value::Static(bytes)) => Ok(bytes.len() as u64),
+            #[cfg(not(target_arch = "wasm32"))]
+            ArchiveStorage::File { .. } => Ok(self.file().await?.1),
+        }
+    }
+
+    /// Returns the archive file, opening it the first time, and its length.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn file(&self) -> Result<&(async_lock::Mutex<async_fs::File>, u64), AssetArchiveError> {
+        let ArchiveStorage::File { path, file } = &*self.storage else {
+            unreachable!("only archives stored in files are opened")
+        };
+        file.get_or_try_init(|| async {
+            let file = async_fs::File::open(path).await?;
+            let len = file.metadata().await?.len();
+            Ok((async_lock::Mutex::new(file), len))
+        })
+        .await
+    }
+
+    /// Returns `len` bytes of the archive starting at `offset`.
+    async fn read_range(&self, offset: u64, len: u64) -> Result<Vec<u8>, AssetArchiveError> {
+        match &*self.storage {
+            ArchiveStorage::Memory(_) => Ok(self.slice(offset, len)?.to_vec()),
+            #[cfg(not(target_arch = "wasm32"))]
+            ArchiveStorage::File { .. } => {
+                use futures_lite::{AsyncReadExt, AsyncSeekExt};
+
+                let (file, file_len) = self.file().await?;
+                // The range comes from the archive, check it before allocating its bytes.
+                if offset.checked_add(len).is_none_or(|end| end > *file_len) {
+                    return Err(AssetArchiveError::Corrupt("entry is out of bounds"));
+                }
+                let mut bytes = vec![0; len as usize];
+                let mut file = file.lock().await;
+                file.seek(std::io::SeekFrom::Start(offset)).await?;
+                file.read_exact(&mut bytes).await.map_err(|err| {
+                    if err.kind() == std::io::ErrorKind::UnexpectedEof {
+                        AssetArchiveError::Corrupt("unexpected end of data")
+                    } else {
+                        err.into()
+                    }
+                })?;
+                Ok(bytes)
+            }
+        }
+    }
+
+    /// Returns the bytes of an archive held in memory from `offset` to `offset + len`.
+    fn slice(&self, offset: u64, len: u64) -> Result<&[u8], AssetArchiveError> {
+        let ArchiveStorage::Memory(value) = &*self.storage else {
+            unreachable!("only archives held in memory can be sliced")
+        };
+        let bytes: &[u8] = match value {
+            Value::Vec(bytes) => bytes,
+            Value::Static(bytes) => bytes,
+        };
+        usize::try_from(offset)
+            .ok()
+            .zip(usize::try_from(len).ok())
+            .and_then(|(offset, len)| bytes.get(offset..offset.checked_add(len)?))
+            .ok_or(AssetArchiveError::Corrupt("entry is out of bounds"))
+    }
+
+    async fn read_entry<'a>(
+        &'a self,
+        entry: Option<ArchiveEntry>,
+        path: &Path,
+    ) -> Result<Box<dyn Reader + 'a>, AssetReaderError> {
+        let entry = entry.ok_or_else(|| AssetReaderError::NotFound(path.to_owned()))?;
+        match entry.compression {
+            ArchiveCompression::None if matches!(*self.storage, ArchiveStorage::Memory(_)) => {
+                Ok(Box::new(SliceReader::new(
+                    self.slice(entry.offset, entry.stored_len)?,
+                )))
+            }
+            ArchiveCompression::None => Ok(Box::new(VecReader::new(
+                self.read_range(entry.offset, entry.stored_len).await?,
+            ))),
+            ArchiveCompression::Deflate => {
+                let stored = self.read_range(entry.offset, entry.stored_len).await?;
+                let bytes =
+                    miniz_oxide::inflate::decompress_to_vec_with_limit(&stored, entry.len as usize)
+                        .map_err(|_| AssetArchiveError::Decompress(path.to_owned()))?;
+                Ok(Box::new(VecReader::new(bytes)))
+            }
+        }
+    }
+}
+
+impl AssetReader for ArchiveAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        let entry = self.index().await?.assets.get(path).copied();
+        self.read_entry(entry, path).await
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        let entry = self.index().await?.metas.get(path).copied();
+        self.read_entry(entry, path).await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let children = self
+            .index()
+            .await?
+            .directories
+            .get(path)
+            .cloned()
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_owned()))?;
+        Ok(Box::new(futures_lite::stream::iter(children)))
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        let index = self.index().await?;
+        if index.directories.contains_key(path) {
+            Ok(true)
+        } else if index.assets.contains_key(path) {
+            Ok(false)
+        } else {
+            Err(AssetReaderError::NotFound(path.to_owned()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ArchiveAssetReader, ArchiveCompression, ArchiveEntryKind, AssetArchiveSettings,
+        AssetArchiveWriter,
+    };
+    use crate::io::{memory::Dir, memory::MemoryAssetReader, AssetReader, Reader};
+    use futures_lite::StreamExt;
+    use std::path::{Path, PathBuf};
+
+    async fn read(reader: &ArchiveAssetReader, path: &str) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut asset = reader.read(Path::new(path)).await.unwrap();
+        asset.read_to_end(&mut bytes).await.unwrap();
+        bytes
+    }
+
+    #[test]
+    fn round_trip() {
+        let repetitive = "bevy ".repeat(100).into_bytes();
+        let bytes = bevy_tasks::block_on(async {
+            let mut writer = AssetArchiveWriter::new(
+                Vec::new(),
+                AssetArchiveSettings {
+                    alignment: 64,
+                    ..Default::default()
+                },
+            );
+            writer.add_asset("a.txt", b"a").await.unwrap();
+            writer.add_meta("a.txt", b"meta").await.unwrap();
+            writer.add_asset("x/y/b.txt", &repetitive).await.unwrap();
+            writer
+                .add_entry(
+                    ArchiveEntryKind::Asset,
+                    "x/c.txt",
+                    Some(ArchiveCompression::None),
+                    &repetitive,
+                )
+                .await
+                .unwrap();
+            writer.finish().await.unwrap()
+        });
+        // the repetitive entry is compressed, the other one isn't
+        assert!(bytes.len() < 2 * repetitive.len());
+
+        let reader = ArchiveAssetReader::from_bytes(bytes);
+        bevy_tasks::block_on(async {
+            assert_eq!(read(&reader, "a.txt").await, b"a");
+            assert_eq!(read(&reader, "x/y/b.txt").await, repetitive);
+            assert_eq!(read(&reader, "x/c.txt").await, repetitive);
+            assert_eq!(
+                reader.read_meta_bytes(Path::new("a.txt")).await.unwrap(),
+                b"meta"
+            );
+            assert!(reader.read_meta(Path::new("x/c.txt")).await.is_err());
+            assert!(reader.read(Path::new("missing.txt")).await.is_err());
+
+            assert!(reader.is_directory(Path::new("x")).await.unwrap());
+            assert!(!reader.is_directory(Path::new("a.txt")).await.unwrap());
+            let mut children = reader
+                .read_directory(Path::new("x"))
+                .await
+                .unwrap()
+                .collect::<Vec<_>>()
+                .await;
+            children.sort();
+            assert_eq!(children, [PathBuf::from("x/c.txt"), PathBuf::from("x/y")]);
+        });
+    }
+
+    #[test]
+    fn pack_directory() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.txt"), "a");
+        dir.insert_meta_text(Path::new("a.txt"), "meta");
+        dir.insert_asset_text(Path::new("x/b.txt"), "b");
+        let source = MemoryAssetReader { root: dir };
+
+        bevy_tasks::block_on(async {
+            let mut writer = AssetArchiveWriter::new(Vec::new(), AssetArchiveSettings::default());
+            writer.add_directory(&source, Path::new("")).await.unwrap();
+            let reader = ArchiveAssetReader::from_bytes(writer.finish().await.unwrap());
+            assert_eq!(read(&reader, "a.txt").await, b"a");
+            assert_eq!(read(&reader, "x/b.txt").await, b"b");
+            assert_eq!(
+                reader.read_meta_bytes(Path::new("a.txt")).await.unwrap(),
+                b"meta"
+            );
+        });
+    }
+
+    #[test]
+    fn invalid_archive() {
+        let reader = ArchiveAssetReader::from_bytes(b"not an archive, but long enough".to_vec());
+        assert!(bevy_tasks::block_on(reader.read(Path::new("a.txt"))).is_err());
+    }
+
+    #[test]
+    fn out_of_bounds_index() {
+        let mut bytes = bevy_tasks::block_on(async {
+            let mut writer = AssetArchiveWriter::new(Vec::new(), AssetArchiveSettings::default());
+            writer.add_asset("a.txt", b"a").await.unwrap();
+            writer.finish().await.unwrap()
+        });
+
+        // Claim that the index is far larger than the archive.
+        let len = bytes.len();
+        bytes[len - 8..].copy_from_slice(&u64::MAX.to_le_bytes());
+        let reader = ArchiveAssetReader::from_bytes(bytes);
+        assert!(bevy_tasks::block_on(reader.read(Path::new("a.txt"))).is_err());
+    }
+}
@@ -1,8 +1,10 @@
-use crate::io::{AssetReader, AssetReaderError, PathStream, Reader};
+use crate::io::{
+    AssetReader, AssetReaderError, AssetWriter, AssetWriterError, PathStream, Reader, Writer,
+};
 use alloc::sync::Arc;
 use bevy_utils::HashMap;
 use core::{pin::Pin, task::Poll};
-use futures_io::AsyncRead;
+use futures_io::{AsyncRead, AsyncWrite};
 use futures_lite::{ready, Stream};
 use parking_lot::RwLock;
 use std::path::{Path, PathBuf};
@@ -18,7 +20,7 @@ struct DirInternal {
 }
 
 /// A clone-able (internally Arc-ed) / thread-safe "in memory" filesystem.
-/// This is built for [`MemoryAssetReader`] and is primarily intended for unit tests.
+/// This is built for [`MemoryAssetReader`] and [`MemoryAssetWriter`] and is primarily intended for unit tests.
 #[derive(Default, Clone, Debug)]
 pub struct Dir(Arc<RwLock<DirInternal>>);
 
@@ -78,6 +80,34 @@ impl Dir {
         );
     }
 
+    /// Removes the stored meta at `path` and returns the `Data` stored if found and otherwise `None`.
+    pub fn remove_metadata(&self, path: &Path) -> Option<Data> {
+        let mut dir = self.clone();
+        if let Some(parent) = path.parent() {
+            dir = self.get_or_insert_dir(parent);
+        }
+        let key: Box<str> = path.file_name().unwrap().to_string_lossy().into();
+        let data = dir.0.write().metadata.remove(&key);
+        data
+    }
+
+    /// Removes the directory at `path` and returns it if found and otherwise `None`.
+    pub fn remove_dir(&self, path: &Path) -> Option<Dir> {
+        let mut dir = self.clone();
+        if let Some(parent) = path.parent() {
+            dir = self.get_dir(parent)?;
+        }
+        let key: Box<str> = path.file_name()?.to_string_lossy().into();
+        let removed = dir.0.write().dirs.remove(&key);
+        removed
+    }
+
+    /// Returns `true` if this directory contains no assets, meta or directories.
+    pub fn is_empty(&self) -> bool {
+        let dir = self.0.read();
+        dir.assets.is_empty() && dir.metadata.is_empty() && dir.dirs.is_empty()
+    }
+
     pub fn get_or_insert_dir(&self, path: &Path) -> Dir {
         let mut dir = self.clone();
         let mut full_path = PathBuf::new();
@@ -180,6 +210,13 @@ pub struct MemoryAssetReader {
     pub root: Dir,
 }
 
+/// In-memory [`AssetWriter`] implementation, writing to the same kind of [`Dir`] as [`MemoryAssetReader`].
+/// This is primarily intended for unit tests.
+#[derive(Default, Clone)]
+pub struct MemoryAssetWriter {
+    pub root: Dir,
+}
+
 /// Asset data stored in a [`Dir`].
 #[derive(Clone, Debug)]
 pub struct Data {
@@ -287,6 +324,51 @@ impl Reader for DataReader {
     }
 }
 
+/// Buffers the bytes written to an asset or meta of a [`Dir`], storing them when flushed or closed.
+struct DataWriter {
+    dir: Dir,
+    path: PathBuf,
+    is_meta: bool,
+    bytes: Vec<u8>,
+}
+
+impl DataWriter {
+    fn store(&self) {
+        if self.is_meta {
+            self.dir.insert_meta(&self.path, self.bytes.clone());
+        } else {
+            self.dir.insert_asset(&self.path, self.bytes.clone());
+        }
+    }
+}
+
+impl AsyncWrite for DataWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _cx: &mut core::task::Context<'_>,
+        buf: &[u8],
+    ) -> Poll<futures_io::Result<usize>> {
+        self.bytes.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        _cx: &mut core::task::Context<'_>,
+    ) -> Poll<futures_io::Result<()>> {
+        self.store();
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        self: Pin<&mut Self>,
+        _cx: &mut core::task::Context<'_>,
+    ) -> Poll<futures_io::Result<()>> {
+        self.store();
+        Poll::Ready(Ok(()))
+    }
+}
+
 impl AssetReader for MemoryAssetReader {
     async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
         self.root
@@ -326,9 +408,109 @@ impl AssetReader for MemoryAssetReader {
     }
 }
 
+fn not_found(path: &Path) -> AssetWriterError {
+    AssetWriterError::Io(std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        format!("{} does not exist", path.display()),
+    ))
+}
+
+impl AssetWriter for MemoryAssetWriter {
+    async fn write<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        Ok(Box::new(DataWriter {
+            dir: self.root.clone(),
+            path: path.to_owned(),
+            is_meta: false,
+            bytes: Vec::new(),
+        }))
+    }
+
+    async fn write_meta<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        Ok(Box::new(DataWriter {
+            dir: self.root.clone(),
+            path: path.to_owned(),
+            is_meta: true,
+            bytes: Vec::new(),
+        }))
+    }
+
+    async fn remove<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.root
+            .remove_asset(path)
+            .map(|_| ())
+            .ok_or_else(|| not_found(path))
+    }
+
+    async fn remove_meta<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.root
+            .remove_metadata(path)
+            .map(|_| ())
+            .ok_or_else(|| not_found(path))
+    }
+
+    async fn rename<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let data = self
+            .root
+            .remove_asset(old_path)
+            .ok_or_else(|| not_found(old_path))?;
+        self.root.insert_asset(new_path, data.value);
+        Ok(())
+    }
+
+    async fn rename_meta<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let data = self
+            .root
+            .remove_metadata(old_path)
+            .ok_or_else(|| not_found(old_path))?;
+        self.root.insert_meta(new_path, data.value);
+        Ok(())
+    }
+
+    async fn remove_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.root
+            .remove_dir(path)
+            .map(|_| ())
+            .ok_or_else(|| not_found(path))
+    }
+
+    async fn remove_empty_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        let dir = self.root.get_dir(path).ok_or_else(|| not_found(path))?;
+        if !dir.is_empty() {
+            return Err(AssetWriterError::Io(std::io::Error::other(format!(
+                "{} is not empty",
+                path.display()
+            ))));
+        }
+        self.root.remove_dir(path);
+        Ok(())
+    }
+
+    async fn remove_assets_in_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let dir = self.root.get_dir(path).ok_or_else(|| not_found(path))?;
+        let mut dir = dir.0.write();
+        dir.assets.clear();
+        dir.metadata.clear();
+        dir.dirs.clear();
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 pub mod test {
-    use super::Dir;
+    use super::{Dir, MemoryAssetWriter};
+    use crate::io::AssetWriter;
+    use bevy_tasks::block_on;
     use std::path::Path;
 
     #[test]
@@ -362,4 +544,29 @@ pub mod test {
         assert_eq!(meta.path(), b_path);
         assert_eq!(meta.value(), b_meta);
     }
+
+    #[test]
+    fn memory_writer() {
+        let writer = MemoryAssetWriter::default();
+        let dir = writer.root.clone();
+        let a_path = Path::new("x/a.txt");
+        let b_path = Path::new("y/b.txt");
+
+        block_on(writer.write_bytes(a_path, b"a")).unwrap();
+        block_on(writer.write_meta_bytes(a_path, b"meta")).unwrap();
+        assert_eq!(dir.get_asset(a_path).unwrap().value(), b"a");
+        assert_eq!(dir.get_metadata(a_path).unwrap().value(), b"meta");
+
+        block_on(writer.rename(a_path, b_path)).unwrap();
+        block_on(writer.rename_meta(a_path, b_path)).unwrap();
+        assert!(dir.get_asset(a_path).is_none());
+        assert_eq!(dir.get_asset(b_path).unwrap().path(), b_path);
+        assert_eq!(dir.get_metadata(b_path).unwrap().value(), b"meta");
+        assert!(block_on(writer.remove(a_path)).is_err());
+
+        assert!(block_on(writer.remove_empty_directory(Path::new("y"))).is_err());
+        block_on(writer.remove_assets_in_directory(Path::new("y"))).unwrap();
+        block_on(writer.remove_empty_directory(Path::new("y"))).unwrap();
+        assert!(dir.get_dir(Path::new("y")).is_none());
+    }
 }
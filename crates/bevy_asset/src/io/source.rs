@@ -1,6 +1,7 @@
 use crate::{
     io::{processor_gated::ProcessorGatedReader, AssetSourceEvent, AssetWatcher},
     processor::AssetProcessorData,
+    server::LoadQueue,
 };
 use alloc::sync::Arc;
 use atomicow::CowArc;
@@ -151,6 +152,7 @@ pub struct AssetSourceBuilder {
     >,
     pub watch_warning: Option<&'static str>,
     pub processed_watch_warning: Option<&'static str>,
+    pub max_concurrent_loads: Option<usize>,
 }
 
 impl AssetSourceBuilder {
@@ -175,6 +177,7 @@ impl AssetSourceBuilder {
             watcher: None,
             processed_event_receiver: None,
             processed_watcher: None,
+            load_queue: LoadQueue::new(self.max_concurrent_loads),
         };
 
         if watch {
@@ -275,6 +278,23 @@ impl AssetSourceBuilder {
         self
     }
 
+    /// Limits the number of assets loaded from this source at the same time to `max`. Loads waiting for
+    /// a slot are started in order of their [`LoadPriority`](crate::LoadPriority).
+    ///
+    /// By default, there is no limit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is zero.
+    pub fn with_max_concurrent_loads(mut self, max: usize) -> Self {
+        assert!(
+            max > 0,
+            "an asset source must allow at least one load at a time"
+        );
+        self.max_concurrent_loads = Some(max);
+        self
+    }
+
     /// Enables a warning for the processed source watcher, which will print when watching is enabled and the processed source doesn't have a watcher.
     pub fn with_processed_watch_warning(mut self, warning: &'static str) -> Self {
         self.processed_watch_warning = Some(warning);
@@ -306,6 +326,22 @@ impl AssetSourceBuilder {
             default
         }
     }
+
+    /// Returns a builder reading both unprocessed and processed assets from the asset archive at
+    /// `path`, relative to the base path of the [`FileAssetReader`](crate::io::file::FileAssetReader).
+    ///
+    /// This is typically used in release builds to load the assets packed by
+    /// [`AssetProcessor::write_archive`](crate::processor::AssetProcessor::write_archive) from a
+    /// single file. Both readers share a single [`ArchiveAssetReader`](super::archive::ArchiveAssetReader),
+    /// so the archive is only opened and indexed once. The source has no writers or watchers.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn archive(path: &str) -> Self {
+        let reader = super::archive::ArchiveAssetReader::new(path);
+        let processed_reader = reader.clone();
+        Self::default()
+            .with_reader(move || Box::new(reader.clone()))
+            .with_processed_reader(move || Box::new(processed_reader.clone()))
+    }
 }
 
 /// A [`Resource`] that hold (repeatable) functions capable of producing new [`AssetReader`](crate::io::AssetReader) and [`AssetWriter`](crate::io::AssetWriter) instances
@@ -383,6 +419,7 @@ pub struct AssetSource {
     processed_watcher: Option<Box<dyn AssetWatcher>>,
     event_receiver: Option<crossbeam_channel::Receiver<AssetSourceEvent>>,
     processed_event_receiver: Option<crossbeam_channel::Receiver<AssetSourceEvent>>,
+    load_queue: LoadQueue,
 }
 
 impl AssetSource {
@@ -397,6 +434,12 @@ impl AssetSource {
         self.id.clone()
     }
 
+    /// Returns the queue limiting the number of loads from this source running at the same time.
+    #[inline]
+    pub(crate) fn load_queue(&self) -> &LoadQueue {
+        &self.load_queue
+    }
+
     /// Return's this source's unprocessed [`AssetReader`](crate::io::AssetReader).
     #[inline]
     pub fn reader(&self) -> &dyn ErasedAssetReader {
@@ -167,6 +167,7 @@ pub mod prelude {
 }
 
 mod assets;
+mod collection;
 mod direct_access_ext;
 mod event;
 mod folder;
@@ -180,7 +181,8 @@ mod render_asset;
 mod server;
 
 pub use assets::*;
-pub use bevy_asset_macros::Asset;
+pub use bevy_asset_macros::{Asset, AssetCollection};
+pub use collection::*;
 pub use direct_access_ext::DirectAssetAccessExt;
 pub use event::*;
 pub use folder::*;
@@ -202,12 +204,14 @@ pub use ron;
 use crate::{
     io::{embedded::EmbeddedAssetRegistry, AssetSourceBuilder, AssetSourceBuilders, AssetSourceId},
     processor::{AssetProcessor, Process},
+    saver::AssetSaver,
 };
 use alloc::sync::Arc;
-use bevy_app::{App, Last, Plugin, PreUpdate};
+use bevy_app::{App, Last, Plugin, PreStartup, PreUpdate};
 use bevy_ecs::{
     reflect::AppTypeRegistry,
     schedule::{IntoSystemConfigs, IntoSystemSetConfigs, SystemSet},
+    system::Resource,
     world::FromWorld,
 };
 use bevy_reflect::{FromReflect, GetTypeRegistration, Reflect, TypePath};
@@ -244,6 +248,17 @@ pub struct AssetPlugin {
     pub mode: AssetMode,
     /// How/If asset meta files should be checked.
     pub meta_check: AssetMetaCheck,
+    /// If set, the [`AssetProcessor`] stores processed assets in, and reuses them from, a
+    /// [`ProcessedAssetCache`](processor::ProcessedAssetCache) in this directory (relative to the
+    /// project root unless it is absolute). Sharing it between machines and checkouts avoids
+    /// processing the same assets again.
+    ///
+    /// This only has an effect in [`AssetMode::Processed`] with the `asset_processor` feature.
+    pub processed_asset_cache_path: Option<String>,
+    /// If `true`, `.meta` files upgraded by a settings migration are written back to their asset
+    /// source, so that the migration doesn't need to run again. See
+    /// [`AssetApp::register_asset_loader_migration`].
+    pub rewrite_migrated_meta: bool,
 }
 
 /// Controls whether or not assets are pre-processed before being loaded.
@@ -297,6 +312,8 @@ impl Default for AssetPlugin {
             processed_file_path: Self::DEFAULT_PROCESSED_FILE_PATH.to_string(),
             watch_for_changes_override: None,
             meta_check: AssetMetaCheck::default(),
+            processed_asset_cache_path: None,
+            rewrite_migrated_meta: false,
         }
     }
 }
@@ -344,6 +361,10 @@ impl Plugin for AssetPlugin {
                     {
                         let mut builders = app.world_mut().resource_mut::<AssetSourceBuilders>();
                         let processor = AssetProcessor::new(&mut builders);
+                        #[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+                        if let Some(path) = &self.processed_asset_cache_path {
+                            processor.set_cache(processor::ProcessedAssetCache::from_path(path));
+                        }
                         let mut sources = builders.build_sources(false, watch);
                         sources.gate_on_processor(processor.data.clone());
                         // the main asset server shares loaders with the processor asset server
@@ -371,6 +392,13 @@ impl Plugin for AssetPlugin {
                 }
             }
         }
+        app.world()
+            .resource::<AssetServer>()
+            .data
+            .loaders
+            .write()
+            .migrations
+            .rewrite = self.rewrite_migrated_meta;
         app.insert_resource(embedded)
             .init_asset::<LoadedFolder>()
             .init_asset::<LoadedUntypedAsset>()
@@ -458,6 +486,9 @@ pub trait AssetApp {
     fn register_asset_loader<L: AssetLoader>(&mut self, loader: L) -> &mut Self;
     /// Registers the given `processor` in the [`App`]'s [`AssetProcessor`].
     fn register_asset_processor<P: Process>(&mut self, processor: P) -> &mut Self;
+    /// Registers the given `saver` in the [`App`]'s [`AssetServer`], to save assets at runtime with
+    /// [`AssetServer::save`].
+    fn register_asset_saver<S: AssetSaver>(&mut self, saver: S) -> &mut Self;
     /// Registers the given [`AssetSourceBuilder`] with the given `id`.
     ///
     /// Note that asset sources must be registered before adding [`AssetPlugin`] to your application,
@@ -469,6 +500,28 @@ pub trait AssetApp {
     ) -> &mut Self;
     /// Sets the default asset processor for the given `extension`.
     fn set_default_asset_processor<P: Process>(&mut self, extension: &str) -> &mut Self;
+    /// Registers a migration upgrading the settings of the [`AssetLoader`] `L` in `.meta` files from
+    /// `from_version`, of type `From`, to the next version, of type `To`.
+    /// See [`AssetServer::register_loader_migration`].
+    fn register_asset_loader_migration<L: AssetLoader, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        From: for<'a> serde::Deserialize<'a>,
+        To: serde::Serialize;
+    /// Registers a migration upgrading the settings of the processor `P` in `.meta` files from
+    /// `from_version`, of type `From`, to the next version, of type `To`.
+    /// See [`AssetProcessor::register_processor_migration`].
+    fn register_asset_processor_migration<P: Process, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        From: for<'a> serde::Deserialize<'a>,
+        To: serde::Serialize;
     /// Initializes the given loader in the [`App`]'s [`AssetServer`].
     fn init_asset_loader<L: AssetLoader + FromWorld>(&mut self) -> &mut Self;
     /// Initializes the given [`Asset`] in the [`App`] by:
@@ -489,6 +542,10 @@ pub trait AssetApp {
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
     /// is registered.
     fn preregister_asset_loader<L: AssetLoader>(&mut self, extensions: &[&str]) -> &mut Self;
+    /// Initializes the given [`AssetCollection`] in the [`App`] by:
+    /// * Loading it in [`PreStartup`] and inserting it as a resource
+    /// * Sending an [`AssetCollectionLoaded`] event once all of its assets are done loading
+    fn init_asset_collection<C: AssetCollection + Resource>(&mut self) -> &mut Self;
 }
 
 impl AssetApp for App {
@@ -506,6 +563,11 @@ impl AssetApp for App {
         self
     }
 
+    fn register_asset_saver<S: AssetSaver>(&mut self, saver: S) -> &mut Self {
+        self.world().resource::<AssetServer>().register_saver(saver);
+        self
+    }
+
     fn register_asset_source(
         &mut self,
         id: impl Into<AssetSourceId<'static>>,
@@ -533,6 +595,36 @@ impl AssetApp for App {
         self
     }
 
+    fn register_asset_loader_migration<L: AssetLoader, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        From: for<'a> serde::Deserialize<'a>,
+        To: serde::Serialize,
+    {
+        self.world()
+            .resource::<AssetServer>()
+            .register_loader_migration::<L, From, To>(from_version, migrate);
+        self
+    }
+
+    fn register_asset_processor_migration<P: Process, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        From: for<'a> serde::Deserialize<'a>,
+        To: serde::Serialize,
+    {
+        if let Some(asset_processor) = self.world().get_resource::<AssetProcessor>() {
+            asset_processor.register_processor_migration::<P, From, To>(from_version, migrate);
+        }
+        self
+    }
+
     fn init_asset_loader<L: AssetLoader + FromWorld>(&mut self) -> &mut Self {
         let loader = L::from_world(self.world_mut());
         self.register_asset_loader(loader)
@@ -592,6 +684,12 @@ impl AssetApp for App {
             .preregister_loader::<L>(extensions);
         self
     }
+
+    fn init_asset_collection<C: AssetCollection + Resource>(&mut self) -> &mut Self {
+        self.add_event::<AssetCollectionLoaded<C>>()
+            .add_systems(PreStartup, load_asset_collection::<C>)
+            .add_systems(PreUpdate, track_asset_collection::<C>.after(TrackAssets))
+    }
 }
 
 /// A system set that holds all "track asset" operations.
@@ -616,8 +714,9 @@ mod tests {
             AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets,
+        Asset, AssetApp, AssetCollection, AssetCollectionLoaded, AssetEvent, AssetId,
+        AssetLoadError, AssetLoadFailedEvent, AssetPath, AssetPlugin, AssetServer, Assets,
+        LoadFolderSettings, LoadPriority, UntypedAssetId,
     };
     use alloc::sync::Arc;
     use bevy_app::{App, Update};
@@ -1511,6 +1610,281 @@ mod tests {
         assert_eq!(events, expected_events);
     }
 
+    #[derive(Default)]
+    struct VersionedTextLoader;
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct VersionedTextSettings {
+        prefix: String,
+        repeat: usize,
+    }
+
+    impl AssetLoader for VersionedTextLoader {
+        type Asset = SubText;
+        type Settings = VersionedTextSettings;
+        type Error = std::io::Error;
+        const SETTINGS_VERSION: u32 = 2;
+
+        async fn load(
+            &self,
+            reader: &mut dyn Reader,
+            settings: &Self::Settings,
+            _load_context: &mut LoadContext<'_>,
+        ) -> Result<Self::Asset, Self::Error> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let text = String::from_utf8_lossy(&bytes);
+            Ok(SubText {
+                text: format!("{}{}", settings.prefix, text.repeat(settings.repeat)),
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["versioned"]
+        }
+    }
+
+    #[test]
+    fn migrate_meta_settings() {
+        #[derive(Deserialize)]
+        struct SettingsV0 {
+            text_prefix: String,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        struct SettingsV1 {
+            prefix: String,
+        }
+
+        let loader = core::any::type_name::<VersionedTextLoader>();
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("old.versioned"), "a");
+        dir.insert_meta_text(
+            Path::new("old.versioned"),
+            &format!(
+                r#"(meta_format_version: "1.0", asset: Load(loader: "{loader}", settings: (text_prefix: ">")))"#
+            ),
+        );
+        dir.insert_asset_text(Path::new("future.versioned"), "a");
+        dir.insert_meta_text(
+            Path::new("future.versioned"),
+            &format!(
+                r#"(meta_format_version: "1.0", settings_version: 3, asset: Load(loader: "{loader}", settings: (prefix: "", repeat: 1)))"#
+            ),
+        );
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<SubText>()
+            .register_asset_loader(VersionedTextLoader)
+            .register_asset_loader_migration::<VersionedTextLoader, _, _>(
+                0,
+                |settings: SettingsV0| SettingsV1 {
+                    prefix: settings.text_prefix,
+                },
+            )
+            .register_asset_loader_migration::<VersionedTextLoader, _, _>(
+                1,
+                |settings: SettingsV1| VersionedTextSettings {
+                    prefix: settings.prefix,
+                    repeat: 2,
+                },
+            );
+        gate_opener.open("old.versioned");
+        gate_opener.open("future.versioned");
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let old: Handle<SubText> = asset_server.load("old.versioned");
+        let future: Handle<SubText> = asset_server.load("future.versioned");
+        run_app_until(&mut app, |world| {
+            let old = world.resource::<Assets<SubText>>().get(&old)?;
+            assert_eq!(old.text, ">aa");
+            asset_server.load_state(&future).is_failed().then_some(())
+        });
+    }
+
+    #[test]
+    fn save_asset() {
+        use crate::{
+            io::memory::MemoryAssetWriter,
+            loader::LoadedAsset,
+            saver::{AssetSaver, SaveAssetError, SavedAsset},
+            transformer::TransformedAsset,
+            AsyncWriteExt,
+        };
+        use bevy_tasks::block_on;
+
+        struct CoolTextSaver;
+
+        impl AssetSaver for CoolTextSaver {
+            type Asset = CoolText;
+            type Settings = ();
+            type OutputLoader = CoolTextLoader;
+            type Error = std::io::Error;
+
+            async fn save(
+                &self,
+                writer: &mut crate::io::Writer,
+                asset: SavedAsset<'_, Self::Asset>,
+                _settings: &Self::Settings,
+            ) -> Result<(), Self::Error> {
+                // the loader labels sub texts with their text
+                let mut sub_texts = asset
+                    .iter_labels()
+                    .filter_map(|label| Some(asset.get_labeled::<SubText, _>(label)?.text.clone()))
+                    .collect::<Vec<_>>();
+                sub_texts.sort();
+                let ron = CoolTextRon {
+                    text: asset.text.clone(),
+                    dependencies: Vec::new(),
+                    embedded_dependencies: Vec::new(),
+                    sub_texts,
+                };
+                let ron = ron::ser::to_string(&ron).unwrap();
+                writer.write_all(ron.as_bytes()).await
+            }
+        }
+
+        let dir = Dir::default();
+        let (reader_dir, writer_dir) = (dir.clone(), dir.clone());
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || {
+                    Box::new(MemoryAssetReader {
+                        root: reader_dir.clone(),
+                    })
+                })
+                .with_writer(move |_| {
+                    Some(Box::new(MemoryAssetWriter {
+                        root: writer_dir.clone(),
+                    }))
+                }),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            LogPlugin::default(),
+            AssetPlugin::default(),
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader)
+        .register_asset_saver(CoolTextSaver);
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let text = || CoolText {
+            text: "saved".to_string(),
+            ..Default::default()
+        };
+        let mut saved = TransformedAsset::from(text());
+        let sub_text = app.world().resource::<Assets<SubText>>().reserve_handle();
+        saved.insert_labeled(
+            "sub",
+            sub_text,
+            LoadedAsset::from(SubText {
+                text: "sub".to_string(),
+            }),
+        );
+        block_on(asset_server.save("texts/saved.cool.ron", &saved)).unwrap();
+        let reader = MemoryAssetReader { root: dir.clone() };
+        let read_meta = |path: &str| {
+            let meta = block_on(reader.read_meta_bytes(Path::new(path))).unwrap();
+            String::from_utf8(meta).unwrap()
+        };
+        assert!(read_meta("texts/saved.cool.ron").contains("CoolTextLoader"));
+
+        // the meta of processed assets is kept, along with the settings of their processor
+        let processed_meta = r#"(
+    meta_format_version: "1.0",
+    asset: Process(
+        processor: "CoolTextProcessor",
+        settings: (text: "processed"),
+    ),
+)"#;
+        dir.insert_meta_text(Path::new("processed.cool.ron"), processed_meta);
+        block_on(asset_server.save("processed.cool.ron", &text())).unwrap();
+        assert_eq!(read_meta("processed.cool.ron"), processed_meta);
+
+        assert!(matches!(
+            block_on(asset_server.save("texts/saved.cool.ron#label", &text())),
+            Err(SaveAssetError::LabeledPath(_))
+        ));
+        let sub_text = SubText {
+            text: "sub".to_string(),
+        };
+        assert!(matches!(
+            block_on(asset_server.save("sub.ron", &sub_text)),
+            Err(SaveAssetError::MissingAssetSaver(_))
+        ));
+
+        let handle: Handle<CoolText> = asset_server.load("texts/saved.cool.ron");
+        let sub_handle: Handle<SubText> = asset_server.load("texts/saved.cool.ron#sub");
+        run_app_until(&mut app, |world| {
+            let loaded = get::<CoolText>(world, handle.id())?;
+            assert_eq!(loaded.text, "saved");
+            let loaded_sub = get::<SubText>(world, sub_handle.id())?;
+            assert_eq!(loaded_sub.text, "sub");
+            Some(())
+        });
+    }
+
+    #[test]
+    fn dependency_graph() {
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: [
+        "b.cool.ron",
+    ],
+    embedded_dependencies: [],
+    sub_texts: ["sub"],
+)"#;
+        dir.insert_asset_text(Path::new(a_path), a_ron);
+        dir.insert_asset_text(Path::new("b.cool.ron"), SIMPLE_TEXT);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        gate_opener.open(a_path);
+        gate_opener.open("b.cool.ron");
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load(a_path);
+        run_app_until(&mut app, |_| {
+            asset_server.is_loaded_with_dependencies(&a).then_some(())
+        });
+
+        let graph = asset_server.dependency_graph();
+        let b = graph.get_by_path("b.cool.ron").next().unwrap().id;
+        let sub = graph.get_by_path("a.cool.ron#sub").next().unwrap().id;
+        let a_node = graph.get(&a).unwrap();
+        assert!(a_node.load_state.is_loaded());
+        assert_eq!(a_node.type_name, Some(core::any::type_name::<CoolText>()));
+        assert_eq!(a_node.strong_handles, 1);
+        assert_eq!(a_node.labeled_assets, [sub]);
+        assert_eq!(a_node.dependencies, [b]);
+        assert_eq!(graph.get(b).unwrap().dependents, [a.id().untyped()]);
+        assert_eq!(asset_server.get_dependents(b), [a.id().untyped()]);
+        assert!(graph.recursive_dependents(b).contains(&a.id().untyped()));
+
+        let subgraph = graph.subgraph([b]);
+        assert_eq!(subgraph.len(), 1);
+        assert!(subgraph.get(b).unwrap().dependents.is_empty());
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph assets {"));
+        assert_eq!(dot.matches(" -> ").count(), 2);
+        let json: serde_json::Value = serde_json::to_value(&graph).unwrap();
+        let assets = json["assets"].as_array().unwrap();
+        assert_eq!(assets.len(), graph.len());
+        let a_json = assets.iter().find(|asset| asset["path"] == a_path).unwrap();
+        assert_eq!(a_json["load_state"], "Loaded");
+        assert_eq!(a_json["dependencies"], serde_json::json!([b.to_string()]));
+    }
+
     #[test]
     fn load_folder() {
         // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
@@ -1605,6 +1979,199 @@ mod tests {
         });
     }
 
+    #[test]
+    fn load_folder_with_settings() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let text_ron = |text: &str| {
+            format!(
+                "(text: \"{text}\", dependencies: [], embedded_dependencies: [], sub_texts: [])"
+            )
+        };
+        let paths = [
+            "text/a.cool.ron",
+            "text/b.cool.ron",
+            "text/c.txt",
+            "text/sub/d.cool.ron",
+        ];
+        let dir = Dir::default();
+        for path in paths {
+            dir.insert_asset_text(Path::new(path), &text_ron(path));
+        }
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let all = asset_server.load_folder("text");
+        let top_level = asset_server.load_folder_with_settings(
+            "text",
+            LoadFolderSettings::default()
+                .with_recursive(false)
+                .with_asset_type::<CoolText>(),
+        );
+        let globbed = asset_server
+            .load_folder_with_settings("text", LoadFolderSettings::default().with_glob("**/[!a]*"));
+        let other_type = asset_server.load_folder_with_settings(
+            "text",
+            LoadFolderSettings::default().with_asset_type::<SubText>(),
+        );
+        assert_ne!(all.id(), top_level.id());
+        // each folder reads the files it loads, and `text/b.cool.ron` is loaded by three folders
+        for _ in 0..3 {
+            for path in paths {
+                gate_opener.open(path);
+            }
+        }
+
+        let folder_paths = |world: &World, handle: &Handle<LoadedFolder>| {
+            let folder = world.resource::<Assets<LoadedFolder>>().get(handle)?;
+            let asset_server = world.resource::<AssetServer>();
+            let mut paths = folder
+                .handles
+                .iter()
+                .map(|handle| asset_server.get_path(handle.id()).unwrap().to_string())
+                .collect::<Vec<_>>();
+            paths.sort();
+            Some(paths)
+        };
+        run_app_until(&mut app, |world| {
+            let asset_server = world.resource::<AssetServer>();
+            [&all, &top_level, &globbed, &other_type]
+                .iter()
+                .all(|handle| asset_server.is_loaded_with_dependencies(*handle))
+                .then_some(())
+        });
+
+        let world = app.world();
+        assert_eq!(
+            folder_paths(world, &all).unwrap(),
+            ["text/a.cool.ron", "text/b.cool.ron", "text/sub/d.cool.ron"]
+        );
+        assert_eq!(
+            folder_paths(world, &top_level).unwrap(),
+            ["text/a.cool.ron", "text/b.cool.ron"]
+        );
+        assert_eq!(
+            folder_paths(world, &globbed).unwrap(),
+            ["text/b.cool.ron", "text/sub/d.cool.ron"]
+        );
+        assert!(folder_paths(world, &other_type).unwrap().is_empty());
+    }
+
+    #[test]
+    fn load_asset_collection() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        #[derive(AssetCollection, Resource)]
+        struct TextCollection {
+            #[asset(path = "a.cool.ron")]
+            a: Handle<CoolText>,
+            #[asset(folder = "text", extension = "ron")]
+            folder: Handle<LoadedFolder>,
+            #[asset(path = "missing.cool.ron")]
+            missing: Handle<CoolText>,
+            selected: usize,
+        }
+
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: [
+        "b.cool.ron",
+    ],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        let b_path = "b.cool.ron";
+        let b_ron = r#"
+(
+    text: "b",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        let c_path = "text/c.cool.ron";
+        let c_ron = r#"
+(
+    text: "c",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        dir.insert_asset_text(Path::new(a_path), a_ron);
+        dir.insert_asset_text(Path::new(b_path), b_ron);
+        dir.insert_asset_text(Path::new(c_path), c_ron);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .init_asset_collection::<TextCollection>();
+
+        app.update();
+        let collection = app.world().resource::<TextCollection>();
+        assert_eq!(collection.selected, 0);
+        let progress = collection.progress(app.world().resource::<AssetServer>());
+        assert_eq!(progress.total, 3);
+        assert_eq!(progress.loaded, 0);
+        assert!(!progress.is_finished());
+
+        gate_opener.open(a_path);
+        gate_opener.open(b_path);
+        gate_opener.open(c_path);
+        gate_opener.open("missing.cool.ron");
+
+        let mut reader = EventCursor::default();
+        run_app_until(&mut app, |world| {
+            let events = world.resource::<Events<AssetCollectionLoaded<TextCollection>>>();
+            let event = reader.read(events).next()?;
+            assert!(!event.is_loaded());
+            assert_eq!(event.progress.loaded, 2);
+            assert_eq!(event.progress.failed(), 1);
+            let failure = &event.progress.failures[0];
+            let collection = world.resource::<TextCollection>();
+            assert_eq!(failure.id, collection.missing.id().untyped());
+            assert_eq!(failure.path, Some(AssetPath::from("missing.cool.ron")));
+            assert!(world
+                .resource::<AssetServer>()
+                .is_loaded_with_dependencies(&collection.a));
+            assert!(world
+                .resource::<AssetServer>()
+                .is_loaded_with_dependencies(&collection.folder));
+            Some(())
+        });
+
+        // changing a field that isn't an asset doesn't send the event again
+        app.world_mut().resource_mut::<TextCollection>().selected = 1;
+        app.update();
+        app.update();
+        let events = app
+            .world()
+            .resource::<Events<AssetCollectionLoaded<TextCollection>>>();
+        assert_eq!(reader.read(events).count(), 0);
+
+        // changing an asset sends it again once the new asset is loaded
+        let b = app
+            .world()
+            .resource::<AssetServer>()
+            .load::<CoolText>(b_path);
+        app.world_mut().resource_mut::<TextCollection>().missing = b;
+        run_app_until(&mut app, |world| {
+            let events = world.resource::<Events<AssetCollectionLoaded<TextCollection>>>();
+            let event = reader.read(events).next()?;
+            assert!(event.is_loaded());
+            Some(())
+        });
+    }
+
     /// Tests that `AssetLoadFailedEvent<A>` events are emitted and can be used to retry failed assets.
     #[test]
     fn load_error_events() {
@@ -1730,6 +2297,203 @@ mod tests {
         });
     }
 
+    #[test]
+    fn load_priorities_and_cancellation() {
+        /// Records the paths of the reads in the order they started, and lets each of them
+        /// complete once its gate is opened.
+        ///
+        /// Unlike [`GatedReader`], waiting reads don't block the threads of the task pool, so
+        /// that waiting loads keep being queued.
+        #[derive(Clone, Default)]
+        struct RecordingReader {
+            reader: MemoryAssetReader,
+            reads: Arc<std::sync::Mutex<Vec<String>>>,
+            gates: Arc<std::sync::Mutex<HashMap<String, Arc<async_lock::Semaphore>>>>,
+        }
+
+        impl RecordingReader {
+            fn gate(&self, path: &str) -> Arc<async_lock::Semaphore> {
+                self.gates
+                    .lock()
+                    .unwrap()
+                    .entry(path.to_owned())
+                    .or_insert_with(|| Arc::new(async_lock::Semaphore::new(0)))
+                    .clone()
+            }
+
+            fn reads(&self) -> Vec<String> {
+                self.reads.lock().unwrap().clone()
+            }
+        }
+
+        impl AssetReader for RecordingReader {
+            async fn read<'a>(
+                &'a self,
+                path: &'a Path,
+            ) -> Result<impl Reader + 'a, AssetReaderError> {
+                let path_string = path.to_string_lossy().into_owned();
+                let gate = self.gate(&path_string);
+                self.reads.lock().unwrap().push(path_string);
+                gate.acquire().await.forget();
+                self.reader.read(path).await
+            }
+            async fn read_meta<'a>(
+                &'a self,
+                path: &'a Path,
+            ) -> Result<impl Reader + 'a, AssetReaderError> {
+                self.reader.read_meta(path).await
+            }
+            async fn read_directory<'a>(
+                &'a self,
+                path: &'a Path,
+            ) -> Result<Box<bevy_asset::io::PathStream>, AssetReaderError> {
+                self.reader.read_directory(path).await
+            }
+            async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+                self.reader.is_directory(path).await
+            }
+        }
+
+        let paths = [
+            "blocker.cool.ron",
+            "cancelled.cool.ron",
+            "low.cool.ron",
+            "normal.cool.ron",
+            "high.cool.ron",
+            "raised.cool.ron",
+            "reload_blocker.cool.ron",
+        ];
+        let dir = Dir::default();
+        for path in paths {
+            dir.insert_asset_text(
+                Path::new(path),
+                &format!(
+                    "(text: \"{path}\", dependencies: [], embedded_dependencies: [], sub_texts: [])"
+                ),
+            );
+        }
+        // the gate of `mid_read` is never opened
+        for path in ["mid_read.cool.ron", "after_cancel.cool.ron"] {
+            dir.insert_asset_text(
+                Path::new(path),
+                &format!(
+                    "(text: \"{path}\", dependencies: [], embedded_dependencies: [], sub_texts: [])"
+                ),
+            );
+        }
+
+        let reader = RecordingReader {
+            reader: MemoryAssetReader { root: dir },
+            ..Default::default()
+        };
+        let source_reader = reader.clone();
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(source_reader.clone()))
+                .with_max_concurrent_loads(1),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            LogPlugin::default(),
+            AssetPlugin::default(),
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let read_paths = || reader.reads();
+
+        // `blocker` holds the only load slot until its gate is opened
+        let blocker: Handle<CoolText> = asset_server.load("blocker.cool.ron");
+        run_app_until(&mut app, |_| (!read_paths().is_empty()).then_some(()));
+
+        let wait_until_queued = |app: &mut App, id: UntypedAssetId, priority: LoadPriority| {
+            run_app_until(app, |world| {
+                world
+                    .resource::<AssetServer>()
+                    .set_load_priority(id, priority)
+                    .then_some(())
+            });
+        };
+        let cancelled: Handle<CoolText> =
+            asset_server.load_with_priority("cancelled.cool.ron", LoadPriority::High);
+        wait_until_queued(&mut app, cancelled.id().untyped(), LoadPriority::High);
+        drop(cancelled);
+
+        let mut handles = Vec::new();
+        for (path, priority) in [
+            ("low.cool.ron", LoadPriority::Low),
+            ("normal.cool.ron", LoadPriority::Normal),
+            ("high.cool.ron", LoadPriority::High),
+            ("raised.cool.ron", LoadPriority::Low),
+        ] {
+            let handle: Handle<CoolText> = asset_server.load_with_priority(path, priority);
+            wait_until_queued(&mut app, handle.id().untyped(), priority);
+            handles.push(handle);
+        }
+        assert!(asset_server.set_load_priority(&handles[3], LoadPriority::High));
+
+        for path in paths
+            .iter()
+            .filter(|path| **path != "reload_blocker.cool.ron")
+        {
+            reader.gate(path).add_permits(1);
+        }
+        run_app_until(&mut app, |world| {
+            let asset_server = world.resource::<AssetServer>();
+            handles
+                .iter()
+                .all(|handle| asset_server.is_loaded(handle))
+                .then_some(())
+        });
+        assert!(asset_server.is_loaded(&blocker));
+        assert_eq!(
+            read_paths(),
+            [
+                "blocker.cool.ron",
+                "high.cool.ron",
+                "raised.cool.ron",
+                "normal.cool.ron",
+                "low.cool.ron",
+            ]
+        );
+
+        // reloads wait for a load slot too
+        let reload_blocker: Handle<CoolText> = asset_server.load("reload_blocker.cool.ron");
+        run_app_until(&mut app, |_| (read_paths().len() == 6).then_some(()));
+        reader.gate("low.cool.ron").add_permits(1);
+        asset_server.reload("low.cool.ron");
+        wait_until_queued(&mut app, handles[0].id().untyped(), LoadPriority::Normal);
+        assert_eq!(read_paths().len(), 6);
+
+        reader.gate("reload_blocker.cool.ron").add_permits(1);
+        run_app_until(&mut app, |world| {
+            (read_paths().len() == 7 && world.resource::<AssetServer>().is_loaded(&reload_blocker))
+                .then_some(())
+        });
+        assert_eq!(read_paths()[6], "low.cool.ron");
+
+        // loads that already started are cancelled too, freeing their load slot
+        #[cfg(feature = "multi_threaded")]
+        {
+            let mid_read: Handle<CoolText> = asset_server.load("mid_read.cool.ron");
+            run_app_until(&mut app, |_| (read_paths().len() == 8).then_some(()));
+            drop(mid_read);
+
+            let after_cancel: Handle<CoolText> = asset_server.load("after_cancel.cool.ron");
+            reader.gate("after_cancel.cool.ron").add_permits(1);
+            run_app_until(&mut app, |world| {
+                world
+                    .resource::<AssetServer>()
+                    .is_loaded(&after_cancel)
+                    .then_some(())
+            });
+            assert_eq!(read_paths()[7], "mid_read.cool.ron");
+        }
+    }
+
     #[test]
     fn ignore_system_ambiguities_on_assets() {
         let mut app = App::new();
@@ -3,8 +3,8 @@ use crate::{
     loader_builders::{Deferred, NestedLoader, StaticTyped},
     meta::{AssetHash, AssetMeta, AssetMetaDyn, ProcessedInfoMinimal, Settings},
     path::AssetPath,
-    Asset, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle, UntypedAssetId,
-    UntypedHandle,
+    Asset, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle, LoadPriority,
+    UntypedAssetId, UntypedHandle,
 };
 use atomicow::CowArc;
 use bevy_ecs::world::World;
@@ -29,6 +29,13 @@ pub trait AssetLoader: Send + Sync + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The type of [error](`std::error::Error`) which could be encountered by this loader.
     type Error: Into<Box<dyn core::error::Error + Send + Sync + 'static>>;
+    /// The version of [`AssetLoader::Settings`], which is stored in `.meta` files.
+    ///
+    /// Increase it whenever the settings change in a way that breaks existing `.meta` files, and
+    /// register a migration from the previous version with
+    /// [`AssetApp::register_asset_loader_migration`](crate::AssetApp::register_asset_loader_migration)
+    /// so that those files can still be loaded.
+    const SETTINGS_VERSION: u32 = 0;
     /// Asynchronously loads [`AssetLoader::Asset`] (and any other labeled assets) from the bytes provided by [`Reader`].
     fn load(
         &self,
@@ -63,6 +70,8 @@ pub trait ErasedAssetLoader: Send + Sync + 'static {
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
     /// Returns the default meta value for the [`AssetLoader`] (erased as [`Box<dyn AssetMetaDyn>`]).
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
+    /// Returns the [`AssetLoader::SETTINGS_VERSION`] of the [`AssetLoader`].
+    fn settings_version(&self) -> u32;
     /// Returns the type name of the [`AssetLoader`].
     fn type_name(&self) -> &'static str;
     /// Returns the [`TypeId`] of the [`AssetLoader`].
@@ -116,6 +125,10 @@ where
         }))
     }
 
+    fn settings_version(&self) -> u32 {
+        L::SETTINGS_VERSION
+    }
+
     fn type_name(&self) -> &'static str {
         core::any::type_name::<L>()
     }
@@ -310,6 +323,16 @@ pub enum DeserializeMetaError {
     #[display("Failed to deserialize minimal asset meta: {_0:?}")]
     #[from(ignore)]
     DeserializeMinimal(SpannedError),
+    #[display(
+        "No migration is registered for the settings of '{type_name}' from version {version}"
+    )]
+    MissingMigration { type_name: String, version: u32 },
+    #[display("The settings version {version} of '{type_name}' is newer than the supported version {supported_version}")]
+    UnsupportedSettingsVersion {
+        type_name: String,
+        version: u32,
+        supported_version: u32,
+    },
 }
 
 /// A context that provides access to assets in [`AssetLoader`]s, tracks dependencies, and collects asset load state.
@@ -324,6 +347,7 @@ pub struct LoadContext<'a> {
     /// Direct dependencies used by this loader.
     pub(crate) loader_dependencies: HashMap<AssetPath<'static>, AssetHash>,
     pub(crate) labeled_assets: HashMap<CowArc<'static, str>, LabeledAsset>,
+    pub(crate) priority: LoadPriority,
 }
 
 impl<'a> LoadContext<'a> {
@@ -333,6 +357,7 @@ impl<'a> LoadContext<'a> {
         asset_path: AssetPath<'static>,
         should_load_dependencies: bool,
         populate_hashes: bool,
+        priority: LoadPriority,
     ) -> Self {
         Self {
             asset_server,
@@ -342,6 +367,7 @@ impl<'a> LoadContext<'a> {
             dependencies: HashSet::default(),
             loader_dependencies: HashMap::default(),
             labeled_assets: HashMap::default(),
+            priority,
         }
     }
 
@@ -380,6 +406,7 @@ impl<'a> LoadContext<'a> {
             self.asset_path.clone(),
             self.should_load_dependencies,
             self.populate_hashes,
+            self.priority,
         )
     }
 
@@ -472,6 +499,11 @@ impl<'a> LoadContext<'a> {
         &self.asset_path
     }
 
+    /// Gets the [`LoadPriority`] of this load, which the deferred loads of its dependencies inherit.
+    pub fn priority(&self) -> LoadPriority {
+        self.priority
+    }
+
     /// Reads the asset at the given path and returns its bytes
     pub async fn read_asset_bytes<'b, 'c>(
         &'b mut self,
@@ -538,6 +570,7 @@ impl<'a> LoadContext<'a> {
                 reader,
                 false,
                 self.populate_hashes,
+                self.priority,
             )
             .await
             .map_err(|error| LoadDirectError {
@@ -2,7 +2,7 @@ use crate::{
     self as bevy_asset, loader::AssetLoader, processor::Process, Asset, AssetPath,
     DeserializeMetaError, VisitAssetDependencies,
 };
-use bevy_utils::tracing::error;
+use bevy_utils::{tracing::error, HashMap};
 use downcast_rs::{impl_downcast, Downcast};
 use ron::ser::PrettyConfig;
 use serde::{Deserialize, Serialize};
@@ -19,6 +19,10 @@ pub struct AssetMeta<L: AssetLoader, P: Process> {
     /// The version of the meta format being used. This will change whenever a breaking change is made to
     /// the meta format.
     pub meta_format_version: String,
+    /// The version of the settings in [`AssetMeta::asset`]. See [`AssetLoader::SETTINGS_VERSION`] and
+    /// [`Process::SETTINGS_VERSION`].
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub settings_version: u32,
     /// Information produced by the [`AssetProcessor`] _after_ processing this asset.
     /// This will only exist alongside processed versions of assets. You should not manually set it in your asset source files.
     ///
@@ -31,8 +35,14 @@ pub struct AssetMeta<L: AssetLoader, P: Process> {
 
 impl<L: AssetLoader, P: Process> AssetMeta<L, P> {
     pub fn new(asset: AssetAction<L::Settings, P::Settings>) -> Self {
+        let settings_version = match &asset {
+            AssetAction::Load { .. } => L::SETTINGS_VERSION,
+            AssetAction::Process { .. } => P::SETTINGS_VERSION,
+            AssetAction::Ignore => 0,
+        };
         Self {
             meta_format_version: META_FORMAT_VERSION.to_string(),
+            settings_version,
             processed_info: None,
             asset,
         }
@@ -95,6 +105,8 @@ pub struct ProcessDependencyInfo {
 // using a type registry.
 #[derive(Serialize, Deserialize)]
 pub struct AssetMetaMinimal {
+    #[serde(default)]
+    pub settings_version: u32,
     pub asset: AssetActionMinimal,
 }
 
@@ -254,3 +266,103 @@ pub(crate) fn get_full_asset_hash(
     }
     *hasher.finalize().as_bytes()
 }
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+/// A function upgrading serialized [`AssetMeta`] from one settings version to the next.
+type MetaMigration = Box<dyn Fn(&[u8]) -> Result<Vec<u8>, DeserializeMetaError> + Send + Sync>;
+
+/// The registered migrations of the settings of [`AssetLoader`]s and [`Process`]ors, which upgrade
+/// `.meta` files written for older versions of the settings.
+#[derive(Default)]
+pub(crate) struct MetaMigrations {
+    /// The migrations from each settings version, keyed by the type name of the loader or processor.
+    migrations: HashMap<(&'static str, u32), MetaMigration>,
+    /// Whether migrated `.meta` files should be written back to their asset source.
+    pub(crate) rewrite: bool,
+}
+
+impl MetaMigrations {
+    /// Registers `migrate` to upgrade the settings of the loader or processor named `type_name`
+    /// from version `from_version`, of type `From`, to the next version, of type `To`.
+    pub(crate) fn register<From, To>(
+        &mut self,
+        type_name: &'static str,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) where
+        From: for<'a> Deserialize<'a>,
+        To: Serialize,
+    {
+        let migration = move |bytes: &[u8]| {
+            let meta: MigratedMeta<From> = ron::de::from_bytes(bytes)?;
+            let asset = match meta.asset {
+                AssetAction::Load { loader, settings } => AssetAction::Load {
+                    loader,
+                    settings: migrate(settings),
+                },
+                AssetAction::Process {
+                    processor,
+                    settings,
+                } => AssetAction::Process {
+                    processor,
+                    settings: migrate(settings),
+                },
+                AssetAction::Ignore => AssetAction::Ignore,
+            };
+            let meta = MigratedMeta {
+                meta_format_version: meta.meta_format_version,
+                settings_version: from_version + 1,
+                processed_info: meta.processed_info,
+                asset,
+            };
+            Ok(ron::ser::to_string_pretty(&meta, PrettyConfig::default())
+                .expect("type is convertible to ron")
+                .into_bytes())
+        };
+        self.migrations
+            .insert((type_name, from_version), Box::new(migration));
+    }
+
+    /// Upgrades the serialized `meta` of the loader or processor named `type_name` from settings
+    /// version `from_version` to `to_version`, returning [`None`] if it is already up to date.
+    pub(crate) fn migrate(
+        &self,
+        type_name: &str,
+        meta: &[u8],
+        from_version: u32,
+        to_version: u32,
+    ) -> Result<Option<Vec<u8>>, DeserializeMetaError> {
+        if from_version > to_version {
+            return Err(DeserializeMetaError::UnsupportedSettingsVersion {
+                type_name: type_name.to_string(),
+                version: from_version,
+                supported_version: to_version,
+            });
+        }
+        let mut migrated = None;
+        for version in from_version..to_version {
+            let migration = self.migrations.get(&(type_name, version)).ok_or_else(|| {
+                DeserializeMetaError::MissingMigration {
+                    type_name: type_name.to_string(),
+                    version,
+                }
+            })?;
+            migrated = Some(migration(migrated.as_deref().unwrap_or(meta))?);
+        }
+        Ok(migrated)
+    }
+}
+
+/// The [`AssetMeta`] of a migration, with settings of a previous version.
+#[derive(Serialize, Deserialize)]
+struct MigratedMeta<S> {
+    meta_format_version: String,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    settings_version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    processed_info: Option<ProcessedInfo>,
+    asset: AssetAction<S, S>,
+}
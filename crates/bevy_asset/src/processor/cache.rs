@@ -0,0 +1,246 @@
+use crate::{
+    io::{
+        AssetReader, AssetReaderError, AssetWriter, AssetWriterError, ErasedAssetReader,
+        ErasedAssetWriter,
+    },
+    meta::{AssetHash, ProcessedInfo, ProcessedInfoMinimal},
+    AssetPath,
+};
+use alloc::sync::Arc;
+use core::fmt::Write;
+use derive_more::derive::{Display, Error, From};
+use futures_lite::AsyncReadExt;
+use std::path::PathBuf;
+
+/// A content-addressed store of processed assets, which lets an [`AssetProcessor`] reuse the
+/// results of processing done on another machine or in another checkout instead of processing
+/// assets again.
+///
+/// Processed assets are keyed by the hash of their source asset and meta file (which contains the
+/// processor settings), combined with the full hashes of the assets they depended on during
+/// processing. The cache is stored in a directory of an [`AssetReader`] / [`AssetWriter`] pair,
+/// which can for example be a network share or a folder synced by CI:
+///
+/// - `dependencies/{hash}.ron` lists the process dependencies of the source asset with the given hash.
+/// - `assets/{full_hash}` and `assets/{full_hash}.meta` are a processed asset and its meta file.
+///
+/// Only assets that are processed by a [`Process`](crate::processor::Process) implementation are
+/// cached. Processors are assumed to be deterministic: if a processor's output changes without
+/// its settings changing, the cache directory should be cleared.
+///
+/// Set it with [`AssetProcessor::set_cache`] or [`AssetPlugin::processed_asset_cache_path`](crate::AssetPlugin::processed_asset_cache_path).
+///
+/// [`AssetProcessor`]: crate::processor::AssetProcessor
+/// [`AssetProcessor::set_cache`]: crate::processor::AssetProcessor::set_cache
+pub struct ProcessedAssetCache {
+    reader: Box<dyn ErasedAssetReader>,
+    writer: Box<dyn ErasedAssetWriter>,
+}
+
+/// A processed asset read from a [`ProcessedAssetCache`].
+pub(crate) struct CachedProcessedAsset {
+    pub(crate) info: ProcessedInfo,
+    pub(crate) asset_bytes: Vec<u8>,
+    pub(crate) meta_bytes: Vec<u8>,
+}
+
+/// An error that occurs while reading from or writing to a [`ProcessedAssetCache`].
+#[derive(Error, Display, Debug, From)]
+pub enum ProcessedAssetCacheError {
+    #[display("failed to read from the processed asset cache: {_0}")]
+    AssetReaderError(AssetReaderError),
+    #[display("failed to write to the processed asset cache: {_0}")]
+    AssetWriterError(AssetWriterError),
+    #[display("failed to deserialize a processed asset cache entry: {_0}")]
+    DeserializeError(ron::error::SpannedError),
+    #[display("the processed asset cache entry {} is invalid", hex(_0))]
+    #[error(ignore)]
+    #[from(ignore)]
+    InvalidEntry(AssetHash),
+}
+
+impl ProcessedAssetCache {
+    /// Creates a cache stored in the root of the given `reader` and `writer`, which should
+    /// access the same location.
+    pub fn new(reader: impl AssetReader, writer: impl AssetWriter) -> Self {
+        Self {
+            reader: Box::new(reader),
+            writer: Box::new(writer),
+        }
+    }
+
+    /// Creates a cache stored in the directory at `path`, relative to the
+    /// [base path](crate::io::file::FileAssetReader::get_base_path) unless it is absolute. The
+    /// directory is created if it doesn't exist.
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Self {
+        use crate::io::file::{FileAssetReader, FileAssetWriter};
+        let path = path.as_ref();
+        Self::new(FileAssetReader::new(path), FileAssetWriter::new(path, true))
+    }
+
+    /// Returns the cached processed asset whose source asset has the given `hash`, if there is
+    /// one that was processed with the same dependencies.
+    ///
+    /// `dependency_hash` is called with the path of each process dependency of the asset, and
+    /// should return its current full hash, or [`None`] if it has no processed version.
+    pub(crate) async fn get<F, Fut>(
+        &self,
+        hash: AssetHash,
+        mut dependency_hash: F,
+    ) -> Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError>
+    where
+        F: FnMut(AssetPath<'static>) -> Fut,
+        Fut: core::future::Future<Output = Option<AssetHash>>,
+    {
+        let Some(dependencies) = self.read(&dependencies_path(hash)).await? else {
+            return Ok(None);
+        };
+        let dependencies: Vec<AssetPath<'static>> = ron::de::from_bytes(&dependencies)?;
+        let mut dependency_hashes = Vec::with_capacity(dependencies.len());
+        for dependency in dependencies {
+            let Some(dependency_hash) = dependency_hash(dependency).await else {
+                return Ok(None);
+            };
+            dependency_hashes.push(dependency_hash);
+        }
+        let full_hash = crate::meta::get_full_asset_hash(hash, dependency_hashes.into_iter());
+
+        let Some(meta_bytes) = self.read(&meta_path(full_hash)).await? else {
+            return Ok(None);
+        };
+        let Some(asset_bytes) = self.read(&asset_path(full_hash)).await? else {
+            return Ok(None);
+        };
+        let minimal: ProcessedInfoMinimal = ron::de::from_bytes(&meta_bytes)?;
+        let info = minimal
+            .processed_info
+            .filter(|info| info.hash == hash && info.full_hash == full_hash)
+            .ok_or(ProcessedAssetCacheError::InvalidEntry(full_hash))?;
+        Ok(Some(CachedProcessedAsset {
+            info,
+            asset_bytes,
+            meta_bytes,
+        }))
+    }
+
+    /// Stores a processed asset, described by its `info`, in the cache.
+    pub(crate) async fn insert(
+        &self,
+        info: &ProcessedInfo,
+        asset_bytes: &[u8],
+        meta_bytes: &[u8],
+    ) -> Result<(), ProcessedAssetCacheError> {
+        self.writer
+            .write_bytes(&asset_path(info.full_hash), asset_bytes)
+            .await?;
+        self.writer
+            .write_bytes(&meta_path(info.full_hash), meta_bytes)
+            .await?;
+        // The dependencies are written last, so that readers never find dependencies leading to
+        // an incomplete entry.
+        let dependencies = info
+            .process_dependencies
+            .iter()
+            .map(|dependency| &dependency.path)
+            .collect::<Vec<_>>();
+        let dependencies =
+            ron::ser::to_string(&dependencies).expect("asset paths can always be serialized");
+        self.writer
+            .write_bytes(&dependencies_path(info.hash), dependencies.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn read(&self, path: &std::path::Path) -> Result<Option<Vec<u8>>, AssetReaderError> {
+        let mut reader = match self.reader.read(path).await {
+            Ok(reader) => reader,
+            Err(AssetReaderError::NotFound(_)) => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|err| AssetReaderError::Io(Arc::new(err)))?;
+        Ok(Some(bytes))
+    }
+}
+
+fn dependencies_path(hash: AssetHash) -> PathBuf {
+    PathBuf::from(format!("dependencies/{}.ron", hex(&hash)))
+}
+
+fn asset_path(full_hash: AssetHash) -> PathBuf {
+    PathBuf::from(format!("assets/{}", hex(&full_hash)))
+}
+
+fn meta_path(full_hash: AssetHash) -> PathBuf {
+    PathBuf::from(format!("assets/{}.meta", hex(&full_hash)))
+}
+
+fn hex(hash: &AssetHash) -> String {
+    hash.iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            write!(hex, "{byte:02x}").unwrap();
+            hex
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProcessedAssetCache;
+    use crate::{
+        io::memory::{Dir, MemoryAssetReader, MemoryAssetWriter},
+        meta::{get_asset_hash, get_full_asset_hash, ProcessDependencyInfo, ProcessedInfo},
+        AssetPath,
+    };
+    use bevy_tasks::block_on;
+
+    #[test]
+    fn round_trip() {
+        let dir = Dir::default();
+        let cache = ProcessedAssetCache::new(
+            MemoryAssetReader { root: dir.clone() },
+            MemoryAssetWriter { root: dir },
+        );
+
+        let hash = get_asset_hash(b"meta", b"asset");
+        let dependency = AssetPath::from("dependency.png");
+        let dependency_hash = get_asset_hash(b"", b"dependency");
+        let info = ProcessedInfo {
+            hash,
+            full_hash: get_full_asset_hash(hash, [dependency_hash].into_iter()),
+            process_dependencies: vec![ProcessDependencyInfo {
+                full_hash: dependency_hash,
+                path: dependency.clone(),
+            }],
+        };
+        let meta = ron::ser::to_string(&crate::meta::ProcessedInfoMinimal {
+            processed_info: Some(info.clone()),
+        })
+        .unwrap();
+
+        let get = |hash, dependency_hash| {
+            let dependency = dependency.clone();
+            block_on(cache.get(hash, move |path| {
+                assert_eq!(path, dependency);
+                async move { dependency_hash }
+            }))
+            .unwrap()
+        };
+
+        assert!(get(hash, Some(dependency_hash)).is_none());
+        block_on(cache.insert(&info, b"processed", meta.as_bytes())).unwrap();
+
+        let cached = get(hash, Some(dependency_hash)).unwrap();
+        assert_eq!(cached.asset_bytes, b"processed");
+        assert_eq!(cached.meta_bytes, meta.as_bytes());
+        assert_eq!(cached.info.full_hash, info.full_hash);
+
+        // a changed or missing dependency is a cache miss
+        assert!(get(hash, Some(get_asset_hash(b"", b"changed"))).is_none());
+        assert!(get(hash, None).is_none());
+        assert!(get(get_asset_hash(b"meta", b"other"), Some(dependency_hash)).is_none());
+    }
+}
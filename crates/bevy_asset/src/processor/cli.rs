@@ -0,0 +1,222 @@
+use crate::{
+    processor::{AssetProcessor, ProcessorState},
+    AssetMode, AssetPlugin,
+};
+use bevy_app::{App, AppExit, Plugin, Update};
+use bevy_ecs::prelude::*;
+use bevy_utils::tracing::{error, info, warn};
+use derive_more::derive::{Display, Error};
+
+/// The usage message of [`AssetProcessorCli`].
+pub const ASSET_PROCESSOR_CLI_USAGE: &str = "\
+Processes the assets of a source directory and writes them to a processed directory.
+
+Options:
+    --source <PATH>        The directory of the source assets [default: assets]
+    --destination <PATH>   The directory to write processed assets to [default: imported_assets/Default]
+    --cache <PATH>         A directory to store and reuse processed assets in
+    --watch                Keep running and reprocess assets when they change
+    --help                 Print this message";
+
+/// Runs the [`AssetProcessor`] of a headless app as a command line tool, to process assets ahead
+/// of time without running the game itself, for example as part of a build pipeline.
+///
+/// Once all assets have been processed, the app exits, with an error code if any asset failed to
+/// process. With `--watch`, the app keeps running and reprocesses assets when they change instead,
+/// which requires the `file_watcher` cargo feature.
+///
+/// This requires the `asset_processor` and `multi_threaded` cargo features. Processors and the
+/// loaders they use are registered as usual:
+///
+/// ```no_run
+/// # use bevy_app::{App, AppExit};
+/// # use bevy_asset::processor::AssetProcessorCli;
+/// fn main() -> AppExit {
+///     let cli = AssetProcessorCli::from_env();
+///     App::new()
+///         .add_plugins((cli.asset_plugin(), cli))
+///         // register asset loaders and processors here
+///         .run()
+/// }
+/// ```
+///
+/// Along with the plugins needed by the processors, the app should include a task pool and a
+/// schedule runner, such as the ones of `MinimalPlugins`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AssetProcessorCli {
+    /// The directory of the source assets, overriding [`AssetPlugin::file_path`].
+    pub source_path: Option<String>,
+    /// The directory processed assets are written to, overriding
+    /// [`AssetPlugin::processed_file_path`].
+    pub processed_path: Option<String>,
+    /// The directory of the [`ProcessedAssetCache`](super::ProcessedAssetCache) to use, if any.
+    pub cache_path: Option<String>,
+    /// Whether to keep running and reprocess assets when they change.
+    pub watch: bool,
+}
+
+/// An error that occurs when parsing the arguments of an [`AssetProcessorCli`].
+#[derive(Error, Display, Debug, PartialEq, Eq)]
+pub enum AssetProcessorCliError {
+    /// The `--help` flag was passed.
+    #[display("{ASSET_PROCESSOR_CLI_USAGE}")]
+    Help,
+    #[display("unknown argument '{_0}'")]
+    #[error(ignore)]
+    UnknownArgument(String),
+    #[display("missing value for '{_0}'")]
+    #[error(ignore)]
+    MissingValue(String),
+}
+
+impl AssetProcessorCli {
+    /// Parses the command line arguments of the current process.
+    ///
+    /// Prints the usage message and exits the process if the arguments are invalid or `--help`
+    /// was passed.
+    pub fn from_env() -> Self {
+        match Self::parse(std::env::args().skip(1)) {
+            Ok(cli) => cli,
+            Err(AssetProcessorCliError::Help) => {
+                println!("{ASSET_PROCESSOR_CLI_USAGE}");
+                std::process::exit(0);
+            }
+            Err(err) => {
+                eprintln!("error: {err}\n\n{ASSET_PROCESSOR_CLI_USAGE}");
+                std::process::exit(2);
+            }
+        }
+    }
+
+    /// Parses the given command line arguments, which shouldn't include the name of the binary.
+    pub fn parse(
+        args: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Self, AssetProcessorCliError> {
+        let mut cli = Self::default();
+        let mut args = args.into_iter().map(Into::into);
+        while let Some(arg) = args.next() {
+            let (name, value) = match arg.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let path = match name.as_str() {
+                "--source" => &mut cli.source_path,
+                "--destination" => &mut cli.processed_path,
+                "--cache" => &mut cli.cache_path,
+                "--watch" if value.is_none() => {
+                    cli.watch = true;
+                    continue;
+                }
+                "--help" | "-h" => return Err(AssetProcessorCliError::Help),
+                _ => return Err(AssetProcessorCliError::UnknownArgument(name)),
+            };
+            match value.or_else(|| args.next()) {
+                Some(value) => *path = Some(value),
+                None => return Err(AssetProcessorCliError::MissingValue(name)),
+            }
+        }
+        Ok(cli)
+    }
+
+    /// Returns the [`AssetPlugin`] to add to the app, running the [`AssetProcessor`] over the
+    /// configured directories.
+    pub fn asset_plugin(&self) -> AssetPlugin {
+        let default = AssetPlugin::default();
+        AssetPlugin {
+            file_path: self.source_path.clone().unwrap_or(default.file_path),
+            processed_file_path: self
+                .processed_path
+                .clone()
+                .unwrap_or(default.processed_file_path),
+            mode: AssetMode::Processed,
+            processed_asset_cache_path: self.cache_path.clone(),
+            // the processed assets are not loaded by this app
+            watch_for_changes_override: Some(false),
+            ..default
+        }
+    }
+}
+
+impl Plugin for AssetProcessorCli {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<AssetProcessor>() {
+            error!(
+                "AssetProcessorCli requires the `asset_processor` cargo feature and the AssetPlugin \
+                returned by AssetProcessorCli::asset_plugin"
+            );
+            app.add_systems(Update, |mut exit: EventWriter<AppExit>| {
+                exit.send(AppExit::error());
+            });
+            return;
+        }
+        if self.watch && !cfg!(feature = "file_watcher") {
+            warn!("Watching for asset changes requires the `file_watcher` cargo feature");
+        }
+
+        let watch = self.watch;
+        app.add_systems(
+            Update,
+            move |processor: Res<AssetProcessor>,
+                  mut finished: Local<bool>,
+                  mut exit: EventWriter<AppExit>| {
+                let state = bevy_tasks::block_on(processor.get_state());
+                let was_finished =
+                    core::mem::replace(&mut *finished, state == ProcessorState::Finished);
+                if *finished && !was_finished {
+                    let failed = report_failed_assets(&processor);
+                    if !watch {
+                        exit.send(if failed {
+                            AppExit::error()
+                        } else {
+                            AppExit::Success
+                        });
+                    }
+                }
+            },
+        );
+    }
+}
+
+/// Logs the assets that failed to process, returning `true` if there are any.
+fn report_failed_assets(processor: &AssetProcessor) -> bool {
+    let failed_assets = bevy_tasks::block_on(processor.get_failed_assets());
+    if failed_assets.is_empty() {
+        info!("All assets were processed successfully");
+        return false;
+    }
+    for (path, err) in &failed_assets {
+        error!("Failed to process asset {path}: {err}");
+    }
+    error!("{} asset(s) failed to process", failed_assets.len());
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetProcessorCli, AssetProcessorCliError};
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            AssetProcessorCli::parse(["--source", "raw", "--destination=baked", "--watch"]),
+            Ok(AssetProcessorCli {
+                source_path: Some("raw".into()),
+                processed_path: Some("baked".into()),
+                cache_path: None,
+                watch: true,
+            })
+        );
+        assert_eq!(
+            AssetProcessorCli::parse(["--cache"]),
+            Err(AssetProcessorCliError::MissingValue("--cache".into()))
+        );
+        assert_eq!(
+            AssetProcessorCli::parse(["--watch=yes"]),
+            Err(AssetProcessorCliError::UnknownArgument("--watch".into()))
+        );
+        assert_eq!(
+            AssetProcessorCli::parse(["--help"]),
+            Err(AssetProcessorCliError::Help)
+        );
+    }
+}
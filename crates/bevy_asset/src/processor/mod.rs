@@ -37,14 +37,19 @@
 //!
 //! In most cases, [`LoadTransformAndSave`] should be sufficient.
 
+mod cache;
+mod cli;
 mod log;
 mod process;
 
+pub use cache::*;
+pub use cli::*;
 pub use log::*;
 pub use process::*;
 
 use crate::{
     io::{
+        archive::{AssetArchiveError, AssetArchiveSettings, AssetArchiveWriter},
         AssetReaderError, AssetSource, AssetSourceBuilders, AssetSourceEvent, AssetSourceId,
         AssetSources, AssetWriterError, ErasedAssetReader, ErasedAssetWriter,
         MissingAssetSourceError,
@@ -102,6 +107,7 @@ pub struct AssetProcessorData {
     processors: RwLock<HashMap<&'static str, Arc<dyn ErasedProcessor>>>,
     /// Default processors for file extensions
     default_processors: RwLock<HashMap<Box<str>, &'static str>>,
+    cache: RwLock<Option<Arc<ProcessedAssetCache>>>,
     state: async_lock::RwLock<ProcessorState>,
     sources: AssetSources,
     initialized_sender: async_broadcast::Sender<()>,
@@ -153,6 +159,17 @@ impl AssetProcessor {
         *self.data.state.read().await
     }
 
+    /// Returns the assets that failed to process the last time they were processed, along with
+    /// their errors.
+    pub async fn get_failed_assets(&self) -> Vec<(AssetPath<'static>, Arc<ProcessError>)> {
+        let infos = self.data.asset_infos.read().await;
+        infos
+            .infos
+            .iter()
+            .filter_map(|(path, info)| Some((path.clone(), info.error.clone()?)))
+            .collect()
+    }
+
     /// Retrieves the [`AssetSource`] for this processor
     #[inline]
     pub fn get_source<'a>(
@@ -167,6 +184,27 @@ impl AssetProcessor {
         &self.data.sources
     }
 
+    /// Packs every processed asset of the given source, along with its meta, into an asset archive
+    /// written to `writer`, once processing has finished.
+    ///
+    /// The archive can then be loaded with [`AssetSourceBuilder::archive`](crate::io::AssetSourceBuilder::archive)
+    /// or an [`ArchiveAssetReader`](crate::io::archive::ArchiveAssetReader).
+    pub async fn write_archive<'a>(
+        &self,
+        source: impl Into<AssetSourceId<'a>>,
+        settings: AssetArchiveSettings,
+        writer: &mut (impl futures_io::AsyncWrite + Unpin + ?Sized),
+    ) -> Result<(), AssetArchiveError> {
+        let source = self.get_source(source)?;
+        self.data.wait_until_finished().await;
+        let mut archive = AssetArchiveWriter::new(writer, settings);
+        archive
+            .add_directory(source.processed_reader()?, Path::new(""))
+            .await?;
+        archive.finish().await?;
+        Ok(())
+    }
+
     /// Logs an unrecoverable error. On the next run of the processor, all assets will be regenerated. This should only be used as a last resort.
     /// Every call to this should be considered with scrutiny and ideally replaced with something more granular.
     async fn log_unrecoverable(&self) {
@@ -542,6 +580,32 @@ impl AssetProcessor {
         self.data.processors.read().get(key).cloned()
     }
 
+    /// Registers a migration upgrading the settings of the processor `P` in `.meta` files from
+    /// `from_version`, of type `From`, to the next version, of type `To`.
+    ///
+    /// When a `.meta` file is read with a settings version lower than [`Process::SETTINGS_VERSION`],
+    /// the migrations from its version up to the current one are applied in order.
+    pub fn register_processor_migration<P: Process, From, To>(
+        &self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) where
+        From: for<'a> serde::Deserialize<'a>,
+        To: serde::Serialize,
+    {
+        self.server.data.loaders.write().migrations.register(
+            core::any::type_name::<P>(),
+            from_version,
+            migrate,
+        );
+    }
+
+    /// Sets the [`ProcessedAssetCache`] used to reuse processed assets across machines and
+    /// checkouts. This should be set before the processor starts.
+    pub fn set_cache(&self, cache: ProcessedAssetCache) {
+        *self.data.cache.write() = Some(Arc::new(cache));
+    }
+
     /// Returns the processor with the given `processor_type_name`, if it exists.
     pub fn get_processor(&self, processor_type_name: &str) -> Option<Arc<dyn ErasedProcessor>> {
         let processors = self.data.processors.read();
@@ -757,20 +821,44 @@ impl AssetProcessor {
         let mut byte_reader = reader.read(path).await.map_err(reader_err)?;
 
         let (mut source_meta, meta_bytes, processor) = match reader.read_meta_bytes(path).await {
-            Ok(meta_bytes) => {
+            Ok(mut meta_bytes) => {
                 let minimal: AssetMetaMinimal = ron::de::from_bytes(&meta_bytes).map_err(|e| {
                     ProcessError::DeserializeMetaError(DeserializeMetaError::DeserializeMinimal(e))
                 })?;
                 let (meta, processor) = match minimal.asset {
-                    AssetActionMinimal::Load { loader } => {
-                        let loader = server.get_asset_loader_with_type_name(&loader).await?;
+                    AssetActionMinimal::Load {
+                        loader: loader_name,
+                    } => {
+                        let loader = server.get_asset_loader_with_type_name(&loader_name).await?;
+                        let current_version = loader.settings_version();
+                        self.migrate_meta(
+                            source,
+                            asset_path,
+                            &loader_name,
+                            &mut meta_bytes,
+                            minimal.settings_version,
+                            current_version,
+                        )
+                        .await?;
                         let meta = loader.deserialize_meta(&meta_bytes)?;
                         (meta, None)
                     }
-                    AssetActionMinimal::Process { processor } => {
-                        let processor = self
-                            .get_processor(&processor)
-                            .ok_or_else(|| ProcessError::MissingProcessor(processor))?;
+                    AssetActionMinimal::Process {
+                        processor: processor_name,
+                    } => {
+                        let processor = self.get_processor(&processor_name).ok_or_else(|| {
+                            ProcessError::MissingProcessor(processor_name.clone())
+                        })?;
+                        let current_version = processor.settings_version();
+                        self.migrate_meta(
+                            source,
+                            asset_path,
+                            &processor_name,
+                            &mut meta_bytes,
+                            minimal.settings_version,
+                            current_version,
+                        )
+                        .await?;
                         let meta = processor.deserialize_meta(&meta_bytes)?;
                         (meta, Some(processor))
                     }
@@ -872,6 +960,23 @@ impl AssetProcessor {
         // TODO: this class of failure can be recovered via re-processing + smarter log validation that allows for duplicate transactions in the event of failures
         self.log_begin_processing(asset_path).await;
         if let Some(processor) = processor {
+            let cache = self.data.cache.read().clone();
+            if let Some(cache) = &cache {
+                if let Some(cached) = self.get_cached_asset(cache, asset_path, new_hash).await {
+                    debug!("Using cached processed asset for {:?}", asset_path);
+                    processed_writer
+                        .write_bytes(path, &cached.asset_bytes)
+                        .await
+                        .map_err(writer_err)?;
+                    processed_writer
+                        .write_meta_bytes(path, &cached.meta_bytes)
+                        .await
+                        .map_err(writer_err)?;
+                    self.log_end_processing(asset_path).await;
+                    return Ok(ProcessResult::Processed(cached.info));
+                }
+            }
+
             let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
             let mut processed_meta = {
                 let mut context =
@@ -903,6 +1008,16 @@ impl AssetProcessor {
                 .write_meta_bytes(path, &meta_bytes)
                 .await
                 .map_err(writer_err)?;
+            if let Some(cache) = &cache {
+                self.insert_cached_asset(
+                    cache,
+                    source,
+                    asset_path,
+                    &new_processed_info,
+                    &meta_bytes,
+                )
+                .await;
+            }
         } else {
             processed_writer
                 .write_bytes(path, &asset_bytes)
@@ -920,6 +1035,88 @@ impl AssetProcessor {
         Ok(ProcessResult::Processed(new_processed_info))
     }
 
+    /// Upgrades the source `meta_bytes` of the asset at `asset_path` to the current settings
+    /// version of the loader or processor named `type_name`, writing them back to the source if
+    /// configured to.
+    async fn migrate_meta(
+        &self,
+        source: &AssetSource,
+        asset_path: &AssetPath<'static>,
+        type_name: &str,
+        meta_bytes: &mut Vec<u8>,
+        version: u32,
+        current_version: u32,
+    ) -> Result<(), DeserializeMetaError> {
+        let Some(migrated) =
+            self.server
+                .migrate_meta(type_name, meta_bytes, version, current_version)?
+        else {
+            return Ok(());
+        };
+        // The hash of the asset is computed from the migrated meta, so rewriting it doesn't cause
+        // the asset to be processed again.
+        if self.server.rewrite_migrated_meta() {
+            crate::server::rewrite_meta(source, asset_path, &migrated).await;
+        }
+        *meta_bytes = migrated;
+        Ok(())
+    }
+
+    /// Returns the processed version of the asset at `asset_path`, whose source has the given
+    /// `hash`, from the `cache`, if its process dependencies are unchanged.
+    async fn get_cached_asset(
+        &self,
+        cache: &ProcessedAssetCache,
+        asset_path: &AssetPath<'static>,
+        hash: AssetHash,
+    ) -> Option<CachedProcessedAsset> {
+        let dependency_hash = |path: AssetPath<'static>| async move {
+            if self.data.wait_until_processed(path.clone()).await != ProcessStatus::Processed {
+                return None;
+            }
+            let infos = self.data.asset_infos.read().await;
+            infos
+                .get(&path)
+                .and_then(|info| info.processed_info.as_ref())
+                .map(|info| info.full_hash)
+        };
+        match cache.get(hash, dependency_hash).await {
+            Ok(cached) => cached,
+            Err(err) => {
+                warn!("Failed to read {asset_path} from the processed asset cache: {err}");
+                None
+            }
+        }
+    }
+
+    /// Stores the asset at `asset_path`, which has just been processed, in the `cache`.
+    async fn insert_cached_asset(
+        &self,
+        cache: &ProcessedAssetCache,
+        source: &AssetSource,
+        asset_path: &AssetPath<'static>,
+        info: &ProcessedInfo,
+        meta_bytes: &[u8],
+    ) {
+        // The processed asset was streamed to its writer, so it is read back from the processed
+        // source rather than kept in memory while processing.
+        let Ok(reader) = source.processed_reader() else {
+            return;
+        };
+        let result = async {
+            let mut asset_reader = reader.read(asset_path.path()).await?;
+            let mut asset_bytes = Vec::new();
+            asset_reader
+                .read_to_end(&mut asset_bytes)
+                .await
+                .map_err(|err| AssetReaderError::Io(err.into()))?;
+            cache.insert(info, &asset_bytes, meta_bytes).await
+        };
+        if let Err(err) = result.await {
+            warn!("Failed to store {asset_path} in the processed asset cache: {err}");
+        }
+    }
+
     async fn validate_transaction_log_and_recover(&self) {
         if let Err(err) = ProcessorTransactionLog::validate().await {
             let state_is_valid = match err {
@@ -1027,6 +1224,7 @@ impl AssetProcessorData {
             processors: Default::default(),
             asset_infos: Default::default(),
             default_processors: Default::default(),
+            cache: Default::default(),
         }
     }
 
@@ -1092,6 +1290,7 @@ struct InstrumentedAssetProcessor<T>(T);
 impl<T: Process> Process for InstrumentedAssetProcessor<T> {
     type Settings = T::Settings;
     type OutputLoader = T::OutputLoader;
+    const SETTINGS_VERSION: u32 = T::SETTINGS_VERSION;
 
     fn process(
         &self,
@@ -1104,6 +1303,7 @@ impl<T: Process> Process for InstrumentedAssetProcessor<T> {
         // Change the processor type for the `AssetMeta`, which works because we share the `Settings` type.
         let meta = AssetMeta {
             meta_format_version: meta.meta_format_version,
+            settings_version: meta.settings_version,
             processed_info: meta.processed_info,
             asset: meta.asset,
         };
@@ -1139,6 +1339,8 @@ pub(crate) struct ProcessorAssetInfo {
     /// Paths of assets that depend on this asset when they are being processed.
     dependents: HashSet<AssetPath<'static>>,
     status: Option<ProcessStatus>,
+    /// The error of the last attempt to process this asset, if it failed.
+    error: Option<Arc<ProcessError>>,
     /// A lock that controls read/write access to processed asset files. The lock is shared for both the asset bytes and the meta bytes.
     /// _This lock must be locked whenever a read or write to processed assets occurs_
     /// There are scenarios where processed assets (and their metadata) are being read and written in multiple places at once:
@@ -1164,6 +1366,7 @@ impl Default for ProcessorAssetInfo {
             dependents: Default::default(),
             file_transaction_lock: Default::default(),
             status: None,
+            error: None,
             status_sender,
             status_receiver,
         }
@@ -1235,6 +1438,9 @@ impl ProcessorAssetInfos {
         asset_path: AssetPath<'static>,
         result: Result<ProcessResult, ProcessError>,
     ) {
+        if let Some(info) = self.infos.get_mut(&asset_path) {
+            info.error = None;
+        }
         match result {
             Ok(ProcessResult::Processed(processed_info)) => {
                 debug!("Finished processing \"{:?}\"", asset_path);
@@ -1290,7 +1496,7 @@ impl ProcessorAssetInfos {
                 error!("Failed to process asset {asset_path}: {err}");
                 // if this failed because a dependency could not be loaded, make sure it is reprocessed if that dependency is reprocessed
                 if let ProcessError::AssetLoadError(AssetLoadError::AssetLoaderError(dependency)) =
-                    err
+                    &err
                 {
                     let info = self.get_mut(&asset_path).expect("info should exist");
                     info.processed_info = Some(ProcessedInfo {
@@ -1302,6 +1508,7 @@ impl ProcessorAssetInfos {
                 }
 
                 let info = self.get_mut(&asset_path).expect("info should exist");
+                info.error = Some(Arc::new(err));
                 info.update_status(ProcessStatus::Failed).await;
             }
         }
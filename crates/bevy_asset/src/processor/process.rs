@@ -7,7 +7,7 @@ use crate::{
     processor::AssetProcessor,
     saver::{AssetSaver, SavedAsset},
     transformer::{AssetTransformer, IdentityAssetTransformer, TransformedAsset},
-    AssetLoadError, AssetLoader, AssetPath, DeserializeMetaError, ErasedLoadedAsset,
+    AssetLoadError, AssetLoader, AssetPath, DeserializeMetaError, ErasedLoadedAsset, LoadPriority,
     MissingAssetLoaderForExtensionError, MissingAssetLoaderForTypeNameError,
 };
 use bevy_utils::{BoxedFuture, ConditionalSendFuture};
@@ -25,6 +25,13 @@ pub trait Process: Send + Sync + Sized + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The [`AssetLoader`] that will be used to load the final processed asset.
     type OutputLoader: AssetLoader;
+    /// The version of [`Process::Settings`], which is stored in `.meta` files.
+    ///
+    /// Increase it whenever the settings change in a way that breaks existing `.meta` files, and
+    /// register a migration from the previous version with
+    /// [`AssetApp::register_asset_processor_migration`](crate::AssetApp::register_asset_processor_migration)
+    /// so that those files can still be processed.
+    const SETTINGS_VERSION: u32 = 0;
     /// Processes the asset stored on `context` in some way using the settings stored on `meta`. The results are written to `writer`. The
     /// final written processed asset is loadable using [`Process::OutputLoader`]. This load will use the returned [`AssetLoader::Settings`].
     fn process(
@@ -230,6 +237,8 @@ pub trait ErasedProcessor: Send + Sync {
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
     /// Returns the default type-erased [`AssetMeta`] for the underlying [`Process`] impl.
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
+    /// Returns the [`Process::SETTINGS_VERSION`] of the underlying [`Process`] impl.
+    fn settings_version(&self) -> u32;
 }
 
 impl<P: Process> ErasedProcessor for P {
@@ -264,6 +273,10 @@ impl<P: Process> ErasedProcessor for P {
             settings: P::Settings::default(),
         }))
     }
+
+    fn settings_version(&self) -> u32 {
+        P::SETTINGS_VERSION
+    }
 }
 
 /// Provides scoped data access to the [`AssetProcessor`].
@@ -327,6 +340,7 @@ impl<'a> ProcessContext<'a> {
                 &mut reader,
                 false,
                 true,
+                LoadPriority::Normal,
             )
             .await?;
         for (path, full_hash) in &loaded_asset.loader_dependencies {
@@ -1,10 +1,13 @@
 use crate::{
-    io::Writer, meta::Settings, transformer::TransformedAsset, Asset, AssetLoader,
-    ErasedLoadedAsset, Handle, LabeledAsset, UntypedHandle,
+    io::{AssetWriterError, MissingAssetSourceError, MissingAssetWriterError, Writer},
+    meta::{AssetAction, AssetMeta, AssetMetaDyn, Settings},
+    transformer::TransformedAsset,
+    Asset, AssetLoader, AssetPath, ErasedLoadedAsset, Handle, LabeledAsset, UntypedHandle,
 };
 use atomicow::CowArc;
 use bevy_utils::{BoxedFuture, ConditionalSendFuture, HashMap};
-use core::{borrow::Borrow, hash::Hash, ops::Deref};
+use core::{any::Any, borrow::Borrow, hash::Hash, ops::Deref};
+use derive_more::derive::{Display, Error, From};
 use serde::{Deserialize, Serialize};
 
 /// Saves an [`Asset`] of a given [`AssetSaver::Asset`] type. [`AssetSaver::OutputLoader`] will then be used to load the saved asset
@@ -73,10 +76,81 @@ impl<S: AssetSaver> ErasedAssetSaver for S {
     }
 }
 
+/// Saves runtime [`Asset`] values with an [`AssetSaver`], for [`AssetServer::save`](crate::AssetServer::save).
+pub(crate) trait ErasedRuntimeAssetSaver: Send + Sync + 'static {
+    /// Saves `value`, which must be an [`AssetSaver::Asset`], along with its `labeled_assets`, with
+    /// the default settings of the saver, returning the meta to load the saved asset with.
+    fn save_value<'a>(
+        &'a self,
+        writer: &'a mut Writer,
+        value: &'a (dyn Any + Send + Sync),
+        labeled_assets: Option<&'a HashMap<CowArc<'static, str>, LabeledAsset>>,
+    ) -> BoxedFuture<
+        'a,
+        Result<Box<dyn AssetMetaDyn>, Box<dyn core::error::Error + Send + Sync + 'static>>,
+    >;
+}
+
+impl<S: AssetSaver> ErasedRuntimeAssetSaver for S {
+    fn save_value<'a>(
+        &'a self,
+        writer: &'a mut Writer,
+        value: &'a (dyn Any + Send + Sync),
+        labeled_assets: Option<&'a HashMap<CowArc<'static, str>, LabeledAsset>>,
+    ) -> BoxedFuture<
+        'a,
+        Result<Box<dyn AssetMetaDyn>, Box<dyn core::error::Error + Send + Sync + 'static>>,
+    > {
+        Box::pin(async move {
+            let value = value
+                .downcast_ref::<S::Asset>()
+                .expect("savers should only be called with their asset type");
+            let saved_asset = SavedAsset {
+                value,
+                labeled_assets,
+            };
+            let settings = match self
+                .save(writer, saved_asset, &S::Settings::default())
+                .await
+            {
+                Ok(settings) => settings,
+                Err(err) => return Err(err.into()),
+            };
+            let meta: Box<dyn AssetMetaDyn> =
+                Box::new(AssetMeta::<S::OutputLoader, ()>::new(AssetAction::Load {
+                    loader: core::any::type_name::<S::OutputLoader>().to_string(),
+                    settings,
+                }));
+            Ok(meta)
+        })
+    }
+}
+
+/// An error that occurs when saving an asset with [`AssetServer::save`](crate::AssetServer::save).
+#[derive(Error, Display, Debug, From)]
+pub enum SaveAssetError {
+    #[display("No AssetSaver is registered for assets of type {_0}")]
+    #[error(ignore)]
+    #[from(ignore)]
+    MissingAssetSaver(&'static str),
+    #[display("Cannot save an asset to the labeled path '{_0}'")]
+    #[error(ignore)]
+    #[from(ignore)]
+    LabeledPath(AssetPath<'static>),
+    MissingAssetSource(MissingAssetSourceError),
+    MissingAssetWriter(MissingAssetWriterError),
+    #[display("Failed to write the saved asset: {_0}")]
+    AssetWriterError(AssetWriterError),
+    #[display("Encountered an error while saving the asset: {_0}")]
+    #[from(ignore)]
+    AssetSaverError(Box<dyn core::error::Error + Send + Sync + 'static>),
+}
+
 /// An [`Asset`] (and any labeled "sub assets") intended to be saved.
 pub struct SavedAsset<'a, A: Asset> {
-    value: &'a A,
-    labeled_assets: &'a HashMap<CowArc<'static, str>, LabeledAsset>,
+    pub(crate) value: &'a A,
+    /// The labeled assets, or `None` if the asset was saved without any.
+    pub(crate) labeled_assets: Option<&'a HashMap<CowArc<'static, str>, LabeledAsset>>,
 }
 
 impl<'a, A: Asset> Deref for SavedAsset<'a, A> {
@@ -93,7 +167,7 @@ impl<'a, A: Asset> SavedAsset<'a, A> {
         let value = asset.value.downcast_ref::<A>()?;
         Some(SavedAsset {
             value,
-            labeled_assets: &asset.labeled_assets,
+            labeled_assets: Some(&asset.labeled_assets),
         })
     }
 
@@ -101,7 +175,15 @@ impl<'a, A: Asset> SavedAsset<'a, A> {
     pub fn from_transformed(asset: &'a TransformedAsset<A>) -> Self {
         Self {
             value: &asset.value,
-            labeled_assets: &asset.labeled_assets,
+            labeled_assets: Some(&asset.labeled_assets),
+        }
+    }
+
+    /// Creates a new [`SavedAsset`] from an asset without labeled assets.
+    pub fn from_asset(value: &'a A) -> Self {
+        Self {
+            value,
+            labeled_assets: None,
         }
     }
 
@@ -117,11 +199,11 @@ impl<'a, A: Asset> SavedAsset<'a, A> {
         CowArc<'static, str>: Borrow<Q>,
         Q: ?Sized + Hash + Eq,
     {
-        let labeled = self.labeled_assets.get(label)?;
+        let labeled = self.labeled_assets?.get(label)?;
         let value = labeled.asset.value.downcast_ref::<B>()?;
         Some(SavedAsset {
             value,
-            labeled_assets: &labeled.asset.labeled_assets,
+            labeled_assets: Some(&labeled.asset.labeled_assets),
         })
     }
 
@@ -131,7 +213,7 @@ impl<'a, A: Asset> SavedAsset<'a, A> {
         CowArc<'static, str>: Borrow<Q>,
         Q: ?Sized + Hash + Eq,
     {
-        let labeled = self.labeled_assets.get(label)?;
+        let labeled = self.labeled_assets?.get(label)?;
         Some(&labeled.asset)
     }
 
@@ -141,7 +223,7 @@ impl<'a, A: Asset> SavedAsset<'a, A> {
         CowArc<'static, str>: Borrow<Q>,
         Q: ?Sized + Hash + Eq,
     {
-        let labeled = self.labeled_assets.get(label)?;
+        let labeled = self.labeled_assets?.get(label)?;
         Some(labeled.handle.clone())
     }
 
@@ -151,7 +233,7 @@ impl<'a, A: Asset> SavedAsset<'a, A> {
         CowArc<'static, str>: Borrow<Q>,
         Q: ?Sized + Hash + Eq,
     {
-        let labeled = self.labeled_assets.get(label)?;
+        let labeled = self.labeled_assets?.get(label)?;
         if let Ok(handle) = labeled.handle.clone().try_typed::<B>() {
             return Some(handle);
         }
@@ -160,6 +242,21 @@ impl<'a, A: Asset> SavedAsset<'a, A> {
 
     /// Iterate over all labels for "labeled assets" in the loaded asset
     pub fn iter_labels(&self) -> impl Iterator<Item = &str> {
-        self.labeled_assets.keys().map(|s| &**s)
+        self.labeled_assets
+            .into_iter()
+            .flat_map(HashMap::keys)
+            .map(|s| &**s)
+    }
+}
+
+impl<'a, A: Asset> From<&'a A> for SavedAsset<'a, A> {
+    fn from(value: &'a A) -> Self {
+        Self::from_asset(value)
+    }
+}
+
+impl<'a, A: Asset> From<&'a TransformedAsset<A>> for SavedAsset<'a, A> {
+    fn from(asset: &'a TransformedAsset<A>) -> Self {
+        Self::from_transformed(asset)
     }
 }
@@ -0,0 +1,308 @@
+use crate::{
+    AssetPath, DependencyLoadState, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+};
+use alloc::collections::VecDeque;
+use bevy_utils::{HashMap, HashSet};
+use core::fmt::Write;
+use disqualified::ShortName;
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use super::info::AssetInfos;
+
+/// Returns the name of a [`LoadState`], [`DependencyLoadState`] or [`RecursiveDependencyLoadState`], without the
+/// error of failed loads.
+macro_rules! state_name {
+    ($state:expr) => {{
+        let state = &$state;
+        if state.is_loading() {
+            "Loading"
+        } else if state.is_loaded() {
+            "Loaded"
+        } else if state.is_failed() {
+            "Failed"
+        } else {
+            "NotLoaded"
+        }
+    }};
+}
+
+/// A snapshot of the assets tracked by the [`AssetServer`](crate::AssetServer) and of the
+/// dependencies between them, returned by
+/// [`AssetServer::dependency_graph`](crate::AssetServer::dependency_graph).
+///
+/// This is meant for diagnostics, such as finding out which assets keep a given asset alive, and
+/// can be exported with [`AssetDependencyGraph::to_dot`] or serialized with [`serde`], for
+/// example as JSON.
+#[derive(Clone, Debug, Default)]
+pub struct AssetDependencyGraph {
+    nodes: HashMap<UntypedAssetId, AssetGraphNode>,
+}
+
+/// An asset of an [`AssetDependencyGraph`].
+#[derive(Clone, Debug)]
+pub struct AssetGraphNode {
+    /// The id of the asset.
+    pub id: UntypedAssetId,
+    /// The path the asset was loaded from, if any.
+    pub path: Option<AssetPath<'static>>,
+    /// The name of the type of the asset, if it has been loaded.
+    pub type_name: Option<&'static str>,
+    /// The load state of the asset.
+    pub load_state: LoadState,
+    /// The load state of the direct dependencies of the asset.
+    pub dependency_load_state: DependencyLoadState,
+    /// The load state of all of the dependencies of the asset.
+    pub recursive_dependency_load_state: RecursiveDependencyLoadState,
+    /// The assets this asset depends on. These are only known once the asset has been loaded.
+    pub dependencies: Vec<UntypedAssetId>,
+    /// The assets that depend on this asset.
+    pub dependents: Vec<UntypedAssetId>,
+    /// The labeled sub-assets loaded from the same file as this asset.
+    pub labeled_assets: Vec<UntypedAssetId>,
+    /// The number of strong handles keeping this asset alive.
+    pub strong_handles: usize,
+}
+
+impl AssetDependencyGraph {
+    pub(crate) fn new(infos: &AssetInfos) -> Self {
+        let mut nodes = infos
+            .iter()
+            .map(|(id, info)| {
+                let mut dependencies = info.dependencies.iter().copied().collect::<Vec<_>>();
+                dependencies.sort_unstable();
+                let node = AssetGraphNode {
+                    id,
+                    path: info.path.clone(),
+                    type_name: info.type_name,
+                    load_state: info.load_state.clone(),
+                    dependency_load_state: info.dep_load_state.clone(),
+                    recursive_dependency_load_state: info.rec_dep_load_state.clone(),
+                    dependencies,
+                    dependents: Vec::new(),
+                    labeled_assets: Vec::new(),
+                    strong_handles: info.strong_handle_count(),
+                };
+                (id, node)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut dependents = Vec::new();
+        let mut labeled_assets = HashMap::<_, Vec<_>>::new();
+        for node in nodes.values() {
+            dependents.extend(node.dependencies.iter().map(|dep| (*dep, node.id)));
+            if let Some(path) = node.path.as_ref().filter(|path| path.label().is_some()) {
+                labeled_assets
+                    .entry(path.without_label().into_owned())
+                    .or_default()
+                    .push(node.id);
+            }
+        }
+        for (id, dependent) in dependents {
+            if let Some(node) = nodes.get_mut(&id) {
+                node.dependents.push(dependent);
+            }
+        }
+        for node in nodes.values_mut() {
+            if let Some(labeled) = node.path.as_ref().and_then(|path| labeled_assets.get(path)) {
+                node.labeled_assets.clone_from(labeled);
+            }
+            node.dependents.sort_unstable();
+            node.labeled_assets.sort_unstable();
+        }
+
+        Self { nodes }
+    }
+
+    /// Returns the node of the asset with the given `id`, if it is tracked.
+    pub fn get(&self, id: impl Into<UntypedAssetId>) -> Option<&AssetGraphNode> {
+        self.nodes.get(&id.into())
+    }
+
+    /// Iterates over the nodes of the assets loaded from `path`, one for each type of asset
+    /// requested from it.
+    pub fn get_by_path<'a>(
+        &'a self,
+        path: impl Into<AssetPath<'a>>,
+    ) -> impl Iterator<Item = &'a AssetGraphNode> {
+        let path = path.into();
+        self.nodes
+            .values()
+            .filter(move |node| node.path.as_ref() == Some(&path))
+    }
+
+    /// Iterates over all of the nodes of the graph, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &AssetGraphNode> {
+        self.nodes.values()
+    }
+
+    /// Returns the number of assets in the graph.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the graph contains no assets.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the ids of the assets the asset with the given `id` depends on, directly or through
+    /// other dependencies.
+    pub fn recursive_dependencies(&self, id: impl Into<UntypedAssetId>) -> HashSet<UntypedAssetId> {
+        self.traverse(id.into(), |node| &node.dependencies)
+    }
+
+    /// Returns the ids of the assets that depend on the asset with the given `id`, directly or
+    /// through other dependents.
+    ///
+    /// These are the assets that keep it alive by holding handles to it.
+    pub fn recursive_dependents(&self, id: impl Into<UntypedAssetId>) -> HashSet<UntypedAssetId> {
+        self.traverse(id.into(), |node| &node.dependents)
+    }
+
+    fn traverse(
+        &self,
+        id: UntypedAssetId,
+        edges: impl Fn(&AssetGraphNode) -> &Vec<UntypedAssetId>,
+    ) -> HashSet<UntypedAssetId> {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::from([id]);
+        while let Some(id) = queue.pop_front() {
+            let Some(node) = self.nodes.get(&id) else {
+                continue;
+            };
+            for next in edges(node) {
+                if visited.insert(*next) {
+                    queue.push_back(*next);
+                }
+            }
+        }
+        visited.remove(&id);
+        visited
+    }
+
+    /// Returns the graph made of the given `roots`, their labeled assets, and all of their
+    /// recursive dependencies.
+    pub fn subgraph(&self, roots: impl IntoIterator<Item = UntypedAssetId>) -> Self {
+        let mut ids = HashSet::new();
+        for root in roots {
+            let Some(node) = self.nodes.get(&root) else {
+                continue;
+            };
+            for id in core::iter::once(root).chain(node.labeled_assets.iter().copied()) {
+                ids.insert(id);
+                ids.extend(self.recursive_dependencies(id));
+            }
+        }
+
+        let nodes = ids
+            .into_iter()
+            .filter_map(|id| Some((id, self.nodes.get(&id)?.clone())))
+            .collect::<HashMap<_, _>>();
+        let mut graph = Self { nodes };
+        let ids = graph.nodes.keys().copied().collect::<HashSet<_>>();
+        for node in graph.nodes.values_mut() {
+            node.dependents.retain(|id| ids.contains(id));
+            node.labeled_assets.retain(|id| ids.contains(id));
+        }
+        graph
+    }
+
+    /// The nodes of the graph, sorted by path so that exports are stable.
+    fn sorted_nodes(&self) -> Vec<&AssetGraphNode> {
+        let mut nodes = self.nodes.values().collect::<Vec<_>>();
+        nodes.sort_by_cached_key(|node| (node.path.as_ref().map(ToString::to_string), node.id));
+        nodes
+    }
+
+    /// Exports the graph in the [DOT] format of Graphviz.
+    ///
+    /// Each asset is a node labeled with its path, type and load state. Solid edges point from
+    /// assets to their dependencies, and dashed edges from assets to their labeled sub-assets.
+    ///
+    /// [DOT]: https://graphviz.org/doc/info/lang.html
+    pub fn to_dot(&self) -> String {
+        let nodes = self.sorted_nodes();
+        let names = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.id, format!("asset{index}")))
+            .collect::<HashMap<_, _>>();
+
+        let mut dot = String::from("digraph assets {\n    node [shape=box];\n");
+        for node in &nodes {
+            let path = node
+                .path
+                .as_ref()
+                .map_or_else(|| node.id.to_string(), ToString::to_string);
+            let mut label = escape_dot(&path);
+            if let Some(type_name) = node.type_name {
+                write!(
+                    label,
+                    "\\n{}",
+                    escape_dot(&ShortName(type_name).to_string())
+                )
+                .unwrap();
+            }
+            write!(label, "\\n{}", state_name!(node.load_state)).unwrap();
+            writeln!(dot, "    {} [label=\"{label}\"];", names[&node.id]).unwrap();
+        }
+        for node in &nodes {
+            for dependency in &node.dependencies {
+                if let Some(dependency) = names.get(dependency) {
+                    writeln!(dot, "    {} -> {dependency};", names[&node.id]).unwrap();
+                }
+            }
+            for labeled_asset in &node.labeled_assets {
+                if let Some(labeled_asset) = names.get(labeled_asset) {
+                    writeln!(
+                        dot,
+                        "    {} -> {labeled_asset} [style=dashed];",
+                        names[&node.id]
+                    )
+                    .unwrap();
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Serializes the graph as a struct with an `assets` sequence, containing a struct for each asset with its `id`,
+/// `path`, `type`, load states, number of `strong_handles` and the ids of its `dependencies`, `dependents` and
+/// `labeled_assets`.
+impl Serialize for AssetDependencyGraph {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut graph = serializer.serialize_struct("AssetDependencyGraph", 1)?;
+        graph.serialize_field("assets", &self.sorted_nodes())?;
+        graph.end()
+    }
+}
+
+impl Serialize for AssetGraphNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let ids = |ids: &[UntypedAssetId]| ids.iter().map(ToString::to_string).collect::<Vec<_>>();
+        let mut node = serializer.serialize_struct("AssetGraphNode", 10)?;
+        node.serialize_field("id", &self.id.to_string())?;
+        node.serialize_field("path", &self.path)?;
+        node.serialize_field("type", &self.type_name)?;
+        node.serialize_field("load_state", state_name!(self.load_state))?;
+        node.serialize_field(
+            "dependency_load_state",
+            state_name!(self.dependency_load_state),
+        )?;
+        node.serialize_field(
+            "recursive_dependency_load_state",
+            state_name!(self.recursive_dependency_load_state),
+        )?;
+        node.serialize_field("strong_handles", &self.strong_handles)?;
+        node.serialize_field("dependencies", &ids(&self.dependencies))?;
+        node.serialize_field("dependents", &ids(&self.dependents))?;
+        node.serialize_field("labeled_assets", &ids(&self.labeled_assets))?;
+        node.end()
+    }
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -1,8 +1,8 @@
 use crate::{
     meta::{AssetHash, MetaTransform},
     Asset, AssetHandleProvider, AssetLoadError, AssetPath, DependencyLoadState, ErasedLoadedAsset,
-    Handle, InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle,
-    UntypedAssetId, UntypedHandle,
+    Handle, InternalAssetEvent, LoadFolderSettings, LoadState, LoadedFolder,
+    RecursiveDependencyLoadState, StrongHandle, UntypedAssetId, UntypedHandle,
 };
 use alloc::sync::{Arc, Weak};
 use bevy_ecs::world::World;
@@ -24,6 +24,10 @@ pub(crate) struct AssetInfo {
     failed_dependencies: HashSet<UntypedAssetId>,
     loading_rec_dependencies: HashSet<UntypedAssetId>,
     failed_rec_dependencies: HashSet<UntypedAssetId>,
+    /// The direct dependencies of this asset, set once it is loaded.
+    pub(crate) dependencies: HashSet<UntypedAssetId>,
+    /// The name of the type of this asset, set once it is loaded.
+    pub(crate) type_name: Option<&'static str>,
     dependents_waiting_on_load: HashSet<UntypedAssetId>,
     dependents_waiting_on_recursive_dep_load: HashSet<UntypedAssetId>,
     /// The asset paths required to load this asset. Hashes will only be set for processed assets.
@@ -38,6 +42,9 @@ pub(crate) struct AssetInfo {
     handle_drops_to_skip: usize,
     /// List of tasks waiting for this asset to complete loading
     pub(crate) waiting_tasks: Vec<Waker>,
+    /// The settings of a [`LoadedFolder`] loaded with
+    /// [`AssetServer::load_folder_with_settings`](crate::AssetServer::load_folder_with_settings).
+    folder_settings: Option<LoadFolderSettings>,
 }
 
 impl AssetInfo {
@@ -52,13 +59,21 @@ impl AssetInfo {
             failed_dependencies: HashSet::default(),
             loading_rec_dependencies: HashSet::default(),
             failed_rec_dependencies: HashSet::default(),
+            dependencies: HashSet::default(),
+            type_name: None,
             loader_dependencies: HashMap::default(),
             dependents_waiting_on_load: HashSet::default(),
             dependents_waiting_on_recursive_dep_load: HashSet::default(),
             handle_drops_to_skip: 0,
             waiting_tasks: Vec::new(),
+            folder_settings: None,
         }
     }
+
+    /// Returns the number of strong handles keeping this asset alive.
+    pub(crate) fn strong_handle_count(&self) -> usize {
+        self.weak_handle.strong_count()
+    }
 }
 
 #[derive(Default)]
@@ -112,6 +127,40 @@ impl AssetInfos {
         .unwrap()
     }
 
+    /// Creates a handle for a [`LoadedFolder`] loaded with the given `settings`. These handles are
+    /// not shared by loads of the same path.
+    pub(crate) fn create_folder_handle(
+        &mut self,
+        path: AssetPath<'static>,
+        settings: LoadFolderSettings,
+    ) -> UntypedHandle {
+        let handle = unwrap_with_context(
+            Self::create_handle_internal(
+                &mut self.infos,
+                &self.handle_providers,
+                &mut self.living_labeled_assets,
+                self.watching_for_changes,
+                TypeId::of::<LoadedFolder>(),
+                Some(path),
+                None,
+                true,
+            ),
+            Either::Left(core::any::type_name::<LoadedFolder>()),
+        )
+        .unwrap();
+        self.infos.get_mut(&handle.id()).unwrap().folder_settings = Some(settings);
+        handle
+    }
+
+    /// Iterates over the [`LoadedFolder`]s loaded with settings, with their paths.
+    pub(crate) fn iter_folders_with_settings(
+        &self,
+    ) -> impl Iterator<Item = (UntypedAssetId, &AssetPath<'static>, &LoadFolderSettings)> {
+        self.infos.iter().filter_map(|(id, info)| {
+            Some((*id, info.path.as_ref()?, info.folder_settings.as_ref()?))
+        })
+    }
+
     #[expect(
         clippy::too_many_arguments,
         reason = "Arguments needed so that both `create_loading_handle_untyped()` and `get_or_create_path_handle_internal()` may share code."
@@ -280,6 +329,10 @@ impl AssetInfos {
         self.infos.get(&id)
     }
 
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (UntypedAssetId, &AssetInfo)> {
+        self.infos.iter().map(|(id, info)| (*id, info))
+    }
+
     pub(crate) fn contains_key(&self, id: UntypedAssetId) -> bool {
         self.infos.contains_key(&id)
     }
@@ -393,7 +446,9 @@ impl AssetInfos {
             return;
         }
 
+        let type_name = loaded_asset.asset_type_name();
         loaded_asset.value.insert(loaded_asset_id, world);
+        let dependencies = loaded_asset.dependencies.clone();
         let mut loading_deps = loaded_asset.dependencies;
         let mut failed_deps = HashSet::new();
         let mut dep_error = None;
@@ -490,6 +545,8 @@ impl AssetInfos {
             let info = self
                 .get_mut(loaded_asset_id)
                 .expect("Asset info should always exist at this point");
+            info.dependencies = dependencies;
+            info.type_name = Some(type_name);
             info.loading_dependencies = loading_deps;
             info.failed_dependencies = failed_deps;
             info.loading_rec_dependencies = loading_rec_deps;
@@ -712,7 +769,10 @@ impl AssetInfos {
         }
 
         if let Some(map) = path_to_id.get_mut(path) {
-            map.remove(&type_id);
+            // assets like folders loaded with settings have a path without being registered for it
+            if map.get(&type_id) == Some(&id) {
+                map.remove(&type_id);
+            }
 
             if map.is_empty() {
                 path_to_id.remove(path);
@@ -0,0 +1,305 @@
+use crate::UntypedAssetId;
+use alloc::collections::BTreeSet;
+use bevy_utils::HashMap;
+use core::{
+    cmp::Reverse,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use parking_lot::Mutex;
+
+/// The priority of an asset load, which decides the order in which loads waiting for an
+/// [`AssetSource`](crate::io::AssetSource) with a limited number of concurrent loads are started.
+///
+/// Loads of the same priority are started in the order they were requested. Assets loaded as
+/// dependencies of another asset inherit the priority of that asset's load.
+///
+/// See [`AssetServer::load_with_priority`](crate::AssetServer::load_with_priority) and
+/// [`AssetSourceBuilder::with_max_concurrent_loads`](crate::io::AssetSourceBuilder::with_max_concurrent_loads).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LoadPriority {
+    /// For assets that aren't needed yet, such as ones prefetched for an upcoming level.
+    Low,
+    /// The priority of loads started with [`AssetServer::load`](crate::AssetServer::load).
+    #[default]
+    Normal,
+    /// For assets that are needed right away, such as textures of visible UI.
+    High,
+}
+
+/// Limits the number of loads running at the same time for an asset source, starting the waiting
+/// loads in order of [`LoadPriority`].
+pub(crate) struct LoadQueue {
+    max_concurrent_loads: Option<usize>,
+    state: Mutex<LoadQueueState>,
+}
+
+#[derive(Default)]
+struct LoadQueueState {
+    running: usize,
+    next_ticket: u64,
+    /// The tickets of the waiting loads, highest priority first.
+    queue: BTreeSet<(Reverse<LoadPriority>, u64)>,
+    waiters: HashMap<u64, Waiter>,
+    /// The ticket of each waiting load of a known asset.
+    tickets: HashMap<UntypedAssetId, u64>,
+}
+
+struct Waiter {
+    id: Option<UntypedAssetId>,
+    priority: LoadPriority,
+    waker: Waker,
+    /// Set once a running load has handed its slot over to this one.
+    granted: bool,
+}
+
+impl LoadQueue {
+    /// Creates a queue running at most `max_concurrent_loads` loads at once, if set.
+    pub(crate) fn new(max_concurrent_loads: Option<usize>) -> Self {
+        Self {
+            max_concurrent_loads,
+            state: Mutex::new(LoadQueueState::default()),
+        }
+    }
+
+    /// Waits until a load of the asset with the given `id` can start, returning a permit that
+    /// must be held until the load is done.
+    pub(crate) fn acquire(
+        &self,
+        id: Option<UntypedAssetId>,
+        priority: LoadPriority,
+    ) -> Acquire<'_> {
+        Acquire {
+            queue: self,
+            id,
+            priority,
+            ticket: None,
+            acquired: false,
+        }
+    }
+
+    /// Changes the priority of the waiting load of the asset with the given `id`, returning
+    /// `false` if there is no such load.
+    ///
+    /// If `raise_only` is set, the priority is only changed if it becomes higher.
+    pub(crate) fn set_priority(
+        &self,
+        id: UntypedAssetId,
+        priority: LoadPriority,
+        raise_only: bool,
+    ) -> bool {
+        let mut state = self.state.lock();
+        let state = &mut *state;
+        let Some(ticket) = state.tickets.get(&id).copied() else {
+            return false;
+        };
+        let Some(waiter) = state.waiters.get_mut(&ticket) else {
+            return false;
+        };
+        if waiter.granted || (raise_only && priority <= waiter.priority) {
+            return true;
+        }
+        state.queue.remove(&(Reverse(waiter.priority), ticket));
+        state.queue.insert((Reverse(priority), ticket));
+        waiter.priority = priority;
+        true
+    }
+
+    /// Hands the slot of a finished load over to the next waiting load, if any.
+    fn release(&self) {
+        let mut state = self.state.lock();
+        let next = state.queue.pop_first();
+        let waker = next.and_then(|(_, ticket)| {
+            let waiter = state.waiters.get_mut(&ticket)?;
+            waiter.granted = true;
+            Some(waiter.waker.clone())
+        });
+        match waker {
+            Some(waker) => {
+                drop(state);
+                waker.wake();
+            }
+            None => state.running -= 1,
+        }
+    }
+}
+
+/// The future returned by [`LoadQueue::acquire`].
+///
+/// Dropping it gives up the load's place in the queue.
+pub(crate) struct Acquire<'a> {
+    queue: &'a LoadQueue,
+    id: Option<UntypedAssetId>,
+    priority: LoadPriority,
+    ticket: Option<u64>,
+    acquired: bool,
+}
+
+impl<'a> Future for Acquire<'a> {
+    type Output = LoadPermit<'a>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let mut state = this.queue.state.lock();
+        let state = &mut *state;
+        match this.ticket {
+            None => {
+                if this
+                    .queue
+                    .max_concurrent_loads
+                    .is_none_or(|max| state.running < max)
+                {
+                    state.running += 1;
+                    this.acquired = true;
+                    return Poll::Ready(LoadPermit { queue: this.queue });
+                }
+                let ticket = state.next_ticket;
+                state.next_ticket += 1;
+                state.queue.insert((Reverse(this.priority), ticket));
+                state.waiters.insert(
+                    ticket,
+                    Waiter {
+                        id: this.id,
+                        priority: this.priority,
+                        waker: cx.waker().clone(),
+                        granted: false,
+                    },
+                );
+                if let Some(id) = this.id {
+                    state.tickets.insert(id, ticket);
+                }
+                this.ticket = Some(ticket);
+                Poll::Pending
+            }
+            Some(ticket) => {
+                let waiter = state
+                    .waiters
+                    .get_mut(&ticket)
+                    .expect("waiting loads stay in the queue until they are dropped");
+                if !waiter.granted {
+                    waiter.waker.clone_from(cx.waker());
+                    return Poll::Pending;
+                }
+                state.remove_waiter(ticket);
+                this.acquired = true;
+                Poll::Ready(LoadPermit { queue: this.queue })
+            }
+        }
+    }
+}
+
+impl Drop for Acquire<'_> {
+    fn drop(&mut self) {
+        let Some(ticket) = self.ticket.filter(|_| !self.acquired) else {
+            return;
+        };
+        let granted = {
+            let mut state = self.queue.state.lock();
+            let waiter = state.remove_waiter(ticket);
+            if !waiter.granted {
+                state.queue.remove(&(Reverse(waiter.priority), ticket));
+            }
+            waiter.granted
+        };
+        // The slot was handed over to this load, which won't use it anymore.
+        if granted {
+            self.queue.release();
+        }
+    }
+}
+
+impl LoadQueueState {
+    fn remove_waiter(&mut self, ticket: u64) -> Waiter {
+        let waiter = self.waiters.remove(&ticket).unwrap();
+        if let Some(id) = waiter.id {
+            if self.tickets.get(&id) == Some(&ticket) {
+                self.tickets.remove(&id);
+            }
+        }
+        waiter
+    }
+}
+
+/// Allows a load to run until it is dropped, at which point the next waiting load is started.
+pub(crate) struct LoadPermit<'a> {
+    queue: &'a LoadQueue,
+}
+
+impl Drop for LoadPermit<'_> {
+    fn drop(&mut self) {
+        self.queue.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LoadPriority, LoadQueue};
+    use crate::{AssetId, UntypedAssetId};
+    use bevy_tasks::block_on;
+    use futures_lite::future::poll_once;
+
+    fn id(index: u128) -> UntypedAssetId {
+        AssetId::<()>::Uuid {
+            uuid: uuid::Uuid::from_u128(index),
+        }
+        .untyped()
+    }
+
+    #[test]
+    fn starts_waiting_loads_by_priority() {
+        let queue = LoadQueue::new(Some(1));
+        let running = block_on(queue.acquire(None, LoadPriority::Normal));
+
+        let mut low = Box::pin(queue.acquire(None, LoadPriority::Low));
+        let mut normal = Box::pin(queue.acquire(None, LoadPriority::Normal));
+        let mut high = Box::pin(queue.acquire(None, LoadPriority::High));
+        assert!(block_on(poll_once(&mut low)).is_none());
+        assert!(block_on(poll_once(&mut normal)).is_none());
+        assert!(block_on(poll_once(&mut high)).is_none());
+
+        drop(running);
+        assert!(block_on(poll_once(&mut low)).is_none());
+        assert!(block_on(poll_once(&mut normal)).is_none());
+        let high = block_on(poll_once(&mut high)).unwrap();
+
+        drop(high);
+        assert!(block_on(poll_once(&mut low)).is_none());
+        let normal = block_on(poll_once(&mut normal)).unwrap();
+
+        drop(normal);
+        block_on(poll_once(&mut low)).unwrap();
+    }
+
+    #[test]
+    fn reprioritizes_and_cancels_waiting_loads() {
+        let queue = LoadQueue::new(Some(1));
+        let running = block_on(queue.acquire(None, LoadPriority::Normal));
+
+        let mut first = Box::pin(queue.acquire(Some(id(1)), LoadPriority::Normal));
+        let mut second = Box::pin(queue.acquire(Some(id(2)), LoadPriority::Low));
+        assert!(block_on(poll_once(&mut first)).is_none());
+        assert!(block_on(poll_once(&mut second)).is_none());
+        assert!(queue.set_priority(id(2), LoadPriority::High, true));
+        assert!(queue.set_priority(id(2), LoadPriority::Low, true));
+        assert!(!queue.set_priority(id(3), LoadPriority::High, false));
+
+        // the slot is handed over to `second`, which is dropped before using it
+        drop(running);
+        drop(second);
+        let first = block_on(poll_once(&mut first)).unwrap();
+        drop(first);
+
+        let _running = block_on(queue.acquire(None, LoadPriority::Low));
+        let mut third = Box::pin(queue.acquire(None, LoadPriority::Normal));
+        assert!(block_on(poll_once(&mut third)).is_none());
+    }
+
+    #[test]
+    fn unlimited() {
+        let queue = LoadQueue::new(None);
+        let _permits = (0..100)
+            .map(|_| block_on(queue.acquire(None, LoadPriority::Low)))
+            .collect::<Vec<_>>();
+    }
+}
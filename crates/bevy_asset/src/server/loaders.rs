@@ -1,5 +1,6 @@
 use crate::{
     loader::{AssetLoader, ErasedAssetLoader},
+    meta::MetaMigrations,
     path::AssetPath,
 };
 use alloc::sync::Arc;
@@ -21,6 +22,9 @@ pub(crate) struct AssetLoaders {
     extension_to_loaders: HashMap<Box<str>, Vec<usize>>,
     type_name_to_loader: HashMap<&'static str, usize>,
     preregistered_loaders: HashMap<&'static str, usize>,
+    /// The migrations of loader and processor settings, which are shared by all servers sharing
+    /// these loaders.
+    pub(crate) migrations: MetaMigrations,
 }
 
 impl AssetLoaders {
@@ -306,6 +310,7 @@ impl<T: AssetLoader> AssetLoader for InstrumentedAssetLoader<T> {
     type Asset = T::Asset;
     type Settings = T::Settings;
     type Error = T::Error;
+    const SETTINGS_VERSION: u32 = T::SETTINGS_VERSION;
 
     fn load(
         &self,
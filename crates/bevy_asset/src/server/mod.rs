@@ -1,8 +1,10 @@
+mod graph;
 mod info;
+mod load_queue;
 mod loaders;
 
 use crate::{
-    folder::LoadedFolder,
+    folder::{LoadFolderSettings, LoadedFolder},
     io::{
         AssetReaderError, AssetSource, AssetSourceEvent, AssetSourceId, AssetSources,
         ErasedAssetReader, MissingAssetSourceError, MissingProcessedAssetReaderError, Reader,
@@ -13,6 +15,7 @@ use crate::{
         MetaTransform, Settings,
     },
     path::AssetPath,
+    saver::{AssetSaver, ErasedRuntimeAssetSaver, SaveAssetError, SavedAsset},
     Asset, AssetEvent, AssetHandleProvider, AssetId, AssetLoadFailedEvent, AssetMetaCheck, Assets,
     DeserializeMetaError, ErasedLoadedAsset, Handle, LoadedUntypedAsset, UntypedAssetId,
     UntypedAssetLoadFailedEvent, UntypedHandle,
@@ -20,17 +23,21 @@ use crate::{
 use alloc::sync::Arc;
 use atomicow::CowArc;
 use bevy_ecs::prelude::*;
-use bevy_tasks::IoTaskPool;
+use bevy_tasks::{IoTaskPool, Task};
 use bevy_utils::{
-    tracing::{error, info},
-    HashSet,
+    tracing::{error, info, warn},
+    HashSet, TypeIdMap,
 };
 use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll};
 use crossbeam_channel::{Receiver, Sender};
 use derive_more::derive::{Display, Error, From};
 use either::Either;
 use futures_lite::{FutureExt, StreamExt};
+pub use graph::{AssetDependencyGraph, AssetGraphNode};
 use info::*;
+use load_queue::LoadPermit;
+pub use load_queue::LoadPriority;
+pub(crate) use load_queue::LoadQueue;
 use loaders::*;
 use parking_lot::{RwLock, RwLockWriteGuard};
 use std::path::{Path, PathBuf};
@@ -63,6 +70,7 @@ pub(crate) struct AssetServerData {
     sources: AssetSources,
     mode: AssetServerMode,
     meta_check: AssetMetaCheck,
+    savers: RwLock<TypeIdMap<Arc<dyn ErasedRuntimeAssetSaver>>>,
 }
 
 /// The "asset mode" the server is currently in.
@@ -123,6 +131,7 @@ impl AssetServer {
                 asset_event_receiver,
                 loaders,
                 infos: RwLock::new(infos),
+                savers: Default::default(),
             }),
         }
     }
@@ -145,6 +154,156 @@ impl AssetServer {
         self.data.loaders.write().push(loader);
     }
 
+    /// Registers a migration upgrading the settings of the [`AssetLoader`] `L` in `.meta` files
+    /// from `from_version`, of type `From`, to the next version, of type `To`.
+    ///
+    /// When a `.meta` file is read with a settings version lower than [`AssetLoader::SETTINGS_VERSION`],
+    /// the migrations from its version up to the current one are applied in order.
+    pub fn register_loader_migration<L: AssetLoader, From, To>(
+        &self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) where
+        From: for<'a> serde::Deserialize<'a>,
+        To: serde::Serialize,
+    {
+        self.data.loaders.write().migrations.register(
+            core::any::type_name::<L>(),
+            from_version,
+            migrate,
+        );
+    }
+
+    /// Upgrades the serialized `meta` of the loader or processor named `type_name` from settings
+    /// version `version` to `current_version`, returning [`None`] if it is already up to date.
+    pub(crate) fn migrate_meta(
+        &self,
+        type_name: &str,
+        meta: &[u8],
+        version: u32,
+        current_version: u32,
+    ) -> Result<Option<Vec<u8>>, DeserializeMetaError> {
+        self.data
+            .loaders
+            .read()
+            .migrations
+            .migrate(type_name, meta, version, current_version)
+    }
+
+    /// Returns `true` if migrated `.meta` files should be written back to their asset source.
+    pub(crate) fn rewrite_migrated_meta(&self) -> bool {
+        self.data.loaders.read().migrations.rewrite
+    }
+
+    /// Registers an [`AssetSaver`], used by [`AssetServer::save`] to save assets of type
+    /// [`AssetSaver::Asset`]. This replaces the saver previously registered for that type, if any.
+    pub fn register_saver<S: AssetSaver>(&self, saver: S) {
+        self.data
+            .savers
+            .write()
+            .insert(TypeId::of::<S::Asset>(), Arc::new(saver));
+    }
+
+    /// Saves the given runtime `asset` to `path`, using the [`AssetSaver`] registered for its type
+    /// with [`AssetServer::register_saver`] and the default settings of the saver. This lets
+    /// in-game editors persist the assets they modify:
+    ///
+    /// ```no_run
+    /// # use bevy_asset::{Asset, AssetServer, Assets, Handle};
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_reflect::TypePath;
+    /// # #[derive(Asset, TypePath)]
+    /// # struct Material;
+    /// #[derive(Resource)]
+    /// struct EditedMaterial(Handle<Material>);
+    ///
+    /// fn save_material(
+    ///     asset_server: Res<AssetServer>,
+    ///     materials: Res<Assets<Material>>,
+    ///     edited: Res<EditedMaterial>,
+    /// ) {
+    ///     let path = asset_server.get_path(&edited.0).unwrap();
+    ///     let material = materials.get(&edited.0).unwrap();
+    ///     asset_server.save(path, material).detach();
+    /// }
+    /// ```
+    ///
+    /// The asset is saved to bytes right away, so it only needs to be borrowed. The returned task then
+    /// writes them on the [`IoTaskPool`] to the [`AssetWriter`](crate::io::AssetWriter) of the
+    /// [`AssetSource`] of `path`, along with a `.meta` file that loads them with
+    /// [`AssetSaver::OutputLoader`] and the settings returned by the saver. If the existing `.meta`
+    /// file processes the asset, it is kept along with its processor settings, so the saver should
+    /// write a format the processor can load. If the server is watching for changes, the saved asset
+    /// is then reloaded like any other modified asset.
+    ///
+    /// To save labeled assets along with the asset, pass a reference to a [`TransformedAsset`] and add
+    /// them with [`TransformedAsset::insert_labeled`]. They are available to the saver through its
+    /// [`SavedAsset`].
+    ///
+    /// [`TransformedAsset`]: crate::transformer::TransformedAsset
+    /// [`TransformedAsset::insert_labeled`]: crate::transformer::TransformedAsset::insert_labeled
+    pub fn save<'a, 'b, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        asset: impl Into<SavedAsset<'b, A>>,
+    ) -> Task<Result<(), SaveAssetError>> {
+        let path = path.into().into_owned();
+        let saved = self.save_to_bytes(&path, asset.into());
+        let server = self.clone();
+        IoTaskPool::get().spawn(async move {
+            let (bytes, meta) = saved?;
+            let source = server.get_source(path.source())?;
+            let writer = source.writer()?;
+            writer.write_bytes(path.path(), &bytes).await?;
+            if !Self::processes_asset(source.reader(), path.path()).await {
+                writer.write_meta_bytes(path.path(), &meta).await?;
+            }
+            info!("Saved asset {path}");
+            Ok(())
+        })
+    }
+
+    /// Returns `true` if the existing `.meta` file of the asset at `path` processes it.
+    async fn processes_asset(reader: &dyn ErasedAssetReader, path: &Path) -> bool {
+        let Ok(meta) = reader.read_meta_bytes(path).await else {
+            return false;
+        };
+        matches!(
+            ron::de::from_bytes::<AssetMetaMinimal>(&meta),
+            Ok(AssetMetaMinimal {
+                asset: AssetActionMinimal::Process { .. },
+                ..
+            })
+        )
+    }
+
+    /// Saves `asset` with its registered [`AssetSaver`], returning the bytes of the asset and of
+    /// its meta file.
+    fn save_to_bytes<A: Asset>(
+        &self,
+        path: &AssetPath<'static>,
+        asset: SavedAsset<A>,
+    ) -> Result<(Vec<u8>, Vec<u8>), SaveAssetError> {
+        if path.label().is_some() {
+            return Err(SaveAssetError::LabeledPath(path.clone()));
+        }
+        let saver = self
+            .data
+            .savers
+            .read()
+            .get(&TypeId::of::<A>())
+            .cloned()
+            .ok_or(SaveAssetError::MissingAssetSaver(
+                core::any::type_name::<A>(),
+            ))?;
+        let mut bytes = Vec::new();
+        // savers write to memory here, so they don't wait on anything
+        let meta =
+            bevy_tasks::block_on(saver.save_value(&mut bytes, asset.value, asset.labeled_assets))
+                .map_err(SaveAssetError::AssetSaverError)?;
+        Ok((bytes, meta.serialize()))
+    }
+
     /// Registers a new [`Asset`] type. [`Asset`] types must be registered before assets of that type can be loaded.
     pub fn register_asset<A: Asset>(&self, assets: &Assets<A>) {
         self.register_handle_provider(assets.get_handle_provider());
@@ -306,7 +465,37 @@ impl AssetServer {
     /// The asset load will fail and an error will be printed to the logs if the asset stored at `path` is not of type `A`.
     #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
     pub fn load<'a, A: Asset>(&self, path: impl Into<AssetPath<'a>>) -> Handle<A> {
-        self.load_with_meta_transform(path, None, ())
+        self.load_with_meta_transform(path, None, (), LoadPriority::Normal)
+    }
+
+    /// Begins loading an [`Asset`] of type `A` stored at `path`, like [`AssetServer::load`], with the given
+    /// [`LoadPriority`].
+    ///
+    /// The priority decides when the load starts if the [`AssetSource`] of `path` limits the number of loads running at
+    /// the same time (see [`AssetSourceBuilder::with_max_concurrent_loads`]). It is inherited by the loads of the asset's
+    /// dependencies. If the asset is already waiting to be loaded with a lower priority, its priority is raised.
+    ///
+    /// Waiting loads are cancelled if all of the strong handles to their asset are dropped. With the `multi_threaded`
+    /// feature, so are loads that already started.
+    ///
+    /// [`AssetSourceBuilder::with_max_concurrent_loads`]: crate::io::AssetSourceBuilder::with_max_concurrent_loads
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_with_priority<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        priority: LoadPriority,
+    ) -> Handle<A> {
+        self.load_with_meta_transform(path, None, (), priority)
+    }
+
+    /// Changes the [`LoadPriority`] of the asset with the given `id`, if it is waiting to be loaded. Returns `false` if
+    /// it isn't.
+    pub fn set_load_priority(&self, id: impl Into<UntypedAssetId>, priority: LoadPriority) -> bool {
+        let id = id.into();
+        self.data
+            .sources
+            .iter()
+            .any(|source| source.load_queue().set_priority(id, priority, false))
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path` while holding a guard item.
@@ -330,7 +519,7 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         guard: G,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, None, guard)
+        self.load_with_meta_transform(path, None, guard, LoadPriority::Normal)
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path`. The given `settings` function will override the asset's
@@ -342,7 +531,12 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         settings: impl Fn(&mut S) + Send + Sync + 'static,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, Some(loader_settings_meta_transform(settings)), ())
+        self.load_with_meta_transform(
+            path,
+            Some(loader_settings_meta_transform(settings)),
+            (),
+            LoadPriority::Normal,
+        )
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path` while holding a guard item.
@@ -361,7 +555,12 @@ impl AssetServer {
         settings: impl Fn(&mut S) + Send + Sync + 'static,
         guard: G,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, Some(loader_settings_meta_transform(settings)), guard)
+        self.load_with_meta_transform(
+            path,
+            Some(loader_settings_meta_transform(settings)),
+            guard,
+            LoadPriority::Normal,
+        )
     }
 
     pub(crate) fn load_with_meta_transform<'a, A: Asset, G: Send + Sync + 'static>(
@@ -369,6 +568,7 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         meta_transform: Option<MetaTransform>,
         guard: G,
+        priority: LoadPriority,
     ) -> Handle<A> {
         let path = path.into().into_owned();
         let mut infos = self.data.infos.write();
@@ -379,7 +579,9 @@ impl AssetServer {
         );
 
         if should_load {
-            self.spawn_load_task(handle.clone().untyped(), path, infos, guard);
+            self.spawn_load_task(handle.clone().untyped(), path, infos, guard, priority);
+        } else {
+            self.raise_load_priority(&path, handle.id().untyped(), priority);
         }
 
         handle
@@ -391,6 +593,7 @@ impl AssetServer {
         type_id: TypeId,
         meta_transform: Option<MetaTransform>,
         guard: G,
+        priority: LoadPriority,
     ) -> UntypedHandle {
         let path = path.into().into_owned();
         let mut infos = self.data.infos.write();
@@ -403,7 +606,9 @@ impl AssetServer {
         );
 
         if should_load {
-            self.spawn_load_task(handle.clone(), path, infos, guard);
+            self.spawn_load_task(handle.clone(), path, infos, guard, priority);
+        } else {
+            self.raise_load_priority(&path, handle.id(), priority);
         }
 
         handle
@@ -415,16 +620,23 @@ impl AssetServer {
         path: AssetPath<'static>,
         infos: RwLockWriteGuard<AssetInfos>,
         guard: G,
+        priority: LoadPriority,
     ) {
         // drop the lock on `AssetInfos` before spawning a task that may block on it in single-threaded
         #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
         drop(infos);
 
-        let owned_handle = handle.clone();
+        // the task doesn't hold a strong handle, so that dropping the last one cancels the load
+        let weak_handle = handle.clone_weak();
         let server = self.clone();
         let task = IoTaskPool::get().spawn(async move {
+            let id = weak_handle.id();
+            let _permit = server.acquire_load_permit(&path, Some(id), priority).await;
+            if server.get_id_handle_untyped(id).is_none() {
+                return;
+            }
             if let Err(err) = server
-                .load_internal(Some(owned_handle), path, false, None)
+                .load_internal(Some(weak_handle), path, false, None, priority)
                 .await
             {
                 error!("{}", err);
@@ -450,14 +662,50 @@ impl AssetServer {
         &self,
         path: impl Into<AssetPath<'a>>,
     ) -> Result<UntypedHandle, AssetLoadError> {
-        let path: AssetPath = path.into();
-        self.load_internal(None, path, false, None).await
+        self.load_untyped_with_priority(path.into(), None, LoadPriority::Normal)
+            .await
+    }
+
+    async fn load_untyped_with_priority(
+        &self,
+        path: AssetPath<'_>,
+        id: Option<UntypedAssetId>,
+        priority: LoadPriority,
+    ) -> Result<UntypedHandle, AssetLoadError> {
+        let _permit = self.acquire_load_permit(&path, id, priority).await;
+        self.load_internal(None, path, false, None, priority).await
+    }
+
+    /// Waits until the [`AssetSource`] of `path` allows another load to run, returning the permit to hold while loading.
+    /// Returns [`None`] if the source doesn't exist, which loading reports.
+    async fn acquire_load_permit(
+        &self,
+        path: &AssetPath<'_>,
+        id: Option<UntypedAssetId>,
+        priority: LoadPriority,
+    ) -> Option<LoadPermit<'_>> {
+        let source = self.get_source(path.source()).ok()?;
+        Some(source.load_queue().acquire(id, priority).await)
+    }
+
+    /// Raises the priority of the load of the asset with the given `id` to `priority`, if it is waiting to be loaded
+    /// with a lower one.
+    fn raise_load_priority(
+        &self,
+        path: &AssetPath<'_>,
+        id: UntypedAssetId,
+        priority: LoadPriority,
+    ) {
+        if let Ok(source) = self.get_source(path.source()) {
+            source.load_queue().set_priority(id, priority, true);
+        }
     }
 
     pub(crate) fn load_unknown_type_with_meta_transform<'a>(
         &self,
         path: impl Into<AssetPath<'a>>,
         meta_transform: Option<MetaTransform>,
+        priority: LoadPriority,
     ) -> Handle<LoadedUntypedAsset> {
         let path = path.into().into_owned();
         let untyped_source = AssetSourceId::Name(match path.source() {
@@ -478,6 +726,7 @@ impl AssetServer {
         drop(infos);
 
         if !should_load {
+            self.raise_load_priority(&path, handle.id().untyped(), priority);
             return handle;
         }
         let id = handle.id().untyped();
@@ -485,7 +734,10 @@ impl AssetServer {
         let server = self.clone();
         let task = IoTaskPool::get().spawn(async move {
             let path_clone = path.clone();
-            match server.load_untyped_async(path).await {
+            match server
+                .load_untyped_with_priority(path, Some(id), priority)
+                .await
+            {
                 Ok(handle) => server.send_asset_event(InternalAssetEvent::Loaded {
                     id,
                     loaded_asset: LoadedAsset::new_with_dependencies(
@@ -538,7 +790,7 @@ impl AssetServer {
     /// required to figure out the asset type before a handle can be created.
     #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
     pub fn load_untyped<'a>(&self, path: impl Into<AssetPath<'a>>) -> Handle<LoadedUntypedAsset> {
-        self.load_unknown_type_with_meta_transform(path, None)
+        self.load_unknown_type_with_meta_transform(path, None, LoadPriority::Normal)
     }
 
     /// Performs an async asset load.
@@ -551,6 +803,7 @@ impl AssetServer {
         path: AssetPath<'a>,
         force: bool,
         meta_transform: Option<MetaTransform>,
+        priority: LoadPriority,
     ) -> Result<UntypedHandle, AssetLoadError> {
         let asset_type_id = input_handle.as_ref().map(UntypedHandle::type_id);
 
@@ -571,11 +824,16 @@ impl AssetServer {
                 }
             })?;
 
-        if let Some(meta_transform) = input_handle.as_ref().and_then(|h| h.meta_transform()) {
-            (*meta_transform)(&mut *meta);
+        // only strong handles contain the asset meta transform, so a weak input handle is briefly upgraded to apply it
+        if let Some(handle) = input_handle
+            .as_ref()
+            .and_then(|h| self.get_id_handle_untyped(h.id()))
+        {
+            if let Some(meta_transform) = handle.meta_transform() {
+                (*meta_transform)(&mut *meta);
+            }
         }
         // downgrade the input handle so we don't keep the asset alive just because we're loading it
-        // note we can't just pass a weak handle in, as only strong handles contain the asset meta transform
         input_handle = input_handle.map(|h| h.clone_weak());
 
         // This contains Some(UntypedHandle), if it was retrievable
@@ -647,7 +905,15 @@ impl AssetServer {
         };
 
         match self
-            .load_with_meta_loader_and_reader(&base_path, meta, &*loader, &mut *reader, true, false)
+            .load_with_meta_loader_and_reader(
+                &base_path,
+                meta,
+                &*loader,
+                &mut *reader,
+                true,
+                false,
+                priority,
+            )
             .await
         {
             Ok(loaded_asset) => {
@@ -698,6 +964,8 @@ impl AssetServer {
     }
 
     /// Kicks off a reload of the asset stored at the given path. This will only reload the asset if it currently loaded.
+    ///
+    /// Reloads count towards the concurrent loads of the asset's [`AssetSource`], with [`LoadPriority::Normal`].
     pub fn reload<'a>(&self, path: impl Into<AssetPath<'a>>) {
         let server = self.clone();
         let path = path.into().into_owned();
@@ -705,23 +973,34 @@ impl AssetServer {
             .spawn(async move {
                 let mut reloaded = false;
 
-                let requests = server
+                let handles = server
                     .data
                     .infos
                     .read()
                     .get_path_handles(&path)
-                    .map(|handle| server.load_internal(Some(handle), path.clone(), true, None))
                     .collect::<Vec<_>>();
 
-                for result in requests {
-                    match result.await {
+                for handle in handles {
+                    let _permit = server
+                        .acquire_load_permit(&path, Some(handle.id()), LoadPriority::Normal)
+                        .await;
+                    match server
+                        .load_internal(Some(handle), path.clone(), true, None, LoadPriority::Normal)
+                        .await
+                    {
                         Ok(_) => reloaded = true,
                         Err(err) => error!("{}", err),
                     }
                 }
 
                 if !reloaded && server.data.infos.read().should_reload(&path) {
-                    if let Err(err) = server.load_internal(None, path, true, None).await {
+                    let _permit = server
+                        .acquire_load_permit(&path, None, LoadPriority::Normal)
+                        .await;
+                    if let Err(err) = server
+                        .load_internal(None, path.clone(), true, None, LoadPriority::Normal)
+                        .await
+                    {
                         error!("{}", err);
                     }
                 }
@@ -836,6 +1115,8 @@ impl AssetServer {
     /// feature is enabled, [`LoadedFolder`] handles will reload when a file in the folder is
     /// removed, added or moved. This includes files in subdirectories and moving, adding,
     /// or removing complete subdirectories.
+    ///
+    /// To only load some of the files of the folder, see [`AssetServer::load_folder_with_settings`].
     #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
     pub fn load_folder<'a>(&self, path: impl Into<AssetPath<'a>>) -> Handle<LoadedFolder> {
         let path = path.into().into_owned();
@@ -852,17 +1133,49 @@ impl AssetServer {
             return handle;
         }
         let id = handle.id().untyped();
-        self.load_folder_internal(id, path);
+        self.load_folder_internal(id, path, LoadFolderSettings::default());
 
         handle
     }
 
-    pub(crate) fn load_folder_internal(&self, id: UntypedAssetId, path: AssetPath) {
+    /// Loads the files of the specified folder that pass the filters of the given `settings`, such
+    /// as glob patterns, file extensions or asset types. Files in subfolders are only loaded if
+    /// [`LoadFolderSettings::recursive`] is set. See [`AssetServer::load_folder`] for more.
+    ///
+    /// Unlike [`AssetServer::load_folder`], each call starts a new load of the folder and returns
+    /// a new handle. If the `file_watcher` feature is enabled, the [`LoadedFolder`] is reloaded
+    /// when a file passing the filters is added to or removed from the folder, so it always
+    /// contains the matching files.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
+    pub fn load_folder_with_settings<'a>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        settings: LoadFolderSettings,
+    ) -> Handle<LoadedFolder> {
+        let path = path.into().into_owned();
+        let handle = self
+            .data
+            .infos
+            .write()
+            .create_folder_handle(path.clone(), settings.clone());
+        self.load_folder_internal(handle.id(), path, settings);
+
+        handle.typed_debug_checked()
+    }
+
+    pub(crate) fn load_folder_internal(
+        &self,
+        id: UntypedAssetId,
+        path: AssetPath,
+        settings: LoadFolderSettings,
+    ) {
         async fn load_folder<'a>(
             source: AssetSourceId<'static>,
+            root: &'a Path,
             path: &'a Path,
             reader: &'a dyn ErasedAssetReader,
             server: &'a AssetServer,
+            settings: &'a LoadFolderSettings,
             handles: &'a mut Vec<UntypedHandle>,
         ) -> Result<(), AssetLoadError> {
             let is_dir = reader.is_directory(path).await?;
@@ -870,22 +1183,43 @@ impl AssetServer {
                 let mut path_stream = reader.read_directory(path.as_ref()).await?;
                 while let Some(child_path) = path_stream.next().await {
                     if reader.is_directory(&child_path).await? {
+                        if !settings.recursive {
+                            continue;
+                        }
                         Box::pin(load_folder(
                             source.clone(),
+                            root,
                             &child_path,
                             reader,
                             server,
+                            settings,
                             handles,
                         ))
                         .await?;
                     } else {
+                        let relative_path = child_path.strip_prefix(root).unwrap_or(&child_path);
+                        if !settings.matches_file(relative_path) {
+                            continue;
+                        }
                         let path = child_path.to_str().expect("Path should be a valid string.");
                         let asset_path = AssetPath::parse(path).with_source(source.clone());
+                        if !settings.asset_types.is_empty() {
+                            // skip files whose loader doesn't produce one of the asset types
+                            // before loading them
+                            match server.get_path_asset_loader(&asset_path).await {
+                                Ok(loader)
+                                    if settings.matches_asset_type(loader.asset_type_id()) => {}
+                                _ => continue,
+                            }
+                        }
                         match server.load_untyped_async(asset_path).await {
+                            // the meta file of an asset can pick another loader
+                            Ok(handle) if !settings.matches_asset_type(handle.type_id()) => {}
                             Ok(handle) => handles.push(handle),
                             // skip assets that cannot be loaded
                             Err(
-                                AssetLoadError::MissingAssetLoaderForTypeName(_)
+                                AssetLoadError::MissingAssetLoader { .. }
+                                | AssetLoadError::MissingAssetLoaderForTypeName(_)
                                 | AssetLoadError::MissingAssetLoaderForExtension(_),
                             ) => {}
                             Err(err) => return Err(err),
@@ -923,7 +1257,17 @@ impl AssetServer {
                 };
 
                 let mut handles = Vec::new();
-                match load_folder(source.id(), path.path(), asset_reader, &server, &mut handles).await {
+                let result = load_folder(
+                    source.id(),
+                    path.path(),
+                    path.path(),
+                    asset_reader,
+                    &server,
+                    &settings,
+                    &mut handles,
+                )
+                .await;
+                match result {
                     Ok(_) => server.send_asset_event(InternalAssetEvent::Loaded {
                         id,
                         loaded_asset: LoadedAsset::new_with_dependencies(
@@ -1004,6 +1348,35 @@ impl AssetServer {
             .map(|i| i.rec_dep_load_state.clone())
     }
 
+    /// Retrieves the ids of the direct dependencies of the asset with the given `id`, if the asset is managed by this
+    /// server. Dependencies are only known once the asset has been loaded.
+    pub fn get_dependencies(&self, id: impl Into<UntypedAssetId>) -> Option<Vec<UntypedAssetId>> {
+        self.data
+            .infos
+            .read()
+            .get(id.into())
+            .map(|i| i.dependencies.iter().copied().collect())
+    }
+
+    /// Retrieves the ids of the loaded assets managed by this server that directly depend on the asset with the given
+    /// `id`.
+    pub fn get_dependents(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        let id = id.into();
+        self.data
+            .infos
+            .read()
+            .iter()
+            .filter(|(_, info)| info.dependencies.contains(&id))
+            .map(|(dependent, _)| dependent)
+            .collect()
+    }
+
+    /// Returns a snapshot of all of the assets managed by this server, with their load states and the dependencies
+    /// between them. See [`AssetDependencyGraph`] for more.
+    pub fn dependency_graph(&self) -> AssetDependencyGraph {
+        AssetDependencyGraph::new(&self.data.infos.read())
+    }
+
     /// Retrieves the main [`LoadState`] of a given asset `id`.
     ///
     /// This is the same as [`AssetServer::get_load_state`] except the result is unwrapped. If
@@ -1257,12 +1630,33 @@ impl AssetServer {
                         }
                     };
                     let loader = self.get_asset_loader_with_type_name(&loader_name).await?;
-                    let meta = loader.deserialize_meta(&meta_bytes).map_err(|e| {
-                        AssetLoadError::DeserializeMeta {
+                    let deserialize_error =
+                        |e: DeserializeMetaError| AssetLoadError::DeserializeMeta {
                             path: asset_path.clone_owned(),
                             error: e.into(),
+                        };
+                    let migrated = self
+                        .migrate_meta(
+                            &loader_name,
+                            &meta_bytes,
+                            minimal.settings_version,
+                            loader.settings_version(),
+                        )
+                        .map_err(deserialize_error)?;
+                    let meta_bytes = match migrated {
+                        Some(migrated) => {
+                            if matches!(self.data.mode, AssetServerMode::Unprocessed)
+                                && self.rewrite_migrated_meta()
+                            {
+                                rewrite_meta(source, asset_path, &migrated).await;
+                            }
+                            migrated
                         }
-                    })?;
+                        None => meta_bytes,
+                    };
+                    let meta = loader
+                        .deserialize_meta(&meta_bytes)
+                        .map_err(deserialize_error)?;
 
                     Ok((meta, loader, reader))
                 }
@@ -1311,6 +1705,7 @@ impl AssetServer {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) async fn load_with_meta_loader_and_reader(
         &self,
         asset_path: &AssetPath<'_>,
@@ -1319,11 +1714,17 @@ impl AssetServer {
         reader: &mut dyn Reader,
         load_dependencies: bool,
         populate_hashes: bool,
+        priority: LoadPriority,
     ) -> Result<ErasedLoadedAsset, AssetLoadError> {
         // TODO: experiment with this
         let asset_path = asset_path.clone_owned();
-        let load_context =
-            LoadContext::new(self, asset_path.clone(), load_dependencies, populate_hashes);
+        let load_context = LoadContext::new(
+            self,
+            asset_path.clone(),
+            load_dependencies,
+            populate_hashes,
+            priority,
+        );
         AssertUnwindSafe(loader.load(reader, meta, load_context))
             .catch_unwind()
             .await
@@ -1467,6 +1868,21 @@ impl AssetServer {
     }
 }
 
+/// Writes the migrated `meta` of the asset at `path` back to its unprocessed asset source.
+pub(crate) async fn rewrite_meta(source: &AssetSource, path: &AssetPath<'_>, meta: &[u8]) {
+    let result = match source.writer() {
+        Ok(writer) => writer
+            .write_meta_bytes(path.path(), meta)
+            .await
+            .map_err(|err| err.to_string()),
+        Err(err) => Err(err.to_string()),
+    };
+    match result {
+        Ok(()) => info!("Migrated the meta file of {path}"),
+        Err(err) => warn!("Failed to write the migrated meta file of {path}: {err}"),
+    }
+}
+
 /// A system that manages internal [`AssetServer`] events, such as finalizing asset loads.
 pub fn handle_internal_asset_events(world: &mut World) {
     world.resource_scope(|world, server: Mut<AssetServer>| {
@@ -1531,15 +1947,38 @@ pub fn handle_internal_asset_events(world: &mut World) {
             }
         }
 
-        let reload_parent_folders = |path: PathBuf, source: &AssetSourceId<'static>| {
-            let mut current_folder = path;
+        let reload_parent_folders = |path: PathBuf, source: &AssetSourceId<'static>, is_asset| {
+            let mut current_folder = path.clone();
             while let Some(parent) = current_folder.parent() {
                 current_folder = parent.to_path_buf();
                 let parent_asset_path =
                     AssetPath::from(current_folder.clone()).with_source(source.clone());
                 for folder_handle in infos.get_path_handles(&parent_asset_path) {
                     info!("Reloading folder {parent_asset_path} because the content has changed");
-                    server.load_folder_internal(folder_handle.id(), parent_asset_path.clone());
+                    server.load_folder_internal(
+                        folder_handle.id(),
+                        parent_asset_path.clone(),
+                        LoadFolderSettings::default(),
+                    );
+                }
+            }
+
+            // folders loaded with settings are only reloaded if the changed file passes their filters
+            for (id, folder_path, settings) in infos.iter_folders_with_settings() {
+                if folder_path.source() != source {
+                    continue;
+                }
+                let Ok(relative_path) = path.strip_prefix(folder_path.path()) else {
+                    continue;
+                };
+                let is_match = if is_asset {
+                    settings.matches_file(relative_path)
+                } else {
+                    settings.recursive
+                };
+                if !relative_path.as_os_str().is_empty() && is_match {
+                    info!("Reloading folder {folder_path} because the content has changed");
+                    server.load_folder_internal(id, folder_path.clone(), settings.clone());
                 }
             }
         };
@@ -1555,14 +1994,14 @@ pub fn handle_internal_asset_events(world: &mut World) {
                     paths_to_reload.insert(path);
                 }
                 AssetSourceEvent::RenamedFolder { old, new } => {
-                    reload_parent_folders(old, &source);
-                    reload_parent_folders(new, &source);
+                    reload_parent_folders(old, &source, false);
+                    reload_parent_folders(new, &source, false);
+                }
+                AssetSourceEvent::AddedAsset(path) | AssetSourceEvent::RemovedAsset(path) => {
+                    reload_parent_folders(path, &source, true);
                 }
-                AssetSourceEvent::AddedAsset(path)
-                | AssetSourceEvent::RemovedAsset(path)
-                | AssetSourceEvent::RemovedFolder(path)
-                | AssetSourceEvent::AddedFolder(path) => {
-                    reload_parent_folders(path, &source);
+                AssetSourceEvent::RemovedFolder(path) | AssetSourceEvent::AddedFolder(path) => {
+                    reload_parent_folders(path, &source, false);
                 }
                 _ => {}
             }
@@ -39,6 +39,15 @@ pub struct TransformedAsset<A: Asset> {
     pub(crate) labeled_assets: HashMap<CowArc<'static, str>, LabeledAsset>,
 }
 
+impl<A: Asset> From<A> for TransformedAsset<A> {
+    fn from(value: A) -> Self {
+        TransformedAsset {
+            value,
+            labeled_assets: HashMap::default(),
+        }
+    }
+}
+
 impl<A: Asset> Deref for TransformedAsset<A> {
     type Target = A;
     fn deref(&self) -> &Self::Target {
@@ -19,6 +19,7 @@ mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
 #[cfg(feature = "sysinfo_plugin")]
 mod system_information_diagnostics_plugin;
+mod system_profiling_diagnostics_plugin;
 
 pub use diagnostic::*;
 
@@ -27,6 +28,7 @@ pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::LogDiagnosticsPlugin;
 #[cfg(feature = "sysinfo_plugin")]
 pub use system_information_diagnostics_plugin::{SystemInfo, SystemInformationDiagnosticsPlugin};
+pub use system_profiling_diagnostics_plugin::SystemProfilingDiagnosticsPlugin;
 
 use bevy_app::prelude::*;
 
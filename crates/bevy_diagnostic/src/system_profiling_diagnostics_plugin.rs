@@ -0,0 +1,211 @@
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, NodeId, SystemProfiles},
+};
+use bevy_utils::{HashMap, Instant};
+
+use crate::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, RegisterDiagnostic,
+};
+
+/// Adds per-system run time diagnostics to an App.
+///
+/// Enables collection of [`SystemProfiles`] and, every frame, records the following for each
+/// system that has run so far:
+///
+/// - `system_profiling/<schedule>/<system>/runs`: how many times the system has run
+/// - `system_profiling/<schedule>/<system>/average`: the average run time in milliseconds
+/// - `system_profiling/<schedule>/<system>/max`: the longest run time in milliseconds
+///
+/// and for each schedule:
+///
+/// - `system_profiling/<schedule>/parallelism`: the average number of systems running at the same
+///   time during the schedule's most recent run
+/// - `system_profiling/<schedule>/max_concurrent_systems`: the highest number of systems running at
+///   the same time during the schedule's most recent run
+///
+/// The diagnostics of the systems added to the app before it is finished are registered up front,
+/// those of systems added later are registered the first time they run.
+///
+/// Use [`SystemProfiles::slowest_systems`] to get the systems with the longest run times.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct SystemProfilingDiagnosticsPlugin;
+
+impl Plugin for SystemProfilingDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiagnosticsStore>()
+            .init_resource::<SystemProfiles>()
+            .add_systems(Last, Self::diagnostic_system);
+    }
+
+    fn finish(&self, app: &mut App) {
+        let mut paths = Vec::new();
+        if let Some(schedules) = app.world().get_resource::<Schedules>() {
+            for (_, schedule) in schedules.iter() {
+                let label = schedule.label();
+                let mut systems = schedule.graph().systems().peekable();
+                if systems.peek().is_none() {
+                    continue;
+                }
+                paths.extend(schedule_paths(label));
+                for (_, system, _) in systems {
+                    paths.extend(system_paths(label, &system.name()));
+                }
+            }
+        }
+        for (path, suffix) in paths {
+            app.register_diagnostic(Diagnostic::new(path).with_suffix(suffix));
+        }
+    }
+}
+
+/// Diagnostic paths and suffixes of each profiled schedule and system, built once and reused across
+/// frames.
+#[derive(Default)]
+struct SystemProfilingPaths {
+    schedules: HashMap<InternedScheduleLabel, [(DiagnosticPath, &'static str); 2]>,
+    systems: HashMap<(InternedScheduleLabel, NodeId), [(DiagnosticPath, &'static str); 3]>,
+}
+
+impl SystemProfilingDiagnosticsPlugin {
+    /// The root component of all diagnostic paths added by this plugin.
+    pub const ROOT: &'static str = "system_profiling";
+
+    fn diagnostic_system(
+        mut store: ResMut<DiagnosticsStore>,
+        profiles: Res<SystemProfiles>,
+        mut paths: Local<SystemProfilingPaths>,
+    ) {
+        let time = Instant::now();
+        for (label, schedule) in profiles.iter() {
+            let [parallelism, max_concurrent_systems] = paths
+                .schedules
+                .entry(label)
+                .or_insert_with(|| schedule_paths(label));
+            add_measurement(&mut store, parallelism, time, schedule.last_parallelism());
+            add_measurement(
+                &mut store,
+                max_concurrent_systems,
+                time,
+                schedule.last_concurrent_systems() as f64,
+            );
+
+            for (id, system) in schedule.systems() {
+                let [runs, average, max] = paths
+                    .systems
+                    .entry((label, id))
+                    .or_insert_with(|| system_paths(label, system.name()));
+                add_measurement(&mut store, runs, time, system.runs() as f64);
+                add_measurement(
+                    &mut store,
+                    average,
+                    time,
+                    system.average_duration().as_secs_f64() * 1000.0,
+                );
+                add_measurement(
+                    &mut store,
+                    max,
+                    time,
+                    system.max_duration().as_secs_f64() * 1000.0,
+                );
+            }
+        }
+    }
+}
+
+fn schedule_path(label: InternedScheduleLabel) -> String {
+    format!(
+        "{}/{}",
+        SystemProfilingDiagnosticsPlugin::ROOT,
+        format!("{label:?}").replace('/', "_")
+    )
+}
+
+/// The paths and suffixes of the diagnostics of a schedule.
+fn schedule_paths(label: InternedScheduleLabel) -> [(DiagnosticPath, &'static str); 2] {
+    let schedule_path = schedule_path(label);
+    [
+        (
+            DiagnosticPath::new(format!("{schedule_path}/parallelism")),
+            "",
+        ),
+        (
+            DiagnosticPath::new(format!("{schedule_path}/max_concurrent_systems")),
+            "",
+        ),
+    ]
+}
+
+/// The paths and suffixes of the diagnostics of a system.
+fn system_paths(label: InternedScheduleLabel, name: &str) -> [(DiagnosticPath, &'static str); 3] {
+    let system_path = format!("{}/{}", schedule_path(label), name.replace('/', "_"));
+    [
+        (DiagnosticPath::new(format!("{system_path}/runs")), ""),
+        (DiagnosticPath::new(format!("{system_path}/average")), "ms"),
+        (DiagnosticPath::new(format!("{system_path}/max")), "ms"),
+    ]
+}
+
+/// Adds a measurement to the diagnostic at `path`, registering the diagnostic first if needed.
+fn add_measurement(
+    store: &mut DiagnosticsStore,
+    (path, suffix): &(DiagnosticPath, &'static str),
+    time: Instant,
+    value: f64,
+) {
+    if store.get(path).is_none() {
+        store.add(Diagnostic::new(path.clone()).with_suffix(*suffix));
+    }
+    if let Some(diagnostic) = store
+        .get_mut(path)
+        .filter(|diagnostic| diagnostic.is_enabled)
+    {
+        diagnostic.add_measurement(DiagnosticMeasurement { time, value });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::schedule::ScheduleLabel;
+
+    fn profiled_system() {}
+
+    #[test]
+    fn registers_diagnostics_up_front() {
+        let mut app = App::new();
+        app.add_plugins(SystemProfilingDiagnosticsPlugin)
+            .add_systems(Update, profiled_system);
+        app.finish();
+        app.cleanup();
+
+        let name = IntoSystem::into_system(profiled_system).name();
+        let runs = DiagnosticPath::new(format!(
+            "{}/{}/runs",
+            schedule_path(Update.intern()),
+            name.replace('/', "_")
+        ));
+        let parallelism =
+            DiagnosticPath::new(format!("{}/parallelism", schedule_path(Update.intern())));
+        let store = app.world().resource::<DiagnosticsStore>();
+        assert!(store.get(&runs).is_some());
+        assert!(store.get(&parallelism).is_some());
+        assert!(store.get_measurement(&runs).is_none());
+
+        app.update();
+        app.update();
+
+        let store = app.world().resource::<DiagnosticsStore>();
+        assert_eq!(
+            store
+                .get_measurement(&runs)
+                .map(|measurement| measurement.value),
+            Some(2.0)
+        );
+    }
+}
@@ -9,7 +9,8 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     token::{Comma, Paren},
-    DeriveInput, ExprClosure, ExprPath, Ident, LitStr, Path, Result,
+    Data, DataStruct, DeriveInput, ExprClosure, ExprPath, Fields, Ident, LitStr, Member, Path,
+    Result, Type,
 };
 
 pub fn derive_event(input: TokenStream) -> TokenStream {
@@ -63,11 +64,33 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         Err(e) => return e.into_compile_error().into(),
     };
 
+    let relationship = match derive_relationship(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let relationship_target = match derive_relationship_target(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
     let storage = storage_path(&bevy_ecs_path, attrs.storage);
 
     let on_add = hook_register_function_call(quote! {on_add}, attrs.on_add);
-    let on_insert = hook_register_function_call(quote! {on_insert}, attrs.on_insert);
-    let on_replace = hook_register_function_call(quote! {on_replace}, attrs.on_replace);
+    let mut on_insert = hook_register_function_call(quote! {on_insert}, attrs.on_insert);
+    let mut on_replace = hook_register_function_call(quote! {on_replace}, attrs.on_replace);
+    if attrs.relationship.is_some() {
+        on_insert = Some(quote! {
+            hooks.on_insert(<Self as #bevy_ecs_path::relationship::Relationship>::on_insert);
+        });
+        on_replace = Some(quote! {
+            hooks.on_replace(<Self as #bevy_ecs_path::relationship::Relationship>::on_replace);
+        });
+    }
+    if attrs.relationship_target.is_some() {
+        on_replace = Some(quote! {
+            hooks.on_replace(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace);
+        });
+    }
     let on_remove = hook_register_function_call(quote! {on_remove}, attrs.on_remove);
 
     ast.generics
@@ -143,6 +166,8 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     // level components are initialized first, giving them precedence over recursively defined constructors for the same component type
     TokenStream::from(quote! {
         #required_component_docs
+        #relationship
+        #relationship_target
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
             const STORAGE_TYPE: #bevy_ecs_path::component::StorageType = #storage;
             fn register_required_components(
@@ -170,6 +195,8 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 pub const COMPONENT: &str = "component";
 pub const STORAGE: &str = "storage";
 pub const REQUIRE: &str = "require";
+pub const RELATIONSHIP: &str = "relationship";
+pub const RELATIONSHIP_TARGET: &str = "relationship_target";
 
 pub const ON_ADD: &str = "on_add";
 pub const ON_INSERT: &str = "on_insert";
@@ -183,6 +210,17 @@ struct Attrs {
     on_insert: Option<ExprPath>,
     on_replace: Option<ExprPath>,
     on_remove: Option<ExprPath>,
+    relationship: Option<Relationship>,
+    relationship_target: Option<RelationshipTarget>,
+}
+
+struct Relationship {
+    relationship_target: Type,
+}
+
+struct RelationshipTarget {
+    relationship: Type,
+    linked_spawn: bool,
 }
 
 #[derive(Clone, Copy)]
@@ -213,6 +251,8 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
         on_replace: None,
         on_remove: None,
         requires: None,
+        relationship: None,
+        relationship_target: None,
     };
 
     let mut require_paths = HashSet::new();
@@ -262,9 +302,30 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
             } else {
                 attrs.requires = Some(punctuated);
             }
+        } else if attr.path().is_ident(RELATIONSHIP) {
+            let relationship = attr.parse_args::<Relationship>()?;
+            attrs.relationship = Some(relationship);
+        } else if attr.path().is_ident(RELATIONSHIP_TARGET) {
+            let relationship_target = attr.parse_args::<RelationshipTarget>()?;
+            attrs.relationship_target = Some(relationship_target);
         }
     }
 
+    if attrs.relationship.is_some() && attrs.relationship_target.is_some() {
+        return Err(syn::Error::new(
+            ast.span(),
+            "A component cannot be both a `relationship` and a `relationship_target`.",
+        ));
+    }
+    if (attrs.relationship.is_some() && (attrs.on_insert.is_some() || attrs.on_replace.is_some()))
+        || (attrs.relationship_target.is_some() && attrs.on_replace.is_some())
+    {
+        return Err(syn::Error::new(
+            ast.span(),
+            "Custom on_insert and on_replace hooks are not supported on relationships, as relationships use these hooks to stay consistent.",
+        ));
+    }
+
     Ok(attrs)
 }
 
@@ -287,6 +348,150 @@ impl Parse for Require {
     }
 }
 
+impl Parse for Relationship {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        if ident != RELATIONSHIP_TARGET {
+            return Err(syn::Error::new(
+                ident.span(),
+                "Expected `relationship_target = SomeType`.",
+            ));
+        }
+        input.parse::<syn::Token![=]>()?;
+        Ok(Relationship {
+            relationship_target: input.parse::<Type>()?,
+        })
+    }
+}
+
+impl Parse for RelationshipTarget {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let mut relationship = None;
+        let mut linked_spawn = false;
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>()?;
+            if ident == RELATIONSHIP {
+                input.parse::<syn::Token![=]>()?;
+                relationship = Some(input.parse::<Type>()?);
+            } else if ident == "linked_spawn" {
+                linked_spawn = true;
+            } else {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "Unsupported attribute, expected `relationship = SomeType` or `linked_spawn`.",
+                ));
+            }
+            if !input.is_empty() {
+                input.parse::<Comma>()?;
+            }
+        }
+        let Some(relationship) = relationship else {
+            return Err(input.error("Missing `relationship = SomeType`."));
+        };
+        Ok(RelationshipTarget {
+            relationship,
+            linked_spawn,
+        })
+    }
+}
+
+/// Returns the single field of the relationship struct, as relationships hold nothing but the related entity / entities.
+fn relationship_field<'a>(ast: &'a DeriveInput, attribute: &str) -> Result<(Member, &'a Type)> {
+    let Data::Struct(DataStruct { fields, .. }) = &ast.data else {
+        return Err(syn::Error::new(
+            ast.span(),
+            format!("`{attribute}` can only be derived for structs."),
+        ));
+    };
+    let field = match fields {
+        Fields::Named(named) if named.named.len() == 1 => named.named.first().unwrap(),
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => unnamed.unnamed.first().unwrap(),
+        _ => {
+            return Err(syn::Error::new(
+                fields.span(),
+                format!("`{attribute}` can only be derived for structs with a single field."),
+            ));
+        }
+    };
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(syn::Index::from(0)),
+    };
+    Ok((member, &field.ty))
+}
+
+fn derive_relationship(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship) = &attrs.relationship else {
+        return Ok(None);
+    };
+    let (member, _) = relationship_field(ast, RELATIONSHIP)?;
+    let relationship_target = &relationship.relationship_target;
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::Relationship for #struct_name #type_generics #where_clause {
+            type RelationshipTarget = #relationship_target;
+
+            #[inline(always)]
+            fn get(&self) -> #bevy_ecs_path::entity::Entity {
+                self.#member
+            }
+
+            #[inline]
+            fn from(entity: #bevy_ecs_path::entity::Entity) -> Self {
+                Self {
+                    #member: entity
+                }
+            }
+        }
+    }))
+}
+
+fn derive_relationship_target(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship_target) = &attrs.relationship_target else {
+        return Ok(None);
+    };
+    let (member, collection) = relationship_field(ast, RELATIONSHIP_TARGET)?;
+    let relationship = &relationship_target.relationship;
+    let linked_spawn = relationship_target.linked_spawn;
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::RelationshipTarget for #struct_name #type_generics #where_clause {
+            const LINKED_SPAWN: bool = #linked_spawn;
+            type Relationship = #relationship;
+            type Collection = #collection;
+
+            #[inline]
+            fn collection(&self) -> &Self::Collection {
+                &self.#member
+            }
+
+            #[inline]
+            fn collection_mut_risky(&mut self) -> &mut Self::Collection {
+                &mut self.#member
+            }
+
+            #[inline]
+            fn from_collection_risky(collection: Self::Collection) -> Self {
+                Self {
+                    #member: collection
+                }
+            }
+        }
+    }))
+}
+
 fn storage_path(bevy_ecs_path: &Path, ty: StorageTy) -> TokenStream2 {
     let storage_type = match ty {
         StorageTy::Table => Ident::new("Table", Span::call_site()),
@@ -602,8 +602,14 @@ pub struct ComponentInfo {
     hooks: ComponentHooks,
     required_components: RequiredComponents,
     required_by: HashSet<ComponentId>,
+    register_required_components: Option<RegisterRequiredComponentsFn>,
 }
 
+/// The [`Component::register_required_components`] function of a component type, kept so that its
+/// requirements can be registered again in another [`World`](crate::world::World).
+type RegisterRequiredComponentsFn =
+    fn(ComponentId, &mut Components, &mut Storages, &mut RequiredComponents, u16);
+
 impl ComponentInfo {
     /// Returns a value uniquely identifying the current component.
     #[inline]
@@ -663,6 +669,7 @@ impl ComponentInfo {
             hooks: Default::default(),
             required_components: Default::default(),
             required_by: Default::default(),
+            register_required_components: None,
         }
     }
 
@@ -918,6 +925,7 @@ impl Components {
             let info = &mut self.components[id.index()];
             T::register_component_hooks(&mut info.hooks);
             info.required_components = required_components;
+            info.register_required_components = Some(T::register_required_components);
         }
         id
     }
@@ -941,6 +949,39 @@ impl Components {
         Components::register_component_inner(&mut self.components, storages, descriptor)
     }
 
+    /// Returns the id of the component with the same Rust type as `info`, which may come from another [`World`](crate::world::World),
+    /// registering it with the same descriptor, hooks and required components if needed.
+    ///
+    /// Only the required components declared on the component type are registered, requirements added at runtime
+    /// with [`World::register_required_components`](crate::world::World::register_required_components) are not carried over.
+    ///
+    /// Returns `None` if `info` doesn't correspond to a Rust type.
+    pub(crate) fn register_component_from_info(
+        &mut self,
+        storages: &mut Storages,
+        info: &ComponentInfo,
+    ) -> Option<ComponentId> {
+        let type_id = info.type_id()?;
+        if let Some(&id) = self.indices.get(&type_id) {
+            return Some(id);
+        }
+        let id = Components::register_component_inner(
+            &mut self.components,
+            storages,
+            info.descriptor.clone(),
+        );
+        self.indices.insert(type_id, id);
+        self.components[id.index()].hooks = info.hooks.clone();
+        if let Some(register_required_components) = info.register_required_components {
+            let mut required_components = RequiredComponents::default();
+            register_required_components(id, self, storages, &mut required_components, 0);
+            let info = &mut self.components[id.index()];
+            info.required_components = required_components;
+            info.register_required_components = Some(register_required_components);
+        }
+        Some(id)
+    }
+
     #[inline]
     fn register_component_inner(
         components: &mut Vec<ComponentInfo>,
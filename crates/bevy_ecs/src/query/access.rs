@@ -823,6 +823,9 @@ pub struct FilteredAccess<T: SparseSetIndex> {
     // An array of filter sets to express `With` or `Without` clauses in disjunctive normal form, for example: `Or<(With<A>, With<B>)>`.
     // Filters like `(With<A>, Or<(With<B>, Without<C>)>` are expanded into `Or<((With<A>, With<B>), (With<A>, Without<C>))>`.
     pub(crate) filter_sets: Vec<AccessFilters<T>>,
+    // The subset of `access` that is performed on entities other than the one matched by the query,
+    // for example through a `Related` query term. It isn't restricted by `filter_sets`.
+    pub(crate) joined: Access<T>,
 }
 
 // This is needed since `#[derive(Clone)]` does not generate optimized `clone_from`.
@@ -832,6 +835,7 @@ impl<T: SparseSetIndex> Clone for FilteredAccess<T> {
             access: self.access.clone(),
             required: self.required.clone(),
             filter_sets: self.filter_sets.clone(),
+            joined: self.joined.clone(),
         }
     }
 
@@ -839,6 +843,7 @@ impl<T: SparseSetIndex> Clone for FilteredAccess<T> {
         self.access.clone_from(&source.access);
         self.required.clone_from(&source.required);
         self.filter_sets.clone_from(&source.filter_sets);
+        self.joined.clone_from(&source.joined);
     }
 }
 
@@ -923,6 +928,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             access: Access::default(),
             required: FixedBitSet::default(),
             filter_sets: vec![AccessFilters::default()],
+            joined: Access::default(),
         }
     }
 
@@ -933,6 +939,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             access: Access::default(),
             required: FixedBitSet::default(),
             filter_sets: Vec::new(),
+            joined: Access::default(),
         }
     }
 
@@ -948,6 +955,22 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
         &mut self.access
     }
 
+    /// Returns a reference to the access performed on entities other than the one matched by the query.
+    ///
+    /// This access is included in [`FilteredAccess::access`], but it isn't restricted by the filters.
+    #[inline]
+    pub fn joined_access(&self) -> &Access<T> {
+        &self.joined
+    }
+
+    /// Adds `access` as access performed on entities other than the one matched by the query.
+    ///
+    /// Unlike the rest of the access, it is not restricted by the filters, and no filters are added for it.
+    pub fn extend_joined_access(&mut self, access: &Access<T>) {
+        self.access.extend(access);
+        self.joined.extend(access);
+    }
+
     /// Adds access to the component given by `index`.
     pub fn add_component_read(&mut self, index: T) {
         self.access.add_component_read(index.clone());
@@ -1008,6 +1031,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     /// Adds all of the accesses from `other` to `self`.
     pub fn extend_access(&mut self, other: &FilteredAccess<T>) {
         self.access.extend(&other.access);
+        self.joined.extend(&other.joined);
     }
 
     /// Returns `true` if this and `other` can be active at the same time.
@@ -1016,6 +1040,11 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             return true;
         }
 
+        // Joined access may happen on any entity, so filters can't make it disjoint from other accesses.
+        if !self.joined.is_compatible(&other.access) || !other.joined.is_compatible(&self.access) {
+            return false;
+        }
+
         // If the access instances are incompatible, we want to check that whether filters can
         // guarantee that queries are disjoint.
         // Since the `filter_sets` array represents a Disjunctive Normal Form formula ("ORs of ANDs"),
@@ -1048,6 +1077,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     /// `Or<((With<A>, With<C>), (With<A>, Without<D>), (Without<B>, With<C>), (Without<B>, Without<D>))>`.
     pub fn extend(&mut self, other: &FilteredAccess<T>) {
         self.access.extend(&other.access);
+        self.joined.extend(&other.joined);
         self.required.union_with(&other.required);
 
         // We can avoid allocating a new array of bitsets if `other` contains just a single set of filters:
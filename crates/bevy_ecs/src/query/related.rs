@@ -0,0 +1,510 @@
+use crate::{
+    archetype::Archetype,
+    component::{ComponentId, Components, Tick},
+    entity::Entity,
+    query::{
+        DebugCheckedUnwrap, FilteredAccess, QueryData, QueryFilter, ReadOnlyQueryData, WorldQuery,
+    },
+    storage::{Table, TableRow},
+    traversal::Traversal,
+    world::{unsafe_world_cell::UnsafeWorldCell, World},
+};
+use core::marker::PhantomData;
+
+/// Fetches the query data `D` from the entity that the current entity points to through the link `L`.
+///
+/// `L` is any [`Traversal`], such as `&Parent` or a reference to a [`Relationship`](crate::relationship::Relationship)
+/// component. Only entities that match `L` are matched by the query; the item is `None` if the link does not
+/// point to an existing entity or if that entity does not match `D`.
+///
+/// This allows following entity links without nesting [`Query::get`](crate::system::Query::get) calls,
+/// while the accessed data is still tracked precisely enough for the scheduler to run systems in parallel.
+/// Since any entity may be the target of a link, data accessed through a [`Related`] term is not restricted
+/// by the query's filters: `Query<Related<&Parent, &A>, With<B>>` conflicts with `Query<&mut A, Without<B>>`.
+///
+/// `D` must be read-only, as several entities may point to the same target.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::query::Related;
+/// # use bevy_ecs::traversal::Traversal;
+/// #[derive(Component)]
+/// struct AttachedTo(Entity);
+///
+/// impl Traversal for &AttachedTo {
+///     fn traverse(item: Self::Item<'_>) -> Option<Entity> {
+///         Some(item.0)
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Position(f32);
+///
+/// fn print_socket_positions(query: Query<(Entity, Related<&AttachedTo, &Position>)>) {
+///     for (entity, socket_position) in &query {
+///         if let Some(position) = socket_position {
+///             println!("{entity:?} is attached to a socket at {}", position.0);
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(print_socket_positions);
+/// ```
+pub struct Related<L, D>(PhantomData<(L, D)>);
+
+/// Filter that selects entities whose entity linked through `L` matches the filter `F`.
+///
+/// `L` is any [`Traversal`], such as `&Parent` or a reference to a [`Relationship`](crate::relationship::Relationship)
+/// component. Entities that don't match `L`, or whose link does not point to an existing entity, are filtered out.
+///
+/// As with [`Related`], component access performed by `F` on the linked entity is not restricted by the
+/// query's own filters.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::query::WithRelated;
+/// # use bevy_ecs::traversal::Traversal;
+/// #[derive(Component)]
+/// struct Targets(Entity);
+///
+/// impl Traversal for &Targets {
+///     fn traverse(item: Self::Item<'_>) -> Option<Entity> {
+///         Some(item.0)
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// fn targeting_players(query: Query<Entity, WithRelated<&Targets, With<Player>>>) {
+///     for entity in &query {
+///         println!("{entity:?} is targeting a player");
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(targeting_players);
+/// ```
+pub struct WithRelated<L, F>(PhantomData<(L, F)>);
+
+#[doc(hidden)]
+pub struct RelatedFetch<'w, L: WorldQuery, D: WorldQuery> {
+    link: L::Fetch<'w>,
+    data: D::Fetch<'w>,
+    data_state: D::State,
+    world: UnsafeWorldCell<'w>,
+}
+
+impl<L: WorldQuery, D: WorldQuery> Clone for RelatedFetch<'_, L, D>
+where
+    D::State: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            link: self.link.clone(),
+            data: self.data.clone(),
+            data_state: self.data_state.clone(),
+            world: self.world,
+        }
+    }
+}
+
+impl<'w, L: Traversal, D: WorldQuery> RelatedFetch<'w, L, D>
+where
+    D::State: Clone,
+{
+    /// Follows the link of `entity` and prepares the inner fetch for the linked entity,
+    /// returning the linked entity and its table row if it matches `D`.
+    ///
+    /// # Safety
+    /// Same as [`WorldQuery::fetch`].
+    #[inline]
+    unsafe fn prepare(
+        &mut self,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> Option<(Entity, TableRow)> {
+        // SAFETY: The invariants are upheld by the caller.
+        let link = unsafe { L::fetch(&mut self.link, entity, table_row) };
+        let target = L::traverse(link)?;
+        let location = self.world.entities().get(target)?;
+        let archetype = self
+            .world
+            .archetypes()
+            .get(location.archetype_id)
+            .debug_checked_unwrap();
+        if !D::matches_component_set(&self.data_state, &|id| archetype.contains(id)) {
+            return None;
+        }
+        // SAFETY: Only the tables of components `D` accesses are read, which was registered
+        // in `update_component_access` as joined access.
+        let table = unsafe { self.world.storages() }
+            .tables
+            .get(location.table_id)
+            .debug_checked_unwrap();
+        // SAFETY: `archetype` and `table` belong to the world the fetch was initialized with.
+        unsafe { D::set_archetype(&mut self.data, &self.data_state, archetype, table) };
+        Some((target, location.table_row))
+    }
+}
+
+/// Adds the access of `D` to `access`, marking it as joined access which applies to any entity.
+fn update_related_access<D: WorldQuery>(
+    state: &D::State,
+    access: &mut FilteredAccess<ComponentId>,
+) {
+    // Running `D` against an empty access records only the components `D` itself reads.
+    let mut joined = FilteredAccess::default();
+    D::update_component_access(state, &mut joined);
+    assert!(
+        access.access().is_compatible(joined.access()),
+        "{} conflicts with a previous access in this query. Components read on related entities cannot coincide with exclusive access.",
+        core::any::type_name::<D>(),
+    );
+    access.extend_joined_access(joined.access());
+}
+
+/// SAFETY:
+/// `fetch` accesses the components of `L` on the current entity, and the components of `D` on the linked entity.
+/// This is sound because `update_component_access` adds the access of `L`, and adds the access of `D`
+/// as joined access, which is registered for every archetype and is not restricted by the query filters.
+/// `D` is read-only, so several entities linking to the same entity never produce aliasing mutable references.
+/// `update_component_access` adds the same filters as `L`.
+/// This is sound because `matches_component_set` forwards to `L`.
+unsafe impl<L: Traversal, D: ReadOnlyQueryData> WorldQuery for Related<L, D>
+where
+    D::State: Clone,
+{
+    type Item<'w> = Option<D::Item<'w>>;
+    type Fetch<'w> = RelatedFetch<'w, L, D>;
+    type State = (L::State, D::State);
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::Item<'wlong>) -> Self::Item<'wshort> {
+        item.map(D::shrink)
+    }
+
+    fn shrink_fetch<'wlong: 'wshort, 'wshort>(fetch: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {
+        RelatedFetch {
+            link: L::shrink_fetch(fetch.link),
+            data: D::shrink_fetch(fetch.data),
+            data_state: fetch.data_state,
+            world: fetch.world,
+        }
+    }
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        state: &Self::State,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        RelatedFetch {
+            // SAFETY: The invariants are upheld by the caller.
+            link: unsafe { L::init_fetch(world, &state.0, last_run, this_run) },
+            // SAFETY: The invariants are upheld by the caller.
+            data: unsafe { D::init_fetch(world, &state.1, last_run, this_run) },
+            data_state: state.1.clone(),
+            world,
+        }
+    }
+
+    const IS_DENSE: bool = L::IS_DENSE;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        state: &Self::State,
+        archetype: &'w Archetype,
+        table: &'w Table,
+    ) {
+        // SAFETY: The invariants are upheld by the caller.
+        unsafe { L::set_archetype(&mut fetch.link, &state.0, archetype, table) };
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(fetch: &mut Self::Fetch<'w>, state: &Self::State, table: &'w Table) {
+        // SAFETY: The invariants are upheld by the caller.
+        unsafe { L::set_table(&mut fetch.link, &state.0, table) };
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> Self::Item<'w> {
+        // SAFETY: The invariants are upheld by the caller.
+        let (target, target_row) = unsafe { fetch.prepare(entity, table_row) }?;
+        // SAFETY: `prepare` set up the fetch for the archetype of `target`.
+        Some(unsafe { D::fetch(&mut fetch.data, target, target_row) })
+    }
+
+    fn update_component_access(state: &Self::State, access: &mut FilteredAccess<ComponentId>) {
+        L::update_component_access(&state.0, access);
+        update_related_access::<D>(&state.1, access);
+    }
+
+    fn init_state(world: &mut World) -> Self::State {
+        (L::init_state(world), D::init_state(world))
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        Some((L::get_state(components)?, D::get_state(components)?))
+    }
+
+    fn matches_component_set(
+        state: &Self::State,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        L::matches_component_set(&state.0, set_contains_id)
+    }
+}
+
+/// SAFETY: `Self` is the same as `Self::ReadOnly`
+unsafe impl<L: Traversal, D: ReadOnlyQueryData> QueryData for Related<L, D>
+where
+    D::State: Clone,
+{
+    type ReadOnly = Self;
+}
+
+/// SAFETY: `L` and `D` are read only
+unsafe impl<L: Traversal, D: ReadOnlyQueryData> ReadOnlyQueryData for Related<L, D> where
+    D::State: Clone
+{
+}
+
+/// SAFETY:
+/// `fetch` accesses the components of `L` on the current entity, and the components of `F` on the linked entity.
+/// This is sound because `update_component_access` adds the access of `L`, and adds the access of `F`
+/// as joined access, which is registered for every archetype and is not restricted by the query filters.
+/// `update_component_access` adds the same filters as `L`.
+/// This is sound because `matches_component_set` forwards to `L`.
+unsafe impl<L: Traversal, F: QueryFilter> WorldQuery for WithRelated<L, F>
+where
+    F::State: Clone,
+{
+    type Item<'w> = bool;
+    type Fetch<'w> = RelatedFetch<'w, L, F>;
+    type State = (L::State, F::State);
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::Item<'wlong>) -> Self::Item<'wshort> {
+        item
+    }
+
+    fn shrink_fetch<'wlong: 'wshort, 'wshort>(fetch: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {
+        RelatedFetch {
+            link: L::shrink_fetch(fetch.link),
+            data: F::shrink_fetch(fetch.data),
+            data_state: fetch.data_state,
+            world: fetch.world,
+        }
+    }
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        state: &Self::State,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        RelatedFetch {
+            // SAFETY: The invariants are upheld by the caller.
+            link: unsafe { L::init_fetch(world, &state.0, last_run, this_run) },
+            // SAFETY: The invariants are upheld by the caller.
+            data: unsafe { F::init_fetch(world, &state.1, last_run, this_run) },
+            data_state: state.1.clone(),
+            world,
+        }
+    }
+
+    const IS_DENSE: bool = L::IS_DENSE;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        state: &Self::State,
+        archetype: &'w Archetype,
+        table: &'w Table,
+    ) {
+        // SAFETY: The invariants are upheld by the caller.
+        unsafe { L::set_archetype(&mut fetch.link, &state.0, archetype, table) };
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(fetch: &mut Self::Fetch<'w>, state: &Self::State, table: &'w Table) {
+        // SAFETY: The invariants are upheld by the caller.
+        unsafe { L::set_table(&mut fetch.link, &state.0, table) };
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> Self::Item<'w> {
+        // SAFETY: The invariants are upheld by the caller.
+        unsafe { Self::filter_fetch(fetch, entity, table_row) }
+    }
+
+    fn update_component_access(state: &Self::State, access: &mut FilteredAccess<ComponentId>) {
+        L::update_component_access(&state.0, access);
+        update_related_access::<F>(&state.1, access);
+    }
+
+    fn init_state(world: &mut World) -> Self::State {
+        (L::init_state(world), F::init_state(world))
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        Some((L::get_state(components)?, F::get_state(components)?))
+    }
+
+    fn matches_component_set(
+        state: &Self::State,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        L::matches_component_set(&state.0, set_contains_id)
+    }
+}
+
+/// SAFETY: read-only access
+unsafe impl<L: Traversal, F: QueryFilter> QueryFilter for WithRelated<L, F>
+where
+    F::State: Clone,
+{
+    const IS_ARCHETYPAL: bool = false;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> bool {
+        // SAFETY: The invariants are upheld by the caller.
+        let Some((target, target_row)) = (unsafe { fetch.prepare(entity, table_row) }) else {
+            return false;
+        };
+        // SAFETY: `prepare` set up the fetch for the archetype of `target`.
+        unsafe { F::filter_fetch(&mut fetch.data, target, target_row) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        component::Component,
+        entity::Entity,
+        prelude::{Changed, With, Without},
+        query::{QueryState, Related, WithRelated},
+        system::{assert_is_system, Query},
+        traversal::Traversal,
+        world::World,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component)]
+    struct Link(Entity);
+
+    impl Traversal for &Link {
+        fn traverse(item: Self::Item<'_>) -> Option<Entity> {
+            Some(item.0)
+        }
+    }
+
+    #[derive(Component, Debug, PartialEq)]
+    struct A(usize);
+
+    #[derive(Component)]
+    struct B;
+
+    #[test]
+    fn related_fetches_linked_data() {
+        let mut world = World::new();
+        let target = world.spawn(A(1)).id();
+        let empty = world.spawn_empty().id();
+        let despawned = world.spawn(A(2)).id();
+        let a = world.spawn((A(10), Link(target))).id();
+        let b = world.spawn(Link(empty)).id();
+        let c = world.spawn(Link(despawned)).id();
+        world.spawn(A(3));
+        world.despawn(despawned);
+
+        let mut query = QueryState::<(Entity, Related<&Link, &A>)>::new(&mut world);
+        let mut results: Vec<_> = query
+            .iter(&world)
+            .map(|(entity, a)| (entity, a.map(|a| a.0)))
+            .collect();
+        results.sort_by_key(|(entity, _)| *entity);
+        assert_eq!(results, vec![(a, Some(1)), (b, None), (c, None)]);
+    }
+
+    #[test]
+    fn with_related_filters_on_linked_entity() {
+        let mut world = World::new();
+        let with_b = world.spawn((A(0), B)).id();
+        let without_b = world.spawn(A(1)).id();
+        let a = world.spawn(Link(with_b)).id();
+        world.spawn(Link(without_b));
+
+        let mut query = QueryState::<Entity, WithRelated<&Link, With<B>>>::new(&mut world);
+        assert_eq!(query.iter(&world).collect::<Vec<_>>(), vec![a]);
+
+        let mut changed = QueryState::<Entity, WithRelated<&Link, Changed<A>>>::new(&mut world);
+        assert_eq!(changed.iter(&world).count(), 2);
+        world.clear_trackers();
+        world.get_mut::<A>(without_b).unwrap().0 = 2;
+        assert_eq!(changed.iter(&world).count(), 1);
+    }
+
+    #[test]
+    fn related_access_ignores_query_filters() {
+        fn reads_related(_: Query<Related<&Link, &A>, With<B>>, _: Query<&mut A, Without<B>>) {}
+        let mut world = World::new();
+        let mut system = bevy_ecs::system::IntoSystem::into_system(reads_related);
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            bevy_ecs::system::System::initialize(&mut system, &mut world);
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn related_access_covers_unmatched_archetypes() {
+        fn reads_related(_: Query<Related<&Link, &A>>) {}
+        let mut world = World::new();
+        let target = world.spawn((A(0), B)).id();
+        world.spawn(Link(target));
+        let mut system = bevy_ecs::system::IntoSystem::into_system(reads_related);
+        bevy_ecs::system::System::initialize(&mut system, &mut world);
+        bevy_ecs::system::System::update_archetype_component_access(
+            &mut system,
+            world.as_unsafe_world_cell(),
+        );
+
+        let a_id = world.component_id::<A>().unwrap();
+        let archetype_component_id = world
+            .entity(target)
+            .archetype()
+            .get_archetype_component_id(a_id)
+            .unwrap();
+        let access = bevy_ecs::system::System::archetype_component_access(&system);
+        assert!(access.has_component_read(archetype_component_id));
+        assert!(!access.has_component_write(archetype_component_id));
+    }
+
+    #[test]
+    fn related_access_is_read_only() {
+        fn reads_related(_: Query<Related<&Link, &A>>, _: Query<&A>) {}
+        assert_is_system(reads_related);
+    }
+
+    #[test]
+    #[should_panic(expected = "conflicts with a previous access in this query")]
+    fn related_conflicts_with_own_write() {
+        let mut world = World::new();
+        QueryState::<(&mut A, Related<&Link, &A>)>::new(&mut world);
+    }
+}
@@ -173,6 +173,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                 if state.new_archetype_internal(archetype) {
                     state.update_archetype_component_access(archetype, access);
                 }
+                state.update_archetype_joined_access(archetype, access);
             }
         }
         state.archetype_generation = world.archetypes.generation();
@@ -445,6 +446,8 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             // SAFETY: The caller ensures that `archetype` is from the World the state was initialized from.
             unsafe { self.update_archetype_component_access(archetype, access) };
         }
+        // SAFETY: The caller ensures that `archetype` is from the World the state was initialized from.
+        unsafe { self.update_archetype_joined_access(archetype, access) };
     }
 
     /// Process the given [`Archetype`] to update internal metadata about the [`Table`](crate::storage::Table)s
@@ -497,6 +500,35 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         })
     }
 
+    /// For the given `archetype`, adds the components this query accesses on entities other than the ones it matches
+    /// (see [`FilteredAccess::joined_access`]) to `access`.
+    ///
+    /// Unlike [`QueryState::update_archetype_component_access`], this applies whether or not the archetype
+    /// is matched by the query.
+    ///
+    /// # Safety
+    /// `archetype` must be from the `World` this state was initialized from.
+    unsafe fn update_archetype_joined_access(
+        &self,
+        archetype: &Archetype,
+        access: &mut Access<ArchetypeComponentId>,
+    ) {
+        let joined = self.component_access.joined_access();
+        if !joined.has_any_component_read() {
+            return;
+        }
+        for (component_id, archetype_component_id) in
+            archetype.components_with_archetype_component_id()
+        {
+            if joined.has_component_read(component_id) {
+                access.add_component_read(archetype_component_id);
+            }
+            if joined.has_component_write(component_id) {
+                access.add_component_write(archetype_component_id);
+            }
+        }
+    }
+
     /// For the given `archetype`, adds any component accessed used by this query's underlying [`FilteredAccess`] to `access`.
     ///
     /// The passed in `access` will be updated with any new accesses introduced by the new archetype.
@@ -0,0 +1,417 @@
+//! This module provides functionality to link entities to each other using specialized components called "relationships".
+//! See the [`Relationship`] trait for more info.
+
+mod related_methods;
+mod relationship_source_collection;
+
+pub use relationship_source_collection::*;
+
+use crate::{
+    component::{Component, ComponentId},
+    entity::Entity,
+    traversal::Traversal,
+    world::{DeferredWorld, World},
+};
+use alloc::vec::Vec;
+use bevy_utils::tracing::warn;
+
+/// A [`Component`] on a "source" [`Entity`] that references another target [`Entity`], creating a "relationship" between them.
+/// Every [`Relationship`] has a corresponding [`RelationshipTarget`] type (and vice-versa), which exists on the "target" entity
+/// of the relationship and contains the list of all "source" entities that relate to the given "target".
+///
+/// The [`Relationship`] component is the "source of truth" and the [`RelationshipTarget`] component reflects that source of truth.
+/// When a [`Relationship`] component is inserted on an [`Entity`], the "source" entity is automatically added to the
+/// [`RelationshipTarget`] collection of the target entity (this is done via "component hooks"). If the target entity does not
+/// have a [`RelationshipTarget`] component yet, it is inserted the next time commands are applied. Likewise, an empty
+/// [`RelationshipTarget`] is removed once its last source goes away.
+///
+/// A common example of a [`Relationship`] is the parent / child relationship. Bevy ECS includes a canonical form of this via
+/// the `Parent` and `Children` components in `bevy_hierarchy`. The same consistency guarantees can be obtained for arbitrary
+/// links ("targets", "owned by", "attached to socket", ...) by deriving [`Component`] with the `relationship` and
+/// `relationship_target` attributes:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// #[relationship(relationship_target = Children)]
+/// pub struct ChildOf(pub Entity);
+///
+/// #[derive(Component)]
+/// #[relationship_target(relationship = ChildOf)]
+/// pub struct Children(Vec<Entity>);
+///
+/// let mut world = World::new();
+/// let parent = world.spawn_empty().id();
+/// let child = world.spawn(ChildOf(parent)).id();
+/// world.flush();
+/// assert_eq!(world.entity(parent).get::<Children>().unwrap().collection(), &vec![child]);
+/// ```
+///
+/// When deriving [`RelationshipTarget`] you can configure whether despawning its entity also despawns
+/// all related source entities, using the `linked_spawn` attribute:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # #[derive(Component)]
+/// # #[relationship(relationship_target = Children)]
+/// # pub struct ChildOf(pub Entity);
+/// #[derive(Component)]
+/// #[relationship_target(relationship = ChildOf, linked_spawn)]
+/// pub struct Children(Vec<Entity>);
+/// ```
+pub trait Relationship: Component + Sized {
+    /// The [`Component`] added to the "target" entities of this [`Relationship`], which contains the list of all "source"
+    /// entities that relate to the "target".
+    type RelationshipTarget: RelationshipTarget<Relationship = Self>;
+
+    /// Gets the [`Entity`] ID of the related entity.
+    fn get(&self) -> Entity;
+
+    /// Creates this [`Relationship`] from the given `entity`.
+    fn from(entity: Entity) -> Self;
+
+    /// The `on_insert` component hook that maintains the [`Relationship`] / [`RelationshipTarget`] connection.
+    fn on_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target_entity = world.entity(entity).get::<Self>().unwrap().get();
+        if target_entity == entity {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} points to itself. The invalid {} relationship has been removed.",
+                core::any::type_name::<Self>(),
+                core::any::type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+            return;
+        }
+        let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) else {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} relates to an entity that does not exist. The invalid {} relationship has been removed.",
+                core::any::type_name::<Self>(),
+                core::any::type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+            return;
+        };
+        if let Some(mut relationship_target) =
+            target_entity_mut.get_mut::<Self::RelationshipTarget>()
+        {
+            let collection = relationship_target.collection_mut_risky();
+            if !collection.contains(entity) {
+                collection.add(entity);
+            }
+        } else {
+            // The target component is inserted through a command so that it does not require structural
+            // access here. Several sources may be inserted before it is applied, so the command merges into
+            // an existing target component instead of overwriting it.
+            world.commands().queue(move |world: &mut World| {
+                let still_related = world
+                    .get_entity(entity)
+                    .ok()
+                    .and_then(|source| source.get::<Self>())
+                    .is_some_and(|relationship| relationship.get() == target_entity);
+                if !still_related {
+                    return;
+                }
+                let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) else {
+                    return;
+                };
+                if let Some(mut relationship_target) =
+                    target_entity_mut.get_mut::<Self::RelationshipTarget>()
+                {
+                    let collection = relationship_target.collection_mut_risky();
+                    if !collection.contains(entity) {
+                        collection.add(entity);
+                    }
+                } else {
+                    let mut collection =
+                        <<Self::RelationshipTarget as RelationshipTarget>::Collection as RelationshipSourceCollection>::with_capacity(1);
+                    collection.add(entity);
+                    target_entity_mut.insert(
+                        <Self::RelationshipTarget as RelationshipTarget>::from_collection_risky(
+                            collection,
+                        ),
+                    );
+                }
+            });
+        }
+    }
+
+    /// The `on_replace` component hook that maintains the [`Relationship`] / [`RelationshipTarget`] connection.
+    // note: think of this as "on_drop"
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target_entity = world.entity(entity).get::<Self>().unwrap().get();
+        let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) else {
+            return;
+        };
+        let Some(mut relationship_target) = target_entity_mut.get_mut::<Self::RelationshipTarget>()
+        else {
+            return;
+        };
+        relationship_target.collection_mut_risky().remove(entity);
+        if relationship_target.is_empty() {
+            world.commands().queue(move |world: &mut World| {
+                let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) else {
+                    return;
+                };
+                // The collection may have been refilled before this command was applied.
+                if target_entity_mut
+                    .get::<Self::RelationshipTarget>()
+                    .is_some_and(RelationshipTarget::is_empty)
+                {
+                    target_entity_mut.remove::<Self::RelationshipTarget>();
+                }
+            });
+        }
+    }
+}
+
+/// A [`Component`] containing the collection of entities that relate to this [`Entity`] via the associated `Relationship` type.
+/// See the [`Relationship`] documentation for more information.
+pub trait RelationshipTarget: Component + Sized {
+    /// If this is true, when despawning an entity with this [`RelationshipTarget`], all related source entities
+    /// will also be despawned. Otherwise, the [`Relationship`] component is removed from the related entities.
+    const LINKED_SPAWN: bool;
+
+    /// The [`Relationship`] that populates this [`RelationshipTarget`] collection.
+    type Relationship: Relationship<RelationshipTarget = Self>;
+
+    /// The collection type that stores the "source" entities for this [`RelationshipTarget`] component.
+    type Collection: RelationshipSourceCollection;
+
+    /// Returns a reference to the stored [`RelationshipTarget::Collection`].
+    fn collection(&self) -> &Self::Collection;
+
+    /// Returns a mutable reference to the stored [`RelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as modifying the internal collection could invalidate the relationship.
+    fn collection_mut_risky(&mut self) -> &mut Self::Collection;
+
+    /// Creates a new [`RelationshipTarget`] from the given [`RelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as constructing the internal collection could invalidate the relationship.
+    fn from_collection_risky(collection: Self::Collection) -> Self;
+
+    /// The `on_replace` component hook that maintains the [`Relationship`] / [`RelationshipTarget`] connection.
+    ///
+    /// If the target entity is being despawned and [`RelationshipTarget::LINKED_SPAWN`] is set, the related
+    /// entities are despawned as well. Otherwise the [`Relationship`] component is removed from them.
+    // note: think of this as "on_drop"
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let sources: Vec<Entity> = world.entity(entity).get::<Self>().unwrap().iter().collect();
+        if sources.is_empty() {
+            return;
+        }
+        world.commands().queue(move |world: &mut World| {
+            // Hooks cannot tell a despawn apart from a removal, but by the time this command is applied
+            // a despawned target entity no longer exists.
+            let target = world.get_entity(entity).ok();
+            let despawned = target.is_none();
+            let replacement: Vec<Entity> = target
+                .and_then(|target| target.get::<Self>())
+                .map(|relationship_target| relationship_target.iter().collect())
+                .unwrap_or_default();
+            for source in sources {
+                if replacement.as_slice().contains(&source) {
+                    continue;
+                }
+                let Ok(mut source_mut) = world.get_entity_mut(source) else {
+                    continue;
+                };
+                if !source_mut
+                    .get::<Self::Relationship>()
+                    .is_some_and(|relationship| relationship.get() == entity)
+                {
+                    continue;
+                }
+                if despawned && Self::LINKED_SPAWN {
+                    source_mut.despawn();
+                } else {
+                    source_mut.remove::<Self::Relationship>();
+                }
+            }
+        });
+    }
+
+    /// Iterates the entities stored in this collection.
+    #[inline]
+    fn iter(&self) -> <Self::Collection as RelationshipSourceCollection>::SourceIter<'_> {
+        self.collection().iter()
+    }
+
+    /// Returns the number of entities in this collection.
+    #[inline]
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns true if this entity collection is empty.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+}
+
+/// Allows following the "source" to "target" direction of any [`Relationship`] when propagating events.
+impl<R: Relationship> Traversal for &R {
+    fn traverse(item: Self::Item<'_>) -> Option<Entity> {
+        Some(item.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        component::Component,
+        entity::Entity,
+        event::Event,
+        observer::Trigger,
+        relationship::{Relationship, RelationshipTarget},
+        system::{ResMut, Resource},
+        world::World,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component)]
+    #[relationship(relationship_target = LikedBy)]
+    struct Likes(pub Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = Likes)]
+    struct LikedBy(Vec<Entity>);
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Owns)]
+    struct OwnedBy {
+        owner: Entity,
+    }
+
+    #[derive(Component)]
+    #[relationship_target(relationship = OwnedBy, linked_spawn)]
+    struct Owns {
+        items: Vec<Entity>,
+    }
+
+    #[derive(Component)]
+    struct Poke;
+
+    impl Event for Poke {
+        type Traversal = &'static Likes;
+
+        const AUTO_PROPAGATE: bool = true;
+    }
+
+    #[derive(Resource, Default)]
+    struct Poked(Vec<Entity>);
+
+    #[test]
+    fn relationship_inserts_and_removes_target() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(Likes(a)).id();
+        let c = world.spawn(Likes(a)).id();
+        world.flush();
+        assert_eq!(world.entity(a).get::<LikedBy>().unwrap().0, vec![b, c]);
+
+        world.entity_mut(b).remove::<Likes>();
+        world.flush();
+        assert_eq!(world.entity(a).get::<LikedBy>().unwrap().0, vec![c]);
+
+        world.entity_mut(c).remove::<Likes>();
+        world.flush();
+        assert!(world.entity(a).get::<LikedBy>().is_none());
+    }
+
+    #[test]
+    fn relationship_replace_moves_source() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn(Likes(a)).id();
+        world.entity_mut(c).insert(Likes(b));
+        world.flush();
+        assert!(world.entity(a).get::<LikedBy>().is_none());
+        assert_eq!(world.entity(b).get::<LikedBy>().unwrap().0, vec![c]);
+    }
+
+    #[test]
+    fn invalid_relationships_are_removed() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        world.entity_mut(a).insert(Likes(a));
+        world.flush();
+        assert!(world.entity(a).get::<Likes>().is_none());
+        assert!(world.entity(a).get::<LikedBy>().is_none());
+
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+        let b = world.spawn(Likes(missing)).id();
+        world.flush();
+        assert!(world.entity(b).get::<Likes>().is_none());
+    }
+
+    #[test]
+    fn despawn_target_removes_relationship() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(Likes(a)).id();
+        world.despawn(a);
+        world.flush();
+        assert!(world.get_entity(b).is_ok());
+        assert!(world.entity(b).get::<Likes>().is_none());
+    }
+
+    #[test]
+    fn despawn_target_cascades_with_linked_spawn() {
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        let item = world.spawn(OwnedBy { owner }).id();
+        let nested = world.spawn(OwnedBy { owner: item }).id();
+        world.flush();
+        assert_eq!(world.entity(owner).get::<Owns>().unwrap().items, vec![item]);
+
+        world.despawn(owner);
+        world.flush();
+        assert!(world.get_entity(item).is_err());
+        assert!(world.get_entity(nested).is_err());
+    }
+
+    #[test]
+    fn removing_target_keeps_linked_sources() {
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        let item = world.spawn(OwnedBy { owner }).id();
+        world.flush();
+        world.entity_mut(owner).remove::<Owns>();
+        world.flush();
+        assert!(world.get_entity(item).is_ok());
+        assert!(world.entity(item).get::<OwnedBy>().is_none());
+    }
+
+    #[test]
+    fn relationship_traits() {
+        let a = Entity::from_raw(1);
+        assert_eq!(<Likes as Relationship>::from(a).get(), a);
+        let liked_by = <LikedBy as RelationshipTarget>::from_collection_risky(vec![a]);
+        assert_eq!(liked_by.iter().collect::<Vec<_>>(), vec![a]);
+        assert_eq!(liked_by.len(), 1);
+    }
+
+    #[test]
+    fn relationship_traversal() {
+        let mut world = World::new();
+        world.init_resource::<Poked>();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(Likes(a)).id();
+        let c = world.spawn(Likes(b)).id();
+        world.flush();
+        world.add_observer(|trigger: Trigger<Poke>, mut poked: ResMut<Poked>| {
+            poked.0.push(trigger.entity());
+        });
+        world.flush();
+        world.trigger_targets(Poke, c);
+        world.flush();
+        assert_eq!(world.resource::<Poked>().0, vec![c, b, a]);
+    }
+}
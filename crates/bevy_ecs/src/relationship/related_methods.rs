@@ -0,0 +1,122 @@
+use crate::{
+    bundle::Bundle,
+    entity::Entity,
+    relationship::{Relationship, RelationshipTarget},
+    system::EntityCommands,
+    world::EntityWorldMut,
+};
+use alloc::vec::Vec;
+
+impl<'w> EntityWorldMut<'w> {
+    /// Spawns an entity related to this entity (with the `R` relationship) by taking a bundle.
+    pub fn with_related<R: Relationship>(&mut self, bundle: impl Bundle) -> &mut Self {
+        let id = self.id();
+        self.world_scope(|world| {
+            world.spawn((bundle, R::from(id)));
+            world.flush();
+        });
+        self
+    }
+
+    /// Relates the given entities to this entity with the relation `R`.
+    pub fn add_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let id = self.id();
+        self.world_scope(|world| {
+            for related in related {
+                world.entity_mut(*related).insert(R::from(id));
+            }
+            world.flush();
+        });
+        self
+    }
+
+    /// Despawns entities that relate to this one via the given [`RelationshipTarget`].
+    /// This entity will not be despawned.
+    pub fn despawn_related<S: RelationshipTarget>(&mut self) -> &mut Self {
+        if let Some(sources) = self.take::<S>() {
+            self.world_scope(|world| {
+                for entity in sources.iter() {
+                    if let Ok(entity_mut) = world.get_entity_mut(entity) {
+                        entity_mut.despawn();
+                    }
+                }
+                world.flush();
+            });
+        }
+        self
+    }
+}
+
+impl<'a> EntityCommands<'a> {
+    /// Spawns an entity related to this entity (with the `R` relationship) by taking a bundle.
+    pub fn with_related<R: Relationship>(&mut self, bundle: impl Bundle) -> &mut Self {
+        let id = self.id();
+        self.commands().spawn((bundle, R::from(id)));
+        self
+    }
+
+    /// Relates the given entities to this entity with the relation `R`.
+    pub fn add_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let related: Vec<Entity> = related.to_vec();
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.add_related::<R>(&related);
+        })
+    }
+
+    /// Despawns entities that relate to this one via the given [`RelationshipTarget`].
+    /// This entity will not be despawned.
+    pub fn despawn_related<S: RelationshipTarget>(&mut self) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.despawn_related::<S>();
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{component::Component, entity::Entity, world::World};
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Inventory)]
+    struct InInventory(pub Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = InInventory)]
+    struct Inventory(Vec<Entity>);
+
+    #[derive(Component)]
+    struct Item;
+
+    #[test]
+    fn add_and_despawn_related() {
+        let mut world = World::new();
+        let a = world.spawn(Item).id();
+        let b = world.spawn(Item).id();
+        let owner = world.spawn_empty().add_related::<InInventory>(&[a, b]).id();
+        assert_eq!(
+            world.entity(owner).get::<Inventory>().unwrap().0,
+            vec![a, b]
+        );
+
+        world.entity_mut(owner).despawn_related::<Inventory>();
+        assert!(world.get_entity(a).is_err());
+        assert!(world.get_entity(b).is_err());
+        assert!(world.entity(owner).get::<Inventory>().is_none());
+    }
+
+    #[test]
+    fn with_related_commands() {
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(owner)
+            .with_related::<InInventory>(Item);
+        world.flush();
+        let inventory = world.entity(owner).get::<Inventory>().unwrap();
+        assert_eq!(inventory.0.len(), 1);
+        assert!(world.entity(inventory.0[0]).contains::<Item>());
+    }
+}
@@ -0,0 +1,114 @@
+use crate::entity::Entity;
+use alloc::vec::Vec;
+use smallvec::SmallVec;
+
+/// The internal [`Entity`] collection used by a [`RelationshipTarget`](crate::relationship::RelationshipTarget) component.
+/// This is not intended to be modified directly by users, as it could invalidate the correctness of relationships.
+pub trait RelationshipSourceCollection {
+    /// The type of iterator returned by [`RelationshipSourceCollection::iter`].
+    type SourceIter<'a>: Iterator<Item = Entity>
+    where
+        Self: 'a;
+
+    /// Returns an instance with the given pre-allocated entity `capacity`.
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// Adds the given `entity` to the collection.
+    fn add(&mut self, entity: Entity);
+
+    /// Removes the given `entity` from the collection.
+    fn remove(&mut self, entity: Entity);
+
+    /// Returns `true` if the collection contains the given `entity`.
+    fn contains(&self, entity: Entity) -> bool {
+        self.iter().any(|e| e == entity)
+    }
+
+    /// Iterates all entities in the collection.
+    fn iter(&self) -> Self::SourceIter<'_>;
+
+    /// Returns the current length of the collection.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the collection contains no entities.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl RelationshipSourceCollection for Vec<Entity> {
+    type SourceIter<'a> = core::iter::Copied<core::slice::Iter<'a, Entity>>;
+
+    fn with_capacity(capacity: usize) -> Self {
+        Vec::with_capacity(capacity)
+    }
+
+    fn add(&mut self, entity: Entity) {
+        Vec::push(self, entity);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        if let Some(index) = <[Entity]>::iter(self).position(|e| *e == entity) {
+            Vec::remove(self, index);
+        }
+    }
+
+    fn iter(&self) -> Self::SourceIter<'_> {
+        <[Entity]>::iter(self).copied()
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+impl<A: smallvec::Array<Item = Entity>> RelationshipSourceCollection for SmallVec<A> {
+    type SourceIter<'a>
+        = core::iter::Copied<core::slice::Iter<'a, Entity>>
+    where
+        A: 'a;
+
+    fn with_capacity(capacity: usize) -> Self {
+        SmallVec::with_capacity(capacity)
+    }
+
+    fn add(&mut self, entity: Entity) {
+        SmallVec::push(self, entity);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        if let Some(index) = <[Entity]>::iter(self).position(|e| *e == entity) {
+            SmallVec::remove(self, index);
+        }
+    }
+
+    fn iter(&self) -> Self::SourceIter<'_> {
+        <[Entity]>::iter(self).copied()
+    }
+
+    fn len(&self) -> usize {
+        SmallVec::len(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec_add_remove() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        let mut collection = <Vec<Entity> as RelationshipSourceCollection>::with_capacity(2);
+        collection.add(a);
+        collection.add(b);
+        assert!(RelationshipSourceCollection::contains(&collection, a));
+        RelationshipSourceCollection::remove(&mut collection, a);
+        assert!(!RelationshipSourceCollection::contains(&collection, a));
+        assert_eq!(RelationshipSourceCollection::len(&collection), 1);
+        // Removing an entity that is not in the collection is a no-op.
+        RelationshipSourceCollection::remove(&mut collection, a);
+        assert_eq!(RelationshipSourceCollection::len(&collection), 1);
+    }
+}
@@ -11,7 +11,7 @@ pub use self::{
 use fixedbitset::FixedBitSet;
 
 use crate::{
-    schedule::{BoxedCondition, NodeId},
+    schedule::{profiling::ScheduleRunProfile, BoxedCondition, NodeId},
     system::BoxedSystem,
     world::World,
 };
@@ -81,6 +81,8 @@ pub struct SystemSchedule {
     ///
     /// If a set doesn't run because of its conditions, this is used to skip all systems in it.
     pub(super) systems_in_sets_with_conditions: Vec<FixedBitSet>,
+    /// Timings of the current run, if [`SystemProfiles`](super::SystemProfiles) are being collected.
+    pub(super) profile: Option<ScheduleRunProfile>,
 }
 
 impl SystemSchedule {
@@ -96,6 +98,7 @@ impl SystemSchedule {
             system_dependents: Vec::new(),
             sets_with_conditions_of_systems: Vec::new(),
             systems_in_sets_with_conditions: Vec::new(),
+            profile: None,
         }
     }
 }
@@ -7,7 +7,7 @@ use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
 use bevy_utils::tracing::info_span;
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::Span;
-use bevy_utils::{default, syncunsafecell::SyncUnsafeCell};
+use bevy_utils::{default, syncunsafecell::SyncUnsafeCell, Duration, Instant};
 use core::panic::AssertUnwindSafe;
 
 use concurrent_queue::ConcurrentQueue;
@@ -32,6 +32,8 @@ struct Environment<'env, 'sys> {
     systems: &'sys [SyncUnsafeCell<BoxedSystem>],
     conditions: SyncUnsafeCell<Conditions<'sys>>,
     world_cell: UnsafeWorldCell<'env>,
+    /// Whether system run times should be measured.
+    profiling: bool,
 }
 
 struct Conditions<'a> {
@@ -56,6 +58,7 @@ impl<'env, 'sys> Environment<'env, 'sys> {
                 sets_with_conditions_of_systems: &schedule.sets_with_conditions_of_systems,
                 systems_in_sets_with_conditions: &schedule.systems_in_sets_with_conditions,
             }),
+            profiling: schedule.profile.is_some(),
             world_cell: world.as_unsafe_world_cell(),
         }
     }
@@ -77,6 +80,8 @@ struct SystemTaskMetadata {
 /// The result of running a system that is sent across a channel.
 struct SystemResult {
     system_index: usize,
+    /// How long the system took to run, if profiling is enabled.
+    duration: Option<Duration>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -123,6 +128,10 @@ pub struct ExecutorState {
     completed_systems: FixedBitSet,
     /// Systems that have run but have not had their buffers applied.
     unapplied_systems: FixedBitSet,
+    /// How long each system took to run, if profiling is enabled.
+    system_durations: Vec<Option<Duration>>,
+    /// The highest number of systems that were running at the same time.
+    max_running_systems: usize,
 }
 
 /// References to data required by the executor.
@@ -190,6 +199,10 @@ impl SystemExecutor for MultiThreadedExecutor {
             return;
         }
         state.num_running_systems = 0;
+        state.max_running_systems = 0;
+        if let Some(profile) = &schedule.profile {
+            state.system_durations.clone_from(&profile.system_durations);
+        }
         state
             .num_dependencies_remaining
             .clone_from(&schedule.system_dependencies);
@@ -245,6 +258,11 @@ impl SystemExecutor for MultiThreadedExecutor {
             state.unapplied_systems.clear();
         }
 
+        if let Some(profile) = &mut schedule.profile {
+            core::mem::swap(&mut profile.system_durations, &mut state.system_durations);
+            profile.max_concurrent_systems = state.max_running_systems;
+        }
+
         // check to see if there was a panic
         let payload = self.panic_payload.get_mut().unwrap();
         if let Some(payload) = payload.take() {
@@ -270,12 +288,16 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         system_index: usize,
         res: Result<(), Box<dyn Any + Send>>,
         system: &BoxedSystem,
+        duration: Option<Duration>,
     ) {
         // tell the executor that the system finished
         self.environment
             .executor
             .system_completion
-            .push(SystemResult { system_index })
+            .push(SystemResult {
+                system_index,
+                duration,
+            })
             .unwrap_or_else(|error| unreachable!("{}", error));
         if let Err(payload) = res {
             eprintln!("Encountered a panic in system `{}`!", &*system.name());
@@ -349,6 +371,8 @@ impl ExecutorState {
             skipped_systems: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
+            system_durations: Vec::new(),
+            max_running_systems: 0,
         }
     }
 
@@ -431,6 +455,7 @@ impl ExecutorState {
 
                 self.running_systems.insert(system_index);
                 self.num_running_systems += 1;
+                self.max_running_systems = self.max_running_systems.max(self.num_running_systems);
 
                 if self.system_task_metadata[system_index].is_exclusive {
                     // SAFETY: `can_run` returned true for this system,
@@ -597,6 +622,7 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
+            let start = context.environment.profiling.then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 // SAFETY:
                 // - The caller ensures that we have permission to
@@ -609,7 +635,8 @@ impl ExecutorState {
                     );
                 };
             }));
-            context.system_completed(system_index, res, system);
+            let duration = start.map(|start| start.elapsed());
+            context.system_completed(system_index, res, system, duration);
         };
 
         self.active_access
@@ -640,7 +667,7 @@ impl ExecutorState {
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
                 let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
-                context.system_completed(system_index, res, system);
+                context.system_completed(system_index, res, system, None);
             };
 
             context.scope.spawn_on_scope(task);
@@ -649,10 +676,12 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
+                let start = context.environment.profiling.then(Instant::now);
                 let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     __rust_begin_short_backtrace::run(&mut **system, world);
                 }));
-                context.system_completed(system_index, res, system);
+                let duration = start.map(|start| start.elapsed());
+                context.system_completed(system_index, res, system, duration);
             };
 
             context.scope.spawn_on_scope(task);
@@ -663,7 +692,14 @@ impl ExecutorState {
     }
 
     fn finish_system_and_handle_dependents(&mut self, result: SystemResult) {
-        let SystemResult { system_index, .. } = result;
+        let SystemResult {
+            system_index,
+            duration,
+        } = result;
+
+        if duration.is_some() {
+            self.system_durations[system_index] = duration;
+        }
 
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
@@ -1,5 +1,6 @@
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
+use bevy_utils::Instant;
 use core::panic::AssertUnwindSafe;
 use fixedbitset::FixedBitSet;
 
@@ -99,6 +100,7 @@ impl SystemExecutor for SimpleExecutor {
                 continue;
             }
 
+            let profile_start = schedule.profile.is_some().then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 __rust_begin_short_backtrace::run(&mut **system, world);
             }));
@@ -106,6 +108,9 @@ impl SystemExecutor for SimpleExecutor {
                 eprintln!("Encountered a panic in system `{}`!", &*system.name());
                 std::panic::resume_unwind(payload);
             }
+            if let (Some(profile), Some(start)) = (&mut schedule.profile, profile_start) {
+                profile.record_system(system_index, start.elapsed());
+            }
         }
 
         self.evaluated_sets.clear();
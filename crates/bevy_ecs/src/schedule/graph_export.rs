@@ -0,0 +1,448 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use bevy_utils::{HashMap, HashSet};
+use petgraph::Direction::Outgoing;
+
+use crate::{
+    component::Components,
+    schedule::{BoxedCondition, Dag, NodeId, Schedule, ScheduleGraph},
+    system::System,
+};
+
+/// A plain-data description of a [`Schedule`]'s graph, which can be rendered to
+/// [DOT](ScheduleGraphExport::to_dot), or serialized with `serde` when the `serialize` feature is enabled.
+///
+/// Nodes are identified by strings such as `"system3"` or `"set1"`, built from their [`NodeId`].
+/// Systems and sets are listed in the order they were added to the schedule, and edges are sorted by node,
+/// so exporting the same schedule twice gives the same output. This makes exports suitable for diffing.
+///
+/// Conflicts are only known once the schedule has been [initialized](Schedule::initialize).
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// fn spawn_player() {}
+/// fn move_player() {}
+///
+/// let mut world = World::new();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems((spawn_player, move_player).chain());
+/// schedule.initialize(&mut world).unwrap();
+///
+/// let export = schedule.export_graph(world.components());
+/// assert_eq!(export.systems.len(), 2);
+/// assert_eq!(export.dependencies.len(), 1);
+/// assert!(export.to_dot().contains("\"system0\" -> \"system1\""));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduleGraphExport {
+    /// The [`Debug`] representation of the schedule's label.
+    pub label: String,
+    /// The systems of the schedule.
+    pub systems: Vec<SystemNodeExport>,
+    /// The system sets of the schedule.
+    pub sets: Vec<SystemSetNodeExport>,
+    /// Edges from each set to the systems and sets it contains.
+    pub hierarchy: Vec<GraphEdgeExport>,
+    /// Edges from each system or set to the systems and sets that must run after it.
+    pub dependencies: Vec<GraphEdgeExport>,
+    /// Pairs of systems or sets that were allowed to be ambiguous with each other.
+    pub ambiguous_with: Vec<GraphEdgeExport>,
+    /// Systems and sets that were allowed to be ambiguous with every other system.
+    pub ambiguous_with_all: Vec<String>,
+    /// Pairs of systems whose data access conflicts and whose order is not defined.
+    pub conflicts: Vec<SystemConflictExport>,
+}
+
+/// A system of a [`ScheduleGraphExport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemNodeExport {
+    /// The identifier of the node.
+    pub id: String,
+    /// The name of the system.
+    pub name: String,
+    /// The names of the run conditions of the system.
+    pub conditions: Vec<String>,
+    /// Whether the system requires exclusive [`World`](crate::world::World) access.
+    pub exclusive: bool,
+}
+
+/// A system set of a [`ScheduleGraphExport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemSetNodeExport {
+    /// The identifier of the node.
+    pub id: String,
+    /// The name of the set.
+    pub name: String,
+    /// The names of the run conditions of the set.
+    pub conditions: Vec<String>,
+    /// Whether this is the set automatically created for each system type.
+    pub system_type: bool,
+    /// Whether this is an anonymous set, created when configuring several systems at once.
+    pub anonymous: bool,
+}
+
+/// An edge of a [`ScheduleGraphExport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct GraphEdgeExport {
+    /// The identifier of the source node.
+    pub from: String,
+    /// The identifier of the target node.
+    pub to: String,
+}
+
+/// A conflict between two systems of a [`ScheduleGraphExport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemConflictExport {
+    /// The identifier of the first system.
+    pub a: String,
+    /// The identifier of the second system.
+    pub b: String,
+    /// The names of the components and resources both systems access, at least one of them mutably.
+    ///
+    /// If this is empty, the systems conflict on [`World`](crate::world::World) access.
+    pub components: Vec<String>,
+}
+
+impl Schedule {
+    /// Exports the graph of this schedule to a [`ScheduleGraphExport`].
+    ///
+    /// `components` is used to name the components involved in conflicts, and must come from the
+    /// [`World`](crate::world::World) the schedule was initialized with.
+    pub fn export_graph(&self, components: &Components) -> ScheduleGraphExport {
+        let graph = self.graph();
+        let condition_names = |conditions: &[BoxedCondition]| {
+            conditions
+                .iter()
+                .map(|condition| condition.name().to_string())
+                .collect()
+        };
+        // Edges are sorted by `NodeId` rather than by their string identifiers, so that `system10` comes after `system9`.
+        let edges = |mut edges: Vec<(NodeId, NodeId)>| {
+            edges.sort();
+            edges
+                .into_iter()
+                .map(|(from, to)| GraphEdgeExport {
+                    from: node_id_string(from),
+                    to: node_id_string(to),
+                })
+                .collect()
+        };
+
+        // Once the schedule is initialized, systems and conditions are moved from the graph to the executable schedule.
+        let executable = self.executable();
+        let mut systems: Vec<(NodeId, &dyn System<In = (), Out = ()>, &[BoxedCondition])> = graph
+            .systems()
+            .chain(
+                executable
+                    .system_ids
+                    .iter()
+                    .zip(&executable.systems)
+                    .zip(&executable.system_conditions)
+                    .map(|((id, system), conditions)| (*id, &**system, conditions.as_slice())),
+            )
+            .collect();
+        systems.sort_by_key(|(id, ..)| *id);
+        let system_names: HashMap<NodeId, String> = systems
+            .iter()
+            .map(|(id, system, _)| (*id, system.name().to_string()))
+            .collect();
+
+        let set_conditions: HashMap<NodeId, &[BoxedCondition]> = executable
+            .set_ids
+            .iter()
+            .zip(&executable.set_conditions)
+            .map(|(id, conditions)| (*id, conditions.as_slice()))
+            .collect();
+        let mut sets: Vec<_> = graph.system_sets().collect();
+        sets.sort_by_key(|(id, ..)| *id);
+        let sets = sets
+            .into_iter()
+            .map(|(id, set, conditions)| SystemSetNodeExport {
+                id: node_id_string(id),
+                name: set_name(graph, &system_names, id),
+                conditions: condition_names(set_conditions.get(&id).copied().unwrap_or(conditions)),
+                system_type: set.system_type().is_some(),
+                anonymous: set.is_anonymous(),
+            })
+            .collect();
+        let systems = systems
+            .into_iter()
+            .map(|(id, system, conditions)| SystemNodeExport {
+                id: node_id_string(id),
+                name: system.name().to_string(),
+                conditions: condition_names(conditions),
+                exclusive: system.is_exclusive(),
+            })
+            .collect();
+
+        let dag_edges = |dag: &Dag| {
+            dag.graph()
+                .all_edges()
+                .map(|(from, to, _)| (from, to))
+                .collect()
+        };
+
+        let mut ambiguous_with_all: Vec<NodeId> = graph.ambiguous_with_all().collect();
+        ambiguous_with_all.sort();
+
+        let mut conflicts: Vec<_> = graph.conflicting_systems().iter().collect();
+        conflicts.sort_by_key(|(a, b, _)| (*a, *b));
+        let conflicts = conflicts
+            .into_iter()
+            .map(|(a, b, conflicts)| SystemConflictExport {
+                a: node_id_string(*a),
+                b: node_id_string(*b),
+                components: conflicts
+                    .iter()
+                    .map(|id| components.get_name(*id).unwrap_or("<unknown>").to_string())
+                    .collect(),
+            })
+            .collect();
+
+        ScheduleGraphExport {
+            label: format!("{:?}", self.label()),
+            systems,
+            sets,
+            hierarchy: edges(dag_edges(graph.hierarchy())),
+            dependencies: edges(dag_edges(graph.dependency())),
+            ambiguous_with: edges(
+                graph
+                    .ambiguous_with()
+                    .all_edges()
+                    .map(|(a, b, _)| (a.min(b), a.max(b)))
+                    .collect(),
+            ),
+            ambiguous_with_all: ambiguous_with_all.into_iter().map(node_id_string).collect(),
+            conflicts,
+        }
+    }
+}
+
+/// Returns the name of a set, naming anonymous sets after their members.
+fn set_name(graph: &ScheduleGraph, system_names: &HashMap<NodeId, String>, id: NodeId) -> String {
+    let set = graph.set_at(id);
+    if !set.is_anonymous() {
+        return format!("{set:?}");
+    }
+    let members: Vec<String> = graph
+        .hierarchy()
+        .graph()
+        .neighbors_directed(id, Outgoing)
+        .map(|member| match member {
+            NodeId::System(_) => system_names.get(&member).cloned().unwrap_or_default(),
+            NodeId::Set(_) => set_name(graph, system_names, member),
+        })
+        .collect();
+    format!("({})", members.join(", "))
+}
+
+fn node_id_string(id: NodeId) -> String {
+    match id {
+        NodeId::System(index) => format!("system{index}"),
+        NodeId::Set(index) => format!("set{index}"),
+    }
+}
+
+impl ScheduleGraphExport {
+    /// Renders the graph in the [DOT](https://graphviz.org/doc/info/lang.html) language of Graphviz.
+    ///
+    /// Systems are drawn as boxes and sets as ellipses, with run conditions listed under their name.
+    /// Dependencies are drawn as solid arrows, set membership as dashed arrows,
+    /// allowed ambiguities as dotted lines and conflicts as red lines labeled with the conflicting data.
+    ///
+    /// To reduce clutter, the sets automatically created for each system type are only drawn
+    /// when they are used in a dependency or an ambiguity.
+    pub fn to_dot(&self) -> String {
+        let mut referenced = HashSet::new();
+        for edge in self.dependencies.iter().chain(&self.ambiguous_with) {
+            referenced.insert(edge.from.as_str());
+            referenced.insert(edge.to.as_str());
+        }
+        referenced.extend(self.ambiguous_with_all.iter().map(String::as_str));
+
+        let mut drawn = HashSet::new();
+        let mut dot = String::new();
+        let _ = writeln!(dot, "digraph {} {{", dot_string(&self.label));
+        let _ = writeln!(dot, "  node [shape=box];");
+        for system in &self.systems {
+            drawn.insert(system.id.as_str());
+            let _ = writeln!(
+                dot,
+                "  {} [label={}];",
+                dot_string(&system.id),
+                dot_string(&node_label(&system.name, &system.conditions))
+            );
+        }
+        for set in &self.sets {
+            if set.system_type && !referenced.contains(set.id.as_str()) {
+                continue;
+            }
+            drawn.insert(set.id.as_str());
+            let _ = writeln!(
+                dot,
+                "  {} [label={}, shape=ellipse];",
+                dot_string(&set.id),
+                dot_string(&node_label(&set.name, &set.conditions))
+            );
+        }
+        for edge in &self.hierarchy {
+            if drawn.contains(edge.from.as_str()) && drawn.contains(edge.to.as_str()) {
+                let _ = writeln!(
+                    dot,
+                    "  {} -> {} [style=dashed, color=gray];",
+                    dot_string(&edge.from),
+                    dot_string(&edge.to)
+                );
+            }
+        }
+        for edge in &self.dependencies {
+            let _ = writeln!(
+                dot,
+                "  {} -> {};",
+                dot_string(&edge.from),
+                dot_string(&edge.to)
+            );
+        }
+        for edge in &self.ambiguous_with {
+            let _ = writeln!(
+                dot,
+                "  {} -> {} [style=dotted, dir=none, constraint=false];",
+                dot_string(&edge.from),
+                dot_string(&edge.to)
+            );
+        }
+        for conflict in &self.conflicts {
+            let label = if conflict.components.is_empty() {
+                "World".to_string()
+            } else {
+                conflict.components.join("\n")
+            };
+            let _ = writeln!(
+                dot,
+                "  {} -> {} [color=red, dir=none, constraint=false, label={}];",
+                dot_string(&conflict.a),
+                dot_string(&conflict.b),
+                dot_string(&label)
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn node_label(name: &str, conditions: &[String]) -> String {
+    let mut label = name.to_string();
+    for condition in conditions {
+        let _ = write!(label, "\nif {condition}");
+    }
+    label
+}
+
+fn dot_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        prelude::{Condition, IntoSystemConfigs, IntoSystemSetConfigs, ResMut, Resource},
+        schedule::{Schedule, SystemSet},
+        world::World,
+    };
+    use alloc::{string::ToString, vec, vec::Vec};
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Physics;
+
+    #[derive(Resource, Default)]
+    struct Counter(u32);
+
+    fn a(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+    fn b(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+    fn c() {}
+    fn always() -> bool {
+        true
+    }
+
+    fn schedule(world: &mut World) -> Schedule {
+        let mut schedule = Schedule::default();
+        schedule
+            .configure_sets(Physics.run_if(always))
+            .add_systems((a, b).in_set(Physics))
+            .add_systems(c.run_if(always.and(always)).before(a));
+        schedule.initialize(world).unwrap();
+        schedule
+    }
+
+    #[test]
+    fn export_graph() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let schedule = schedule(&mut world);
+        let export = schedule.export_graph(world.components());
+
+        assert_eq!(export.label, "DefaultSchedule");
+        let names: Vec<_> = export.systems.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names.len(), 3);
+        assert!(names[0].ends_with("::a"));
+        let c = &export.systems[2];
+        assert_eq!(c.conditions.len(), 1);
+
+        let physics = export.sets.iter().find(|s| s.name == "Physics").unwrap();
+        assert_eq!(physics.conditions.len(), 1);
+        assert!(!physics.system_type && !physics.anonymous);
+        let in_physics = export
+            .hierarchy
+            .iter()
+            .filter(|edge| edge.from == physics.id)
+            .count();
+        assert_eq!(in_physics, 2);
+
+        // `a` and `b` both write `Counter` with no defined order.
+        assert_eq!(export.conflicts.len(), 1);
+        assert_eq!(
+            export.conflicts[0].components,
+            vec![core::any::type_name::<Counter>().to_string()]
+        );
+        assert_eq!(export, schedule.export_graph(world.components()));
+    }
+
+    #[test]
+    fn render_dot() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let export = schedule(&mut world).export_graph(world.components());
+
+        let dot = export.to_dot();
+        assert!(dot.starts_with("digraph \"DefaultSchedule\" {"));
+        assert!(dot.contains("color=red"));
+        assert!(dot.contains("shape=ellipse"));
+        assert!(dot.ends_with("}\n"));
+    }
+}
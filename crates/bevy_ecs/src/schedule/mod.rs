@@ -3,14 +3,18 @@
 mod condition;
 mod config;
 mod executor;
+mod graph_export;
 mod graph_utils;
+mod profiling;
 #[allow(clippy::module_inception)]
 mod schedule;
 mod set;
 mod stepping;
 
 use self::graph_utils::*;
-pub use self::{condition::*, config::*, executor::*, schedule::*, set::*};
+pub use self::{
+    condition::*, config::*, executor::*, graph_export::*, profiling::*, schedule::*, set::*,
+};
 
 pub use self::graph_utils::NodeId;
 
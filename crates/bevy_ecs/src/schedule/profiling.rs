@@ -0,0 +1,330 @@
+use alloc::borrow::Cow;
+use core::cmp::Reverse;
+
+use bevy_utils::{Duration, HashMap};
+
+use crate::{
+    self as bevy_ecs,
+    schedule::{InternedScheduleLabel, NodeId, ScheduleLabel, SystemSchedule},
+    system::Resource,
+};
+
+/// Collects per-system run times for every [`Schedule`](super::Schedule) run in the world.
+///
+/// Profiling is opt-in: schedules only time their systems while this resource exists.
+/// Insert it to start collecting data and remove it to stop.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::{ScheduleLabel, SystemProfiles};
+/// # #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
+/// # struct Update;
+/// fn physics() {}
+///
+/// let mut world = World::new();
+/// world.init_resource::<SystemProfiles>();
+///
+/// let mut schedule = Schedule::new(Update);
+/// schedule.add_systems(physics);
+/// schedule.run(&mut world);
+///
+/// let profiles = world.resource::<SystemProfiles>();
+/// let (label, slowest) = profiles.slowest_systems(10)[0];
+/// assert_eq!(label, Update.intern());
+/// assert_eq!(slowest.runs(), 1);
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct SystemProfiles {
+    schedules: HashMap<InternedScheduleLabel, ScheduleProfile>,
+}
+
+impl SystemProfiles {
+    /// Creates an empty `SystemProfiles`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the profile of the schedule with the given `label`, if it has run since profiling began.
+    pub fn get(&self, label: impl ScheduleLabel) -> Option<&ScheduleProfile> {
+        self.schedules.get(&label.intern())
+    }
+
+    /// Returns an iterator over the profiles of all schedules that have run since profiling began.
+    pub fn iter(&self) -> impl Iterator<Item = (InternedScheduleLabel, &ScheduleProfile)> {
+        self.schedules
+            .iter()
+            .map(|(label, profile)| (*label, profile))
+    }
+
+    /// Returns up to `count` systems across all schedules, ordered by descending average run time.
+    pub fn slowest_systems(&self, count: usize) -> Vec<(InternedScheduleLabel, &SystemProfile)> {
+        let mut systems = self
+            .iter()
+            .flat_map(|(label, profile)| profile.systems().map(move |(_, system)| (label, system)))
+            .collect::<Vec<_>>();
+        systems.sort_by_key(|(_, system)| Reverse(system.average_duration()));
+        systems.truncate(count);
+        systems
+    }
+
+    /// Discards all collected data.
+    pub fn clear(&mut self) {
+        self.schedules.clear();
+    }
+
+    /// Accumulates the timings of a single run of `schedule`.
+    pub(super) fn record(
+        &mut self,
+        label: InternedScheduleLabel,
+        schedule: &SystemSchedule,
+        run: ScheduleRunProfile,
+        elapsed: Duration,
+    ) {
+        let profile = self.schedules.entry(label).or_default();
+        profile.runs += 1;
+        profile.total_duration += elapsed;
+        profile.max_duration = profile.max_duration.max(elapsed);
+        profile.last_duration = elapsed;
+        profile.max_concurrent_systems = profile
+            .max_concurrent_systems
+            .max(run.max_concurrent_systems);
+        profile.last_concurrent_systems = run.max_concurrent_systems;
+        profile.last_system_duration = Duration::ZERO;
+
+        for (index, duration) in run.system_durations.into_iter().enumerate() {
+            let Some(duration) = duration else {
+                continue;
+            };
+            let system = profile
+                .systems
+                .entry(schedule.system_ids[index])
+                .or_insert_with(|| SystemProfile::new(schedule.systems[index].name()));
+            system.runs += 1;
+            system.total_duration += duration;
+            system.max_duration = system.max_duration.max(duration);
+            system.last_duration = duration;
+            profile.total_system_duration += duration;
+            profile.last_system_duration += duration;
+        }
+    }
+}
+
+/// Run time statistics of a single schedule, collected in [`SystemProfiles`].
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleProfile {
+    runs: u64,
+    total_duration: Duration,
+    max_duration: Duration,
+    last_duration: Duration,
+    total_system_duration: Duration,
+    last_system_duration: Duration,
+    max_concurrent_systems: usize,
+    last_concurrent_systems: usize,
+    systems: HashMap<NodeId, SystemProfile>,
+}
+
+impl ScheduleProfile {
+    /// Returns the number of times the schedule has run.
+    pub fn runs(&self) -> u64 {
+        self.runs
+    }
+
+    /// Returns the average wall-clock time of a schedule run.
+    pub fn average_duration(&self) -> Duration {
+        average(self.total_duration, self.runs)
+    }
+
+    /// Returns the longest wall-clock time of a schedule run.
+    pub fn max_duration(&self) -> Duration {
+        self.max_duration
+    }
+
+    /// Returns the wall-clock time of the most recent schedule run.
+    pub fn last_duration(&self) -> Duration {
+        self.last_duration
+    }
+
+    /// Returns the average number of systems running at the same time across all runs.
+    ///
+    /// This is the time spent in systems divided by the wall-clock time of the schedule,
+    /// so a value close to `1.0` means the schedule effectively ran on a single thread.
+    pub fn parallelism(&self) -> f64 {
+        parallelism(self.total_system_duration, self.total_duration)
+    }
+
+    /// Returns the average number of systems running at the same time during the most recent run.
+    pub fn last_parallelism(&self) -> f64 {
+        parallelism(self.last_system_duration, self.last_duration)
+    }
+
+    /// Returns the highest number of systems that were running at the same time.
+    pub fn max_concurrent_systems(&self) -> usize {
+        self.max_concurrent_systems
+    }
+
+    /// Returns the highest number of systems that were running at the same time during the most recent run.
+    pub fn last_concurrent_systems(&self) -> usize {
+        self.last_concurrent_systems
+    }
+
+    /// Returns the profile of the system with the given [`NodeId`].
+    pub fn system(&self, id: NodeId) -> Option<&SystemProfile> {
+        self.systems.get(&id)
+    }
+
+    /// Returns an iterator over the profiles of all systems that have run in this schedule.
+    pub fn systems(&self) -> impl Iterator<Item = (NodeId, &SystemProfile)> {
+        self.systems.iter().map(|(id, profile)| (*id, profile))
+    }
+}
+
+/// Run time statistics of a single system, collected in [`SystemProfiles`].
+#[derive(Debug, Clone)]
+pub struct SystemProfile {
+    name: Cow<'static, str>,
+    runs: u64,
+    total_duration: Duration,
+    max_duration: Duration,
+    last_duration: Duration,
+}
+
+impl SystemProfile {
+    fn new(name: Cow<'static, str>) -> Self {
+        Self {
+            name,
+            runs: 0,
+            total_duration: Duration::ZERO,
+            max_duration: Duration::ZERO,
+            last_duration: Duration::ZERO,
+        }
+    }
+
+    /// Returns the name of the system.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the number of times the system has run.
+    pub fn runs(&self) -> u64 {
+        self.runs
+    }
+
+    /// Returns the total time spent running the system.
+    pub fn total_duration(&self) -> Duration {
+        self.total_duration
+    }
+
+    /// Returns the average time of a single run of the system.
+    pub fn average_duration(&self) -> Duration {
+        average(self.total_duration, self.runs)
+    }
+
+    /// Returns the longest time of a single run of the system.
+    pub fn max_duration(&self) -> Duration {
+        self.max_duration
+    }
+
+    /// Returns the time of the most recent run of the system.
+    pub fn last_duration(&self) -> Duration {
+        self.last_duration
+    }
+}
+
+/// Timings recorded by an executor during a single run of a [`SystemSchedule`].
+#[derive(Default)]
+pub(super) struct ScheduleRunProfile {
+    /// Indexed by system index. `None` if the system did not run.
+    pub(super) system_durations: Vec<Option<Duration>>,
+    /// The highest number of systems running at the same time.
+    pub(super) max_concurrent_systems: usize,
+}
+
+impl ScheduleRunProfile {
+    pub(super) fn new(system_count: usize) -> Self {
+        Self {
+            system_durations: vec![None; system_count],
+            max_concurrent_systems: 0,
+        }
+    }
+
+    /// Records the time a system took when systems are run one at a time.
+    pub(super) fn record_system(&mut self, system_index: usize, duration: Duration) {
+        self.system_durations[system_index] = Some(duration);
+        self.max_concurrent_systems = self.max_concurrent_systems.max(1);
+    }
+}
+
+fn average(total: Duration, count: u64) -> Duration {
+    if count == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_secs_f64(total.as_secs_f64() / count as f64)
+}
+
+fn parallelism(system_duration: Duration, wall_duration: Duration) -> f64 {
+    if wall_duration.is_zero() {
+        return 0.0;
+    }
+    system_duration.as_secs_f64() / wall_duration.as_secs_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        prelude::*,
+        schedule::{ExecutorKind, ScheduleLabel, SystemProfiles},
+    };
+
+    #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
+    struct TestSchedule;
+
+    fn profile_executor(kind: ExecutorKind) {
+        fn a() {}
+        fn b() {}
+        fn skipped() {}
+
+        let mut world = World::new();
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.set_executor_kind(kind);
+        schedule.add_systems((a, b, skipped.run_if(|| false)));
+
+        // not collected without the resource
+        schedule.run(&mut world);
+        world.init_resource::<SystemProfiles>();
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+
+        let profiles = world.resource::<SystemProfiles>();
+        let profile = profiles.get(TestSchedule).unwrap();
+        assert_eq!(profile.runs(), 2);
+        assert!(profile.max_concurrent_systems() >= 1);
+
+        let mut names = profile
+            .systems()
+            .map(|(_, system)| {
+                assert_eq!(system.runs(), 2);
+                assert!(system.max_duration() >= system.average_duration());
+                system.name().rsplit("::").next().unwrap().to_string()
+            })
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, ["a", "b"]);
+        assert_eq!(profiles.slowest_systems(1).len(), 1);
+    }
+
+    #[test]
+    fn profile_single_threaded() {
+        profile_executor(ExecutorKind::SingleThreaded);
+    }
+
+    #[test]
+    fn profile_simple() {
+        profile_executor(ExecutorKind::Simple);
+    }
+
+    #[test]
+    fn profile_multi_threaded() {
+        profile_executor(ExecutorKind::MultiThreaded);
+    }
+}
@@ -6,7 +6,7 @@ use bevy_utils::tracing::info_span;
 use bevy_utils::{
     default,
     tracing::{error, info, warn},
-    HashMap, HashSet,
+    HashMap, HashSet, Instant,
 };
 use derive_more::derive::{Display, Error};
 use disqualified::ShortName;
@@ -17,7 +17,7 @@ use crate::{
     self as bevy_ecs,
     component::{ComponentId, Components, Tick},
     prelude::Component,
-    schedule::*,
+    schedule::{profiling::ScheduleRunProfile, *},
     system::{BoxedSystem, IntoSystem, Resource, System},
     world::World,
 };
@@ -263,6 +263,7 @@ pub struct Schedule {
     executable: SystemSchedule,
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
+    generation: u64,
 }
 
 #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
@@ -287,6 +288,7 @@ impl Schedule {
             executable: SystemSchedule::new(),
             executor: make_executor(ExecutorKind::default()),
             executor_initialized: false,
+            generation: 0,
         }
     }
 
@@ -382,6 +384,12 @@ impl Schedule {
         self.initialize(world)
             .unwrap_or_else(|e| panic!("Error when initializing schedule {:?}: {e}", self.label));
 
+        let profile_start = world
+            .contains_resource::<SystemProfiles>()
+            .then(Instant::now);
+        self.executable.profile =
+            profile_start.map(|_| ScheduleRunProfile::new(self.executable.systems.len()));
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
         self.executor.run(&mut self.executable, world, None);
 
@@ -395,6 +403,15 @@ impl Schedule {
             self.executor
                 .run(&mut self.executable, world, skip_systems.as_ref());
         }
+
+        if let Some(start) = profile_start {
+            let elapsed = start.elapsed();
+            let profile = self.executable.profile.take().unwrap_or_default();
+            // The resource may have been removed by one of the systems.
+            if let Some(mut profiles) = world.get_resource_mut::<SystemProfiles>() {
+                profiles.record(self.label, &self.executable, profile, elapsed);
+            }
+        }
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -416,6 +433,7 @@ impl Schedule {
             )?;
             self.graph.changed = false;
             self.executor_initialized = false;
+            self.generation += 1;
         }
 
         if !self.executor_initialized {
@@ -426,6 +444,14 @@ impl Schedule {
         Ok(())
     }
 
+    /// Returns the number of times the executable schedule was rebuilt from its graph, which happens
+    /// when the schedule is [initialized](Self::initialize) after systems or sets were added to it.
+    ///
+    /// This can be used to tell whether data derived from the graph is outdated.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
     /// Returns the [`ScheduleGraph`].
     pub fn graph(&self) -> &ScheduleGraph {
         &self.graph
@@ -727,6 +753,18 @@ impl ScheduleGraph {
         &self.conflicting_systems
     }
 
+    /// Returns the graph of systems and sets that were explicitly allowed to be ambiguous with each other,
+    /// e.g. with [`ambiguous_with`](crate::schedule::IntoSystemConfigs::ambiguous_with).
+    pub fn ambiguous_with(&self) -> &UnGraphMap<NodeId, ()> {
+        &self.ambiguous_with
+    }
+
+    /// Returns the systems and sets that were allowed to be ambiguous with every other system,
+    /// e.g. with [`ambiguous_with_all`](crate::schedule::IntoSystemConfigs::ambiguous_with_all).
+    pub fn ambiguous_with_all(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.ambiguous_with_all.iter().copied()
+    }
+
     fn process_config<T: ProcessNodeConfig>(
         &mut self,
         config: NodeConfig<T>,
@@ -1484,6 +1522,7 @@ impl ScheduleGraph {
             system_dependents,
             sets_with_conditions_of_systems,
             systems_in_sets_with_conditions,
+            profile: None,
         }
     }
 
@@ -14,7 +14,7 @@ use crate::{
 };
 use bevy_ptr::{OwningPtr, Ptr};
 use bevy_utils::{HashMap, HashSet};
-use core::{any::TypeId, marker::PhantomData, mem::MaybeUninit};
+use core::{any::TypeId, marker::PhantomData, mem::MaybeUninit, ptr::NonNull};
 use derive_more::derive::{Display, Error};
 
 use super::{unsafe_world_cell::UnsafeEntityCell, Ref, ON_REMOVE, ON_REPLACE};
@@ -1372,6 +1372,133 @@ impl<'w> EntityWorldMut<'w> {
         Some(result)
     }
 
+    /// Removes every component from the entity without dropping them, handing their values to `f`.
+    ///
+    /// `f` receives the [`ComponentId`]s of the removed components along with pointers to their values,
+    /// in the same order. It takes ownership of the values, and must move them elsewhere or drop them.
+    /// Removal hooks and observers are triggered before `f` is called, as with [`EntityWorldMut::take`].
+    ///
+    /// The entity is moved out of its archetype even if `f` panics, in which case the values it didn't
+    /// move out are leaked rather than dropped twice.
+    pub(crate) fn take_all_with(&mut self, f: impl FnOnce(&[ComponentId], Vec<OwningPtr<'_>>)) {
+        let component_ids: Vec<ComponentId> = self.archetype().components().collect();
+        if component_ids.is_empty() {
+            f(&[], Vec::new());
+            return;
+        }
+
+        let world = &mut self.world;
+        let bundle_id = world
+            .bundles
+            .init_dynamic_info(&world.components, &component_ids);
+        // SAFETY: We just ensured this bundle exists
+        let bundle_info = unsafe { world.bundles.get_unchecked(bundle_id) };
+        let old_location = self.location;
+        // SAFETY: `archetype_id` exists because it is referenced in the old `EntityLocation` which is valid,
+        // and every component of the bundle is in the archetype as it was built from it.
+        let Some(new_archetype_id) = (unsafe {
+            remove_bundle_from_archetype(
+                &mut world.archetypes,
+                &mut world.storages,
+                &world.components,
+                &world.observers,
+                old_location.archetype_id,
+                bundle_info,
+                false,
+            )
+        }) else {
+            return;
+        };
+
+        let entity = self.entity;
+        // SAFETY: Archetypes and Bundles cannot be mutably aliased through DeferredWorld
+        let (old_archetype, bundle_info, mut deferred_world) = unsafe {
+            let bundle_info: *const BundleInfo = bundle_info;
+            let world = world.as_unsafe_world_cell();
+            (
+                &world.archetypes()[old_location.archetype_id],
+                &*bundle_info,
+                world.into_deferred(),
+            )
+        };
+
+        // SAFETY: all bundle components exist in World
+        unsafe {
+            trigger_on_replace_and_on_remove_hooks_and_observers(
+                &mut deferred_world,
+                old_archetype,
+                entity,
+                bundle_info,
+            );
+        }
+
+        let storages = &mut world.storages;
+        let components = &world.components;
+        let removed_components = &mut world.removed_components;
+        let mut values = Vec::with_capacity(component_ids.len());
+        for &component_id in &component_ids {
+            // SAFETY:
+            // - entity location is valid
+            // - table row is removed below, without dropping the contents
+            // - `components` comes from the same world as `storages`
+            let value = unsafe {
+                take_component(
+                    storages,
+                    components,
+                    removed_components,
+                    component_id,
+                    entity,
+                    old_location,
+                )
+            };
+            // SAFETY: Each component is taken from a different column or sparse set, which aren't
+            // modified again until the table row is removed below, after `f` has moved the values out.
+            values.push(unsafe { OwningPtr::new(NonNull::from(value)) });
+        }
+
+        /// Moves the entity out of its archetype once `f` returns or unwinds.
+        struct MoveOnDrop<'a> {
+            entity: Entity,
+            location: &'a mut EntityLocation,
+            old_location: EntityLocation,
+            entities: &'a mut Entities,
+            archetypes: &'a mut Archetypes,
+            storages: &'a mut Storages,
+            new_archetype_id: ArchetypeId,
+        }
+
+        impl Drop for MoveOnDrop<'_> {
+            fn drop(&mut self) {
+                // SAFETY: `new_archetype_id` is a subset of the components of the old archetype,
+                // and the removed values were moved out by `f` (or are leaked if it panicked),
+                // so they must be forgotten.
+                unsafe {
+                    EntityWorldMut::move_entity_from_remove::<false>(
+                        self.entity,
+                        self.location,
+                        self.old_location.archetype_id,
+                        self.old_location,
+                        self.entities,
+                        self.archetypes,
+                        self.storages,
+                        self.new_archetype_id,
+                    );
+                }
+            }
+        }
+
+        let _move_on_drop = MoveOnDrop {
+            entity,
+            location: &mut self.location,
+            old_location,
+            entities: &mut world.entities,
+            archetypes: &mut world.archetypes,
+            storages: &mut world.storages,
+            new_archetype_id,
+        };
+        f(&component_ids, values);
+    }
+
     /// # Safety
     ///
     /// `new_archetype_id` must have the same or a subset of the components
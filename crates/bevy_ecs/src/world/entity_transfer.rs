@@ -0,0 +1,472 @@
+use alloc::vec::Vec;
+use core::any::TypeId;
+
+use bevy_utils::{HashMap, HashSet};
+
+use crate::{
+    component::ComponentId,
+    entity::{Entity, EntityHashMap, EntityHashSet},
+    world::{error::TransferEntityError, World},
+};
+
+#[cfg(feature = "bevy_reflect")]
+use crate::{
+    entity::EntityMapper,
+    reflect::{AppTypeRegistry, ReflectMapEntities},
+};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{ReflectFromPtr, TypeRegistry};
+
+impl World {
+    /// Moves `entity` and all of its components into `destination`, returning the id of the entity in `destination`.
+    ///
+    /// See [`World::move_entities`] for details.
+    pub fn transfer_entity(
+        &mut self,
+        entity: Entity,
+        destination: &mut World,
+    ) -> Result<Entity, TransferEntityError> {
+        let entity_map = self.move_entities(&[entity], destination)?;
+        Ok(entity_map[&entity])
+    }
+
+    /// Moves the given entities and all of their components into `destination`,
+    /// returning a map from their ids in this world to their ids in `destination`.
+    ///
+    /// Component values are moved as-is rather than cloned, so this works for components that don't
+    /// implement [`Clone`] or [`Reflect`](bevy_reflect::Reflect). The entities are despawned from this world,
+    /// triggering the usual removal hooks and observers, and inserted into `destination` as freshly added components.
+    ///
+    /// Entity references inside components registered with [`ReflectMapEntities`](crate::reflect::ReflectMapEntities)
+    /// and [`ReflectFromPtr`](bevy_reflect::ReflectFromPtr) in the [`AppTypeRegistry`](crate::reflect::AppTypeRegistry)
+    /// of `destination` (or of this world, if `destination` has none) are remapped, so that references between moved
+    /// entities stay valid. References to entities that weren't moved are left unchanged.
+    ///
+    /// Component types that aren't yet known to `destination` are registered with the same storage type, hooks and
+    /// required components, as declared on the component types.
+    ///
+    /// Nothing is moved if any of the entities doesn't exist, or has a component that doesn't correspond to a Rust type.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component, PartialEq, Debug)]
+    /// struct Loaded(String);
+    ///
+    /// let mut loading_world = World::new();
+    /// let entity = loading_world.spawn(Loaded("level".into())).id();
+    ///
+    /// let mut world = World::new();
+    /// let moved = loading_world.transfer_entity(entity, &mut world).unwrap();
+    /// assert!(loading_world.get_entity(entity).is_err());
+    /// assert_eq!(world.get::<Loaded>(moved), Some(&Loaded("level".into())));
+    /// ```
+    pub fn move_entities(
+        &mut self,
+        entities: &[Entity],
+        destination: &mut World,
+    ) -> Result<EntityHashMap<Entity>, TransferEntityError> {
+        let mut entity_map = EntityHashMap::default();
+        self.move_entities_with_map(entities, destination, &mut entity_map)?;
+        Ok(entity_map)
+    }
+
+    /// Moves the given entities and all of their components into `destination`, recording their new ids in `entity_map`.
+    ///
+    /// Entities that are already mapped to an existing entity of `destination` are moved into that entity,
+    /// overwriting components of the same type. Other entries of `entity_map` are used when remapping references
+    /// to entities that aren't moved.
+    ///
+    /// See [`World::move_entities`] for details.
+    pub fn move_entities_with_map(
+        &mut self,
+        entities: &[Entity],
+        destination: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), TransferEntityError> {
+        self.flush();
+        destination.flush();
+
+        // Validate every entity and component before touching `destination`, so that nothing changes on failure.
+        let mut moved = EntityHashSet::default();
+        let mut component_ids = HashSet::default();
+        for &entity in entities {
+            let entity_ref = self
+                .get_entity(entity)
+                .map_err(|_| TransferEntityError::NoSuchEntity(entity))?;
+            moved.insert(entity);
+            for component_id in entity_ref.archetype().components() {
+                if component_ids.insert(component_id)
+                    && self
+                        .components
+                        .get_info(component_id)
+                        .unwrap()
+                        .type_id()
+                        .is_none()
+                {
+                    return Err(TransferEntityError::DynamicComponent(component_id));
+                }
+            }
+        }
+
+        let mut component_map: HashMap<ComponentId, (ComponentId, TypeId)> = HashMap::default();
+        for component_id in component_ids {
+            let info = self.components.get_info(component_id).unwrap();
+            let destination_id = destination
+                .components
+                .register_component_from_info(&mut destination.storages, info)
+                .expect("component was checked to correspond to a Rust type");
+            component_map.insert(component_id, (destination_id, info.type_id().unwrap()));
+        }
+
+        for &entity in &moved {
+            let target = entity_map.get(&entity).copied();
+            if !target.is_some_and(|target| destination.get_entity(target).is_ok()) {
+                entity_map.insert(entity, destination.spawn_empty().id());
+            }
+        }
+
+        #[cfg(feature = "bevy_reflect")]
+        let registry = destination
+            .get_resource::<AppTypeRegistry>()
+            .or_else(|| self.get_resource::<AppTypeRegistry>())
+            .cloned();
+
+        // The emptied entities are only despawned once every entity has been moved, as despawning flushes
+        // commands queued by removal hooks, which could otherwise tear down relationships between them.
+        let mut emptied = Vec::with_capacity(moved.len());
+        for &entity in entities {
+            if !moved.remove(&entity) {
+                continue;
+            }
+            emptied.push(entity);
+            let mut source = self.entity_mut(entity);
+            source.take_all_with(|component_ids, values| {
+                let (destination_ids, type_ids): (Vec<ComponentId>, Vec<TypeId>) =
+                    component_ids.iter().map(|id| component_map[id]).unzip();
+
+                #[cfg(feature = "bevy_reflect")]
+                let values = {
+                    let mut values = values;
+                    if let Some(registry) = &registry {
+                        let registry = registry.read();
+                        let mut mapper = TransferEntityMapper(entity_map);
+                        for (value, type_id) in values.iter_mut().zip(type_ids) {
+                            // SAFETY: `value` points to a component of type `type_id`.
+                            unsafe {
+                                map_entities_in_place(
+                                    &registry,
+                                    type_id,
+                                    value.as_mut(),
+                                    &mut mapper,
+                                );
+                            }
+                        }
+                    }
+                    values
+                };
+                #[cfg(not(feature = "bevy_reflect"))]
+                let _ = type_ids;
+
+                if destination_ids.is_empty() {
+                    return;
+                }
+                let mut target = destination.entity_mut(entity_map[&entity]);
+                // SAFETY:
+                // - every id in `destination_ids` was registered in `destination`
+                // - each value has the same type as the component it is inserted as,
+                //   since the ids were matched by `TypeId`
+                unsafe {
+                    target.insert_by_ids(&destination_ids, values.into_iter());
+                }
+            });
+        }
+        for entity in emptied {
+            self.despawn(entity);
+        }
+
+        self.flush();
+        destination.flush();
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bevy_reflect")]
+struct TransferEntityMapper<'m>(&'m EntityHashMap<Entity>);
+
+#[cfg(feature = "bevy_reflect")]
+impl EntityMapper for TransferEntityMapper<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+/// # Safety
+///
+/// `value` must point to a valid value of the type identified by `type_id`.
+#[cfg(feature = "bevy_reflect")]
+unsafe fn map_entities_in_place(
+    registry: &TypeRegistry,
+    type_id: TypeId,
+    value: bevy_ptr::PtrMut<'_>,
+    mapper: &mut dyn EntityMapper,
+) {
+    let (Some(from_ptr), Some(map_entities)) = (
+        registry.get_type_data::<ReflectFromPtr>(type_id),
+        registry.get_type_data::<ReflectMapEntities>(type_id),
+    ) else {
+        return;
+    };
+    // SAFETY: `ReflectFromPtr` was registered for `type_id`, which is the type of `value`.
+    let reflected = unsafe { from_ptr.as_reflect_mut(value) };
+    map_entities.map_entities(reflected.as_partial_reflect_mut(), mapper);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        component::Component,
+        entity::Entity,
+        world::{error::TransferEntityError, World},
+    };
+    use alloc::{string::String, vec, vec::Vec};
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Name(String);
+
+    #[derive(Component, Debug, PartialEq)]
+    #[component(storage = "SparseSet")]
+    struct Marker;
+
+    #[test]
+    fn transfer_moves_non_clone_components() {
+        let mut source = World::new();
+        let mut destination = World::new();
+        // Occupy some ids so that the entity gets a different id in `destination`.
+        destination.spawn_empty();
+        destination.spawn_empty();
+
+        let entity = source.spawn((Name("a".into()), Marker)).id();
+        let other = source.spawn(Name("b".into())).id();
+        let moved = source.transfer_entity(entity, &mut destination).unwrap();
+
+        assert!(source.get_entity(entity).is_err());
+        assert_eq!(source.get::<Name>(other), Some(&Name("b".into())));
+        assert_eq!(destination.get::<Name>(moved), Some(&Name("a".into())));
+        assert_eq!(destination.get::<Marker>(moved), Some(&Marker));
+        assert_eq!(destination.query::<&Name>().iter(&destination).count(), 1);
+    }
+
+    #[test]
+    fn transfer_missing_entity_fails() {
+        let mut source = World::new();
+        let mut destination = World::new();
+        let entity = source.spawn(Name("a".into())).id();
+        let missing = source.spawn_empty().id();
+        source.despawn(missing);
+
+        assert!(matches!(
+            source.move_entities(&[entity, missing], &mut destination),
+            Err(TransferEntityError::NoSuchEntity(e)) if e == missing
+        ));
+        assert!(source.get_entity(entity).is_ok());
+        assert_eq!(destination.entities().len(), 0);
+    }
+
+    #[test]
+    fn transfer_registers_required_components() {
+        #[derive(Component, Default, Debug, PartialEq)]
+        struct Health(u32);
+
+        #[derive(Component)]
+        #[require(Health)]
+        struct Player;
+
+        let mut source = World::new();
+        let mut destination = World::new();
+        let entity = source.spawn(Player).id();
+        source.entity_mut(entity).remove::<Health>();
+        source.transfer_entity(entity, &mut destination).unwrap();
+
+        let spawned = destination.spawn(Player).id();
+        assert_eq!(destination.get::<Health>(spawned), Some(&Health(0)));
+    }
+
+    #[test]
+    fn failed_transfer_leaves_destination_untouched() {
+        let mut source = World::new();
+        let mut destination = World::new();
+        let entity = source.spawn(Name("a".into())).id();
+        let missing = source.spawn_empty().id();
+        source.despawn(missing);
+        let components = destination.components().len();
+
+        assert!(source
+            .move_entities(&[entity, missing], &mut destination)
+            .is_err());
+        assert_eq!(destination.components().len(), components);
+    }
+
+    #[test]
+    fn transfer_is_panic_safe() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Counted;
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        #[derive(Component)]
+        struct Dropped(#[allow(dead_code)] Counted);
+
+        #[derive(Component)]
+        #[component(on_add = panic_on_add)]
+        struct Panicking;
+
+        #[derive(crate::system::Resource)]
+        struct Armed;
+
+        fn panic_on_add(
+            world: crate::world::DeferredWorld,
+            _: Entity,
+            _: crate::component::ComponentId,
+        ) {
+            if world.contains_resource::<Armed>() {
+                panic!("on_add");
+            }
+        }
+
+        let mut source = World::new();
+        let mut destination = World::new();
+        // Inserting the moved values into `destination` panics.
+        destination.insert_resource(Armed);
+        let entity = source.spawn((Dropped(Counted), Panicking)).id();
+        let other = source.spawn(Dropped(Counted)).id();
+
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            let _ = source.transfer_entity(entity, &mut destination);
+        }));
+        assert!(result.is_err());
+
+        // The source world is left in a consistent state, and no value is dropped twice.
+        assert!(source.get::<Dropped>(other).is_some());
+        drop(source);
+        drop(destination);
+        assert_eq!(DROPS.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn transfer_runs_hooks_in_both_worlds() {
+        #[derive(Component)]
+        #[component(on_add = count_add, on_remove = count_remove)]
+        struct Tracked;
+
+        #[derive(crate::system::Resource, Default)]
+        struct Count(i32);
+
+        fn count_add(
+            mut world: crate::world::DeferredWorld,
+            _: Entity,
+            _: crate::component::ComponentId,
+        ) {
+            if let Some(mut count) = world.get_resource_mut::<Count>() {
+                count.0 += 1;
+            }
+        }
+
+        fn count_remove(
+            mut world: crate::world::DeferredWorld,
+            _: Entity,
+            _: crate::component::ComponentId,
+        ) {
+            if let Some(mut count) = world.get_resource_mut::<Count>() {
+                count.0 -= 1;
+            }
+        }
+
+        let mut source = World::new();
+        let mut destination = World::new();
+        source.init_resource::<Count>();
+        destination.init_resource::<Count>();
+        let entity = source.spawn(Tracked).id();
+        assert_eq!(source.resource::<Count>().0, 1);
+
+        source.transfer_entity(entity, &mut destination).unwrap();
+        assert_eq!(source.resource::<Count>().0, 0);
+        assert_eq!(destination.resource::<Count>().0, 1);
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn move_entities_remaps_references() {
+        use crate::{
+            entity::{VisitEntities, VisitEntitiesMut},
+            reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
+        };
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect, VisitEntities, VisitEntitiesMut)]
+        #[reflect(Component, MapEntities)]
+        struct Target(Entity);
+
+        #[derive(Component, Reflect, VisitEntities, VisitEntitiesMut)]
+        #[reflect(Component, MapEntities)]
+        #[relationship(relationship_target = Followers)]
+        struct Following(Entity);
+
+        #[derive(Component, Reflect, VisitEntities, VisitEntitiesMut)]
+        #[reflect(Component, MapEntities)]
+        #[relationship_target(relationship = Following)]
+        struct Followers(Vec<Entity>);
+
+        for leader_first in [false, true] {
+            let mut source = World::new();
+            let mut destination = World::new();
+            destination.init_resource::<AppTypeRegistry>();
+            {
+                let mut registry = destination.resource::<AppTypeRegistry>().write();
+                registry.register::<Target>();
+                registry.register::<Following>();
+                registry.register::<Followers>();
+            }
+            // Occupy some ids so that moved entities get different ids in `destination`.
+            for _ in 0..4 {
+                destination.spawn_empty();
+            }
+
+            let leader = source.spawn_empty().id();
+            let follower = source.spawn((Target(leader), Following(leader))).id();
+            let outsider = source.spawn_empty().id();
+            let watcher = source.spawn(Target(outsider)).id();
+            source.flush();
+
+            let order = if leader_first {
+                [leader, follower, watcher]
+            } else {
+                [follower, leader, watcher]
+            };
+            let entity_map = source.move_entities(&order, &mut destination).unwrap();
+            let (leader, follower, watcher) = (
+                entity_map[&leader],
+                entity_map[&follower],
+                entity_map[&watcher],
+            );
+
+            assert_eq!(destination.get::<Target>(follower).unwrap().0, leader);
+            assert_eq!(destination.get::<Following>(follower).unwrap().0, leader);
+            assert_eq!(
+                destination.get::<Followers>(leader).unwrap().0,
+                vec![follower]
+            );
+            // References to entities that weren't moved are left unchanged.
+            assert_eq!(destination.get::<Target>(watcher).unwrap().0, outsider);
+            assert!(source.get_entity(outsider).is_ok());
+            assert_eq!(source.entities().len(), 1);
+        }
+    }
+}
@@ -37,3 +37,18 @@ pub enum EntityFetchError {
     #[error(ignore)]
     AliasedMutability(Entity),
 }
+
+/// An error that occurs when moving entities between worlds with [`World::move_entities`].
+///
+/// [`World::move_entities`]: crate::world::World::move_entities
+#[derive(Error, Display, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferEntityError {
+    /// The entity with the given ID does not exist.
+    #[display("The entity with ID {_0:?} does not exist.")]
+    #[error(ignore)]
+    NoSuchEntity(Entity),
+    /// The component with the given [`ComponentId`] does not correspond to a Rust type, so it can't be matched to a component of the destination world.
+    #[display("The component with ID {_0:?} does not correspond to a Rust type and cannot be moved to another world.")]
+    #[error(ignore)]
+    DynamicComponent(ComponentId),
+}
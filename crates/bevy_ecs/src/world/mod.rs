@@ -5,6 +5,7 @@ mod component_constants;
 mod deferred_world;
 mod entity_fetch;
 mod entity_ref;
+mod entity_transfer;
 pub mod error;
 mod filtered_resource;
 mod identifier;
@@ -13,6 +14,8 @@ pub mod unsafe_world_cell;
 
 #[cfg(feature = "bevy_reflect")]
 pub mod reflect;
+#[cfg(feature = "bevy_reflect")]
+pub mod snapshot;
 
 pub use crate::{
     change_detection::{Mut, Ref, CHECK_TICK_THRESHOLD},
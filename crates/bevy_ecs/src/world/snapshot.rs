@@ -0,0 +1,964 @@
+//! Snapshots of the reflectable state of a [`World`], which can be restored in place.
+//!
+//! This is the building block for rollback networking and "undo" in editor tools:
+//! take a [`WorldSnapshot`] before simulating, and [restore](WorldSnapshot::restore) it to resimulate
+//! from the same state. Entity ids and component change ticks are preserved by the round trip.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::any::{Any, TypeId};
+
+use bevy_reflect::{PartialReflect, ReflectFromReflect};
+use bevy_utils::{HashSet, TypeIdMap};
+use derive_more::derive::{Display, Error};
+
+use crate::{
+    component::{Component, ComponentInfo, ComponentTicks},
+    entity::{AllocAtWithoutReplacement, Entity, EntityHashSet},
+    observer::ObserverState,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    system::{Resource, SystemIdMarker},
+    world::{EntityRef, EntityWorldMut, World},
+};
+
+/// Selects which component or resource types are captured by a [`WorldSnapshot`].
+///
+/// Only types registered in the [`AppTypeRegistry`] with [`ReflectComponent`] (for components)
+/// or [`ReflectResource`] (for resources) can be captured, regardless of the filter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SnapshotFilter {
+    /// Every reflectable type is captured.
+    #[default]
+    All,
+    /// Only the listed types are captured.
+    Allow(HashSet<TypeId>),
+    /// Every reflectable type except the listed ones is captured.
+    Deny(HashSet<TypeId>),
+}
+
+impl SnapshotFilter {
+    /// Allows the type with the given [`TypeId`].
+    ///
+    /// If the filter was [`SnapshotFilter::All`], it becomes an allowlist containing only this type.
+    pub fn allow_by_id(&mut self, type_id: TypeId) -> &mut Self {
+        match self {
+            Self::All => *self = Self::Allow([type_id].into_iter().collect()),
+            Self::Allow(list) => {
+                list.insert(type_id);
+            }
+            Self::Deny(list) => {
+                list.remove(&type_id);
+            }
+        }
+        self
+    }
+
+    /// Denies the type with the given [`TypeId`].
+    ///
+    /// If the filter was [`SnapshotFilter::All`], it becomes a denylist containing only this type.
+    pub fn deny_by_id(&mut self, type_id: TypeId) -> &mut Self {
+        match self {
+            Self::All => *self = Self::Deny([type_id].into_iter().collect()),
+            Self::Allow(list) => {
+                list.remove(&type_id);
+            }
+            Self::Deny(list) => {
+                list.insert(type_id);
+            }
+        }
+        self
+    }
+
+    /// Returns `true` if the type with the given [`TypeId`] passes the filter.
+    pub fn is_allowed_by_id(&self, type_id: TypeId) -> bool {
+        match self {
+            Self::All => true,
+            Self::Allow(list) => list.contains(&type_id),
+            Self::Deny(list) => !list.contains(&type_id),
+        }
+    }
+}
+
+/// A captured component or resource value, along with its change ticks.
+pub struct SnapshotValue {
+    /// The [`TypeId`] of the component or resource.
+    pub type_id: TypeId,
+    /// A reflected clone of the value.
+    pub value: Box<dyn PartialReflect>,
+    /// The change ticks of the value at the time of the snapshot.
+    pub ticks: ComponentTicks,
+}
+
+/// A component or resource value captured with [`Clone`] rather than reflection.
+///
+/// See [`WorldSnapshotBuilder::clone_component`] and [`WorldSnapshotBuilder::clone_resource`].
+pub struct ClonedValue {
+    /// The [`TypeId`] of the component or resource.
+    pub type_id: TypeId,
+    /// A clone of the value, which can be downcast to the component or resource type.
+    pub value: Box<dyn Any + Send + Sync>,
+    /// The change ticks of the value at the time of the snapshot.
+    pub ticks: ComponentTicks,
+}
+
+/// The captured components of a single entity.
+pub struct EntitySnapshot {
+    /// The id of the entity, which is preserved when restoring.
+    pub entity: Entity,
+    /// The components captured through reflection, ordered by [`TypeId`].
+    pub components: Vec<SnapshotValue>,
+    /// The components captured with [`Clone`], ordered by [`TypeId`].
+    pub cloned_components: Vec<ClonedValue>,
+}
+
+/// Captures, restores and removes a component type captured with [`Clone`].
+#[derive(Clone, Copy)]
+struct CloneComponentFns {
+    capture: fn(EntityRef) -> Option<Box<dyn Any + Send + Sync>>,
+    restore: fn(&mut EntityWorldMut, &dyn Any),
+    remove: fn(&mut EntityWorldMut),
+}
+
+impl CloneComponentFns {
+    fn of<T: Component + Clone>() -> Self {
+        Self {
+            capture: |entity| entity.get::<T>().map(|value| Box::new(value.clone()) as _),
+            restore: |entity, value| {
+                let Some(value) = value.downcast_ref::<T>() else {
+                    return;
+                };
+                match entity.get_mut::<T>() {
+                    Some(mut current) => *current = value.clone(),
+                    None => {
+                        entity.insert(value.clone());
+                    }
+                }
+            },
+            remove: |entity| {
+                entity.remove::<T>();
+            },
+        }
+    }
+}
+
+/// Captures, restores and removes a resource type captured with [`Clone`].
+#[derive(Clone, Copy)]
+struct CloneResourceFns {
+    capture: fn(&World) -> Option<Box<dyn Any + Send + Sync>>,
+    restore: fn(&mut World, &dyn Any),
+    remove: fn(&mut World),
+}
+
+impl CloneResourceFns {
+    fn of<R: Resource + Clone>() -> Self {
+        Self {
+            capture: |world| {
+                world
+                    .get_resource::<R>()
+                    .map(|value| Box::new(value.clone()) as _)
+            },
+            restore: |world, value| {
+                let Some(value) = value.downcast_ref::<R>() else {
+                    return;
+                };
+                match world.get_resource_mut::<R>() {
+                    Some(mut current) => *current = value.clone(),
+                    None => world.insert_resource(value.clone()),
+                }
+            },
+            remove: |world| {
+                world.remove_resource::<R>();
+            },
+        }
+    }
+}
+
+/// A snapshot of (a filtered subset of) the components and resources of a [`World`].
+///
+/// Values are captured through reflection, so component and resource types must be registered
+/// in the world's [`AppTypeRegistry`] with `#[reflect(Component)]` or `#[reflect(Resource)]`,
+/// unless they are captured with [`Clone`] through [`WorldSnapshotBuilder::clone_component`] or
+/// [`WorldSnapshotBuilder::clone_resource`].
+/// Types that aren't captured, or are excluded by the [`SnapshotFilter`]s, are left untouched
+/// by [`WorldSnapshot::restore`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::snapshot::WorldSnapshot;
+/// # use bevy_reflect::Reflect;
+/// #[derive(Component, Reflect, PartialEq, Debug)]
+/// #[reflect(Component)]
+/// struct Position(f32);
+///
+/// let mut world = World::new();
+/// world.init_resource::<AppTypeRegistry>();
+/// world.resource::<AppTypeRegistry>().write().register::<Position>();
+///
+/// let entity = world.spawn(Position(0.0)).id();
+/// let snapshot = WorldSnapshot::from_world(&world);
+///
+/// world.entity_mut(entity).insert(Position(10.0));
+/// let spawned = world.spawn(Position(5.0)).id();
+///
+/// snapshot.restore(&mut world).unwrap();
+/// assert_eq!(world.get::<Position>(entity), Some(&Position(0.0)));
+/// assert!(world.get_entity(spawned).is_err());
+/// ```
+pub struct WorldSnapshot {
+    entities: Vec<EntitySnapshot>,
+    resources: Vec<SnapshotValue>,
+    cloned_resources: Vec<ClonedValue>,
+    component_filter: SnapshotFilter,
+    resource_filter: SnapshotFilter,
+    clone_component_fns: TypeIdMap<CloneComponentFns>,
+    clone_resource_fns: TypeIdMap<CloneResourceFns>,
+    all_entities: bool,
+}
+
+/// Builds a [`WorldSnapshot`] with custom filters.
+///
+/// By default every entity and every reflectable component and resource is captured.
+pub struct WorldSnapshotBuilder<'w> {
+    world: &'w World,
+    component_filter: SnapshotFilter,
+    resource_filter: SnapshotFilter,
+    clone_component_fns: TypeIdMap<CloneComponentFns>,
+    clone_resource_fns: TypeIdMap<CloneResourceFns>,
+    entities: Option<Vec<Entity>>,
+}
+
+impl<'w> WorldSnapshotBuilder<'w> {
+    /// Creates a new builder capturing everything from `world`.
+    pub fn new(world: &'w World) -> Self {
+        Self {
+            world,
+            component_filter: SnapshotFilter::All,
+            resource_filter: SnapshotFilter::All,
+            clone_component_fns: TypeIdMap::default(),
+            clone_resource_fns: TypeIdMap::default(),
+            entities: None,
+        }
+    }
+
+    /// Only captures the given entities.
+    ///
+    /// When restoring, entities that aren't part of the snapshot are left untouched.
+    /// Otherwise, entities spawned after the snapshot are despawned, except for internal entities
+    /// such as [observers](crate::observer::Observer) and [registered systems](World::register_system).
+    pub fn with_entities(mut self, entities: impl IntoIterator<Item = Entity>) -> Self {
+        self.entities.get_or_insert_with(Vec::new).extend(entities);
+        self
+    }
+
+    /// Replaces the component filter.
+    pub fn with_component_filter(mut self, filter: SnapshotFilter) -> Self {
+        self.component_filter = filter;
+        self
+    }
+
+    /// Replaces the resource filter.
+    pub fn with_resource_filter(mut self, filter: SnapshotFilter) -> Self {
+        self.resource_filter = filter;
+        self
+    }
+
+    /// Allows the component `T` to be captured.
+    pub fn allow_component<T: Component>(mut self) -> Self {
+        self.component_filter.allow_by_id(TypeId::of::<T>());
+        self
+    }
+
+    /// Prevents the component `T` from being captured.
+    pub fn deny_component<T: Component>(mut self) -> Self {
+        self.component_filter.deny_by_id(TypeId::of::<T>());
+        self
+    }
+
+    /// Allows the resource `R` to be captured.
+    pub fn allow_resource<R: Resource>(mut self) -> Self {
+        self.resource_filter.allow_by_id(TypeId::of::<R>());
+        self
+    }
+
+    /// Prevents the resource `R` from being captured.
+    pub fn deny_resource<R: Resource>(mut self) -> Self {
+        self.resource_filter.deny_by_id(TypeId::of::<R>());
+        self
+    }
+
+    /// Captures the component `T` with [`Clone`] rather than reflection.
+    ///
+    /// `T` doesn't need to be registered in the [`AppTypeRegistry`], and is captured regardless
+    /// of the component filter.
+    pub fn clone_component<T: Component + Clone>(mut self) -> Self {
+        self.clone_component_fns
+            .insert(TypeId::of::<T>(), CloneComponentFns::of::<T>());
+        self
+    }
+
+    /// Captures the resource `R` with [`Clone`] rather than reflection.
+    ///
+    /// `R` doesn't need to be registered in the [`AppTypeRegistry`], and is captured regardless
+    /// of the resource filter.
+    pub fn clone_resource<R: Resource + Clone>(mut self) -> Self {
+        self.clone_resource_fns
+            .insert(TypeId::of::<R>(), CloneResourceFns::of::<R>());
+        self
+    }
+
+    /// Captures the snapshot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the world doesn't contain an [`AppTypeRegistry`] resource.
+    pub fn build(self) -> WorldSnapshot {
+        let registry = self.world.resource::<AppTypeRegistry>().read();
+
+        let entity_ids: Vec<Entity> = match &self.entities {
+            Some(entities) => entities.clone(),
+            None => self
+                .world
+                .iter_entities()
+                .filter(|entity| !is_internal(*entity))
+                .map(|entity| entity.id())
+                .collect(),
+        };
+        let mut entities = Vec::with_capacity(entity_ids.len());
+        for entity in entity_ids {
+            let Ok(entity_ref) = self.world.get_entity(entity) else {
+                continue;
+            };
+            let mut components = Vec::new();
+            let mut cloned_components = Vec::new();
+            for component_id in entity_ref.archetype().components() {
+                let (Some(type_id), Some(ticks)) = (
+                    self.world
+                        .components()
+                        .get_info(component_id)
+                        .and_then(ComponentInfo::type_id),
+                    entity_ref.get_change_ticks_by_id(component_id),
+                ) else {
+                    continue;
+                };
+                if let Some(fns) = self.clone_component_fns.get(&type_id) {
+                    if let Some(value) = (fns.capture)(entity_ref) {
+                        cloned_components.push(ClonedValue {
+                            type_id,
+                            value,
+                            ticks,
+                        });
+                    }
+                    continue;
+                }
+                if !self.component_filter.is_allowed_by_id(type_id) {
+                    continue;
+                }
+                let Some(value) = registry
+                    .get_type_data::<ReflectComponent>(type_id)
+                    .and_then(|reflect_component| reflect_component.reflect(entity_ref))
+                else {
+                    continue;
+                };
+                components.push(SnapshotValue {
+                    type_id,
+                    value: value.clone_value(),
+                    ticks,
+                });
+            }
+            components.sort_by_key(|component| component.type_id);
+            cloned_components.sort_by_key(|component| component.type_id);
+            entities.push(EntitySnapshot {
+                entity,
+                components,
+                cloned_components,
+            });
+        }
+        entities.sort_by_key(|entity| entity.entity);
+
+        let mut resources = Vec::new();
+        let mut cloned_resources = Vec::new();
+        for (info, _) in self.world.iter_resources() {
+            let (Some(type_id), Some(ticks)) = (
+                info.type_id(),
+                self.world.get_resource_change_ticks_by_id(info.id()),
+            ) else {
+                continue;
+            };
+            if let Some(fns) = self.clone_resource_fns.get(&type_id) {
+                if let Some(value) = (fns.capture)(self.world) {
+                    cloned_resources.push(ClonedValue {
+                        type_id,
+                        value,
+                        ticks,
+                    });
+                }
+                continue;
+            }
+            if !self.resource_filter.is_allowed_by_id(type_id) {
+                continue;
+            }
+            let Some(value) = registry
+                .get_type_data::<ReflectResource>(type_id)
+                .and_then(|reflect_resource| reflect_resource.reflect(self.world))
+            else {
+                continue;
+            };
+            resources.push(SnapshotValue {
+                type_id,
+                value: value.clone_value(),
+                ticks,
+            });
+        }
+        resources.sort_by_key(|resource| resource.type_id);
+        cloned_resources.sort_by_key(|resource| resource.type_id);
+
+        WorldSnapshot {
+            entities,
+            resources,
+            cloned_resources,
+            component_filter: self.component_filter,
+            resource_filter: self.resource_filter,
+            clone_component_fns: self.clone_component_fns,
+            clone_resource_fns: self.clone_resource_fns,
+            all_entities: self.entities.is_none(),
+        }
+    }
+}
+
+impl WorldSnapshot {
+    /// Creates a [`WorldSnapshotBuilder`] for `world`.
+    pub fn builder(world: &World) -> WorldSnapshotBuilder<'_> {
+        WorldSnapshotBuilder::new(world)
+    }
+
+    /// Captures every entity and every reflectable component and resource of `world`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the world doesn't contain an [`AppTypeRegistry`] resource.
+    pub fn from_world(world: &World) -> Self {
+        WorldSnapshotBuilder::new(world).build()
+    }
+
+    /// Returns the captured entities, ordered by [`Entity`].
+    pub fn entities(&self) -> &[EntitySnapshot] {
+        &self.entities
+    }
+
+    /// Returns the resources captured through reflection, ordered by [`TypeId`].
+    pub fn resources(&self) -> &[SnapshotValue] {
+        &self.resources
+    }
+
+    /// Returns the resources captured with [`Clone`], ordered by [`TypeId`].
+    pub fn cloned_resources(&self) -> &[ClonedValue] {
+        &self.cloned_resources
+    }
+
+    /// Returns `true` if both snapshots captured the same entities, with equal component and resource values.
+    ///
+    /// Values are compared with [`PartialReflect::reflect_partial_eq`], and values that don't support the comparison
+    /// are considered unequal. Change ticks are not compared, so snapshots of two worlds that ran the same
+    /// simulation independently compare equal. Values captured with [`Clone`] are not compared.
+    pub fn reflect_eq(&self, other: &WorldSnapshot) -> bool {
+        fn values_eq(a: &[SnapshotValue], b: &[SnapshotValue]) -> bool {
+            a.len() == b.len()
+                && a.iter().zip(b).all(|(a, b)| {
+                    a.type_id == b.type_id && a.value.reflect_partial_eq(&*b.value).unwrap_or(false)
+                })
+        }
+
+        self.entities.len() == other.entities.len()
+            && self
+                .entities
+                .iter()
+                .zip(&other.entities)
+                .all(|(a, b)| a.entity == b.entity && values_eq(&a.components, &b.components))
+            && values_eq(&self.resources, &other.resources)
+    }
+
+    /// Restores the captured state into `world`.
+    ///
+    /// - Captured entities are respawned with their original id if they were despawned.
+    ///   If the snapshot captured every entity, entities spawned since are despawned, except for
+    ///   internal entities such as [observers](crate::observer::Observer) and
+    ///   [registered systems](World::register_system).
+    /// - Captured components and resources are written back along with their change ticks,
+    ///   replacing their current value. Components and resources that pass the snapshot's
+    ///   filters but didn't exist when it was taken are removed.
+    /// - Everything else is left untouched.
+    ///
+    /// The snapshot is checked against `world` before anything is written, so the world is left
+    /// untouched if an error is returned.
+    ///
+    /// The world's own change tick is not rewound, so that systems keep observing monotonic ticks.
+    pub fn restore(&self, world: &mut World) -> Result<(), RestoreSnapshotError> {
+        let registry = world
+            .get_resource::<AppTypeRegistry>()
+            .ok_or(RestoreSnapshotError::MissingAppTypeRegistry)?
+            .clone();
+        let registry = registry.read();
+        world.flush();
+
+        for snapshot in &self.entities {
+            for component in &snapshot.components {
+                if registry
+                    .get_type_data::<ReflectComponent>(component.type_id)
+                    .is_none()
+                {
+                    return Err(RestoreSnapshotError::UnregisteredType(component.type_id));
+                }
+            }
+        }
+        for resource in &self.resources {
+            if registry
+                .get_type_data::<ReflectResource>(resource.type_id)
+                .is_none()
+            {
+                return Err(RestoreSnapshotError::UnregisteredType(resource.type_id));
+            }
+        }
+
+        let spawned: EntityHashSet = if self.all_entities {
+            let captured: EntityHashSet = self.entities.iter().map(|e| e.entity).collect();
+            world
+                .iter_entities()
+                .filter(|entity| !is_internal(*entity) && !captured.contains(&entity.id()))
+                .map(|entity| entity.id())
+                .collect()
+        } else {
+            EntityHashSet::default()
+        };
+        for snapshot in &self.entities {
+            if world.get_entity(snapshot.entity).is_ok() {
+                continue;
+            }
+            let occupant = world.entities().resolve_from_id(snapshot.entity.index());
+            if occupant.is_some_and(|occupant| {
+                world.get_entity(occupant).is_ok() && !spawned.contains(&occupant)
+            }) {
+                return Err(RestoreSnapshotError::EntityOccupied(snapshot.entity));
+            }
+        }
+
+        for entity in spawned {
+            world.despawn(entity);
+        }
+
+        // All entities are respawned before any component is written back, so that components
+        // referencing other entities (and their hooks) observe a complete set of entities.
+        for snapshot in &self.entities {
+            world.flush();
+            match world.entities.alloc_at_without_replacement(snapshot.entity) {
+                AllocAtWithoutReplacement::Exists(_) => {}
+                AllocAtWithoutReplacement::DidNotExist => {
+                    // SAFETY: The entity was just allocated.
+                    unsafe { world.spawn_at_empty_internal(snapshot.entity) };
+                }
+                AllocAtWithoutReplacement::ExistsWithWrongGeneration => {
+                    unreachable!("the ids of captured entities were checked to be free")
+                }
+            }
+        }
+
+        for snapshot in &self.entities {
+            let mut entity_mut = world.entity_mut(snapshot.entity);
+            let current: Vec<TypeId> = entity_mut
+                .archetype()
+                .components()
+                .filter_map(|id| entity_mut.world().components().get_info(id)?.type_id())
+                .collect();
+            for type_id in current {
+                if snapshot.components.iter().any(|c| c.type_id == type_id)
+                    || snapshot
+                        .cloned_components
+                        .iter()
+                        .any(|c| c.type_id == type_id)
+                {
+                    continue;
+                }
+                if let Some(fns) = self.clone_component_fns.get(&type_id) {
+                    (fns.remove)(&mut entity_mut);
+                } else if self.component_filter.is_allowed_by_id(type_id) {
+                    if let Some(reflect_component) =
+                        registry.get_type_data::<ReflectComponent>(type_id)
+                    {
+                        reflect_component.remove(&mut entity_mut);
+                    }
+                }
+            }
+
+            for component in &snapshot.components {
+                let reflect_component = registry
+                    .get_type_data::<ReflectComponent>(component.type_id)
+                    .expect("captured component types were checked to be registered");
+                let value = registry
+                    .get_type_data::<ReflectFromReflect>(component.type_id)
+                    .and_then(|from_reflect| from_reflect.from_reflect(&*component.value));
+                // Replacing the value rather than applying to it ensures that elements added to
+                // lists, maps and sets since the snapshot are removed.
+                let replaced = match (value, reflect_component.reflect_mut(&mut entity_mut)) {
+                    (Some(value), Some(mut current)) => current.set(value).is_ok(),
+                    _ => false,
+                };
+                if !replaced {
+                    reflect_component.insert(&mut entity_mut, &*component.value, &registry);
+                }
+                set_component_ticks(&mut entity_mut, component.type_id, component.ticks);
+            }
+            for component in &snapshot.cloned_components {
+                (self.clone_component_fns[&component.type_id].restore)(
+                    &mut entity_mut,
+                    &*component.value,
+                );
+                set_component_ticks(&mut entity_mut, component.type_id, component.ticks);
+            }
+        }
+
+        let current: Vec<TypeId> = world
+            .iter_resources()
+            .filter_map(|(info, _)| info.type_id())
+            .collect();
+        for type_id in current {
+            if self.resources.iter().any(|r| r.type_id == type_id)
+                || self.cloned_resources.iter().any(|r| r.type_id == type_id)
+            {
+                continue;
+            }
+            if let Some(fns) = self.clone_resource_fns.get(&type_id) {
+                (fns.remove)(world);
+            } else if self.resource_filter.is_allowed_by_id(type_id) {
+                if let Some(reflect_resource) = registry.get_type_data::<ReflectResource>(type_id) {
+                    reflect_resource.remove(world);
+                }
+            }
+        }
+        for resource in &self.resources {
+            let reflect_resource = registry
+                .get_type_data::<ReflectResource>(resource.type_id)
+                .expect("captured resource types were checked to be registered");
+            let value = registry
+                .get_type_data::<ReflectFromReflect>(resource.type_id)
+                .and_then(|from_reflect| from_reflect.from_reflect(&*resource.value));
+            let replaced = match (value, reflect_resource.reflect_mut(world)) {
+                (Some(value), Some(mut current)) => current.set(value).is_ok(),
+                _ => false,
+            };
+            if !replaced {
+                reflect_resource.insert(world, &*resource.value, &registry);
+            }
+            set_resource_ticks(world, resource.type_id, resource.ticks);
+        }
+        for resource in &self.cloned_resources {
+            (self.clone_resource_fns[&resource.type_id].restore)(world, &*resource.value);
+            set_resource_ticks(world, resource.type_id, resource.ticks);
+        }
+
+        world.flush();
+        Ok(())
+    }
+}
+
+/// Returns `true` if `entity` is managed by the world itself, such as an observer or a
+/// registered system, and should not be despawned by a restore.
+fn is_internal(entity: EntityRef) -> bool {
+    entity.contains::<ObserverState>() || entity.contains::<SystemIdMarker>()
+}
+
+fn set_component_ticks(entity_mut: &mut EntityWorldMut, type_id: TypeId, ticks: ComponentTicks) {
+    let Some(component_id) = entity_mut.world().components().get_id(type_id) else {
+        return;
+    };
+    if let Ok(value) = entity_mut.get_mut_by_id(component_id) {
+        *value.ticks.added = ticks.added;
+        *value.ticks.changed = ticks.changed;
+    }
+}
+
+fn set_resource_ticks(world: &mut World, type_id: TypeId, ticks: ComponentTicks) {
+    let component_id = world.components().get_resource_id(type_id);
+    if let Some(value) = component_id.and_then(|id| world.get_resource_mut_by_id(id)) {
+        *value.ticks.added = ticks.added;
+        *value.ticks.changed = ticks.changed;
+    }
+}
+
+/// An error returned by [`WorldSnapshot::restore`].
+#[derive(Error, Display, Debug)]
+pub enum RestoreSnapshotError {
+    /// The [`World`] was missing the [`AppTypeRegistry`] resource.
+    #[display("The `World` was missing the `AppTypeRegistry` resource")]
+    MissingAppTypeRegistry,
+    /// A captured value's type is no longer registered with [`ReflectComponent`] or [`ReflectResource`].
+    #[display(
+        "The captured type {_0:?} is not registered with `ReflectComponent` or `ReflectResource`"
+    )]
+    #[error(ignore)]
+    UnregisteredType(TypeId),
+    /// The id of a captured entity is used by a different generation of that entity, which isn't part of the snapshot.
+    #[display(
+        "Cannot respawn {_0:?}: its id is used by an entity that is not part of the snapshot"
+    )]
+    #[error(ignore)]
+    EntityOccupied(Entity),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_ecs;
+    use crate::{
+        observer::Trigger,
+        reflect::ReflectResource,
+        system::{Commands, Resource},
+        world::OnAdd,
+    };
+    use bevy_reflect::Reflect;
+    use bevy_utils::HashMap;
+
+    #[derive(Component, Reflect, Debug, PartialEq, Clone)]
+    #[reflect(Component)]
+    struct Position(f32);
+
+    #[derive(Component, Reflect, Debug, PartialEq, Clone)]
+    #[reflect(Component)]
+    struct Velocity(f32);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct NotReflected;
+
+    #[derive(Resource, Reflect, Debug, PartialEq, Default)]
+    #[reflect(Resource)]
+    struct Frame(u32);
+
+    #[derive(Component, Reflect, Debug, PartialEq, Clone, Default)]
+    #[reflect(Component)]
+    struct Inventory(HashMap<u32, u32>);
+
+    #[derive(Component, Debug, PartialEq, Clone)]
+    struct Health(u32);
+
+    #[derive(Resource, Debug, PartialEq, Clone)]
+    struct Score(u32);
+
+    fn test_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Position>();
+            registry.register::<Velocity>();
+            registry.register::<Frame>();
+            registry.register::<Inventory>();
+        }
+        world
+    }
+
+    fn step(world: &mut World) {
+        world.increment_change_tick();
+        let mut query = world.query::<(&mut Position, &Velocity)>();
+        for (mut position, velocity) in query.iter_mut(world) {
+            position.0 += velocity.0;
+        }
+        world.resource_mut::<Frame>().0 += 1;
+    }
+
+    #[test]
+    fn restore_resimulates_deterministically() {
+        let mut world = test_world();
+        world.init_resource::<Frame>();
+        world.spawn((Position(0.0), Velocity(1.0)));
+        world.spawn((Position(10.0), Velocity(-2.0)));
+
+        let initial = WorldSnapshot::from_world(&world);
+        for _ in 0..3 {
+            step(&mut world);
+        }
+        let first_run = WorldSnapshot::from_world(&world);
+        assert!(!first_run.reflect_eq(&initial));
+
+        initial.restore(&mut world).unwrap();
+        assert!(WorldSnapshot::from_world(&world).reflect_eq(&initial));
+        for _ in 0..3 {
+            step(&mut world);
+        }
+        assert!(WorldSnapshot::from_world(&world).reflect_eq(&first_run));
+    }
+
+    #[test]
+    fn restore_preserves_entities_and_ticks() {
+        let mut world = test_world();
+        let a = world.spawn(Position(1.0)).id();
+        let b = world.spawn((Position(2.0), Velocity(3.0))).id();
+        let ticks = world.entity(a).get_change_ticks::<Position>().unwrap();
+        let snapshot = WorldSnapshot::from_world(&world);
+
+        world.increment_change_tick();
+        world.despawn(b);
+        world.entity_mut(a).insert((Position(5.0), Velocity(1.0)));
+        // Reuses the index of `b` with a new generation.
+        let c = world.spawn(Position(7.0)).id();
+        assert_eq!(c.index(), b.index());
+
+        snapshot.restore(&mut world).unwrap();
+        assert!(world.get_entity(c).is_err());
+        assert_eq!(world.get::<Position>(a), Some(&Position(1.0)));
+        assert_eq!(world.get::<Velocity>(a), None);
+        assert_eq!(world.get::<Position>(b), Some(&Position(2.0)));
+        assert_eq!(world.get::<Velocity>(b), Some(&Velocity(3.0)));
+
+        let restored = world.entity(a).get_change_ticks::<Position>().unwrap();
+        assert_eq!(restored.added, ticks.added);
+        assert_eq!(restored.changed, ticks.changed);
+    }
+
+    #[test]
+    fn filtered_snapshot_leaves_other_data_untouched() {
+        let mut world = test_world();
+        world.insert_resource(Frame(1));
+        let a = world
+            .spawn((Position(1.0), Velocity(1.0), NotReflected))
+            .id();
+        let b = world.spawn(Position(2.0)).id();
+        let snapshot = WorldSnapshot::builder(&world)
+            .with_entities([a])
+            .deny_component::<Velocity>()
+            .deny_resource::<Frame>()
+            .build();
+        assert_eq!(snapshot.entities().len(), 1);
+        assert_eq!(snapshot.entities()[0].components.len(), 1);
+        assert!(snapshot.resources().is_empty());
+
+        world.entity_mut(a).insert((Position(3.0), Velocity(3.0)));
+        world.entity_mut(b).insert(Position(3.0));
+        world.insert_resource(Frame(3));
+        let spawned = world.spawn_empty().id();
+
+        snapshot.restore(&mut world).unwrap();
+        assert_eq!(world.get::<Position>(a), Some(&Position(1.0)));
+        assert_eq!(world.get::<Velocity>(a), Some(&Velocity(3.0)));
+        assert_eq!(world.get::<NotReflected>(a), Some(&NotReflected));
+        assert_eq!(world.get::<Position>(b), Some(&Position(3.0)));
+        assert_eq!(world.resource::<Frame>(), &Frame(3));
+        assert!(world.get_entity(spawned).is_ok());
+    }
+
+    #[test]
+    fn restore_resources() {
+        let mut world = test_world();
+        world.insert_resource(Frame(1));
+        let snapshot = WorldSnapshot::from_world(&world);
+        world.insert_resource(Frame(2));
+        snapshot.restore(&mut world).unwrap();
+        assert_eq!(world.resource::<Frame>(), &Frame(1));
+
+        // Resources inserted after the snapshot are removed.
+        let mut world = test_world();
+        let snapshot = WorldSnapshot::from_world(&world);
+        world.insert_resource(Frame(2));
+        snapshot.restore(&mut world).unwrap();
+        assert!(!world.contains_resource::<Frame>());
+    }
+
+    #[test]
+    fn restore_into_occupied_id_fails() {
+        let mut world = test_world();
+        let a = world.spawn(Position(1.0)).id();
+        let snapshot = WorldSnapshot::builder(&world).with_entities([a]).build();
+        world.despawn(a);
+        let b = world.spawn_empty().id();
+        assert_eq!(a.index(), b.index());
+        assert!(matches!(
+            snapshot.restore(&mut world),
+            Err(RestoreSnapshotError::EntityOccupied(entity)) if entity == a
+        ));
+    }
+
+    #[test]
+    fn restore_replaces_values() {
+        let mut world = test_world();
+        let mut items = HashMap::default();
+        items.insert(1, 1);
+        let a = world.spawn(Inventory(items.clone())).id();
+        let snapshot = WorldSnapshot::from_world(&world);
+
+        let mut inventory = world.get_mut::<Inventory>(a).unwrap();
+        inventory.0.insert(2, 2);
+        inventory.0.insert(1, 3);
+
+        snapshot.restore(&mut world).unwrap();
+        assert_eq!(world.get::<Inventory>(a), Some(&Inventory(items)));
+    }
+
+    #[test]
+    fn restore_keeps_internal_entities() {
+        let mut world = test_world();
+        let snapshot = WorldSnapshot::from_world(&world);
+
+        let observer = world
+            .add_observer(|_: Trigger<OnAdd, Position>, mut commands: Commands| {
+                commands.insert_resource(Frame(1));
+            })
+            .id();
+        let system = world.register_system(|mut commands: Commands| {
+            commands.insert_resource(Frame(2));
+        });
+        world.flush();
+
+        snapshot.restore(&mut world).unwrap();
+        assert!(world.get_entity(observer).is_ok());
+        world.run_system(system).unwrap();
+        assert_eq!(world.resource::<Frame>(), &Frame(2));
+        world.spawn(Position(0.0));
+        world.flush();
+        assert_eq!(world.resource::<Frame>(), &Frame(1));
+    }
+
+    #[test]
+    fn failed_restore_leaves_world_untouched() {
+        let mut world = test_world();
+        let a = world.spawn((Position(1.0), Velocity(1.0))).id();
+        let snapshot = WorldSnapshot::from_world(&world);
+
+        world.entity_mut(a).insert(Position(2.0));
+        let spawned = world.spawn(Position(3.0)).id();
+        world.insert_resource(AppTypeRegistry::default());
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Position>();
+
+        assert!(matches!(
+            snapshot.restore(&mut world),
+            Err(RestoreSnapshotError::UnregisteredType(type_id)) if type_id == TypeId::of::<Velocity>()
+        ));
+        assert_eq!(world.get::<Position>(a), Some(&Position(2.0)));
+        assert!(world.get_entity(spawned).is_ok());
+    }
+
+    #[test]
+    fn restore_cloned_values() {
+        let mut world = test_world();
+        world.insert_resource(Score(1));
+        let a = world.spawn(Health(10)).id();
+        let b = world.spawn_empty().id();
+        let ticks = world.entity(a).get_change_ticks::<Health>().unwrap();
+        let snapshot = WorldSnapshot::builder(&world)
+            .clone_component::<Health>()
+            .clone_resource::<Score>()
+            .build();
+        assert_eq!(snapshot.entities()[0].cloned_components.len(), 1);
+        assert_eq!(snapshot.cloned_resources().len(), 1);
+
+        world.increment_change_tick();
+        world.get_mut::<Health>(a).unwrap().0 = 5;
+        world.entity_mut(b).insert(Health(3));
+        world.insert_resource(Score(2));
+
+        snapshot.restore(&mut world).unwrap();
+        assert_eq!(world.get::<Health>(a), Some(&Health(10)));
+        assert_eq!(world.get::<Health>(b), None);
+        assert_eq!(world.resource::<Score>(), &Score(1));
+        let restored = world.entity(a).get_change_ticks::<Health>().unwrap();
+        assert_eq!(restored.changed, ticks.changed);
+    }
+}
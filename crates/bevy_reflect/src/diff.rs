@@ -0,0 +1,545 @@
+use core::{
+    fmt::{self, Write},
+    hash::{Hash, Hasher},
+};
+
+use derive_more::derive::{Display, Error, From};
+
+use crate::{
+    utility::reflect_hasher, ApplyError, PartialReflect, ReflectKind, ReflectMut, ReflectPath,
+    ReflectPathError, ReflectRef,
+};
+
+/// A change of a value nested in a reflected value, as found by [`ReflectDiff::new`].
+#[derive(Debug)]
+pub struct ReflectChange {
+    /// The path of the changed value, which can be used as a [`ReflectPath`](crate::ReflectPath).
+    ///
+    /// An empty path means that the whole value changed.
+    pub path: String,
+    /// The value before the change.
+    pub old: Box<dyn PartialReflect>,
+    /// The value after the change.
+    pub new: Box<dyn PartialReflect>,
+}
+
+impl Clone for ReflectChange {
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            old: self.old.clone_value(),
+            new: self.new.clone_value(),
+        }
+    }
+}
+
+/// The structural difference between two reflected values.
+///
+/// The diff walks both values and descends into structs, tuples, tuple structs, lists and arrays
+/// of the same shape, and into enums set to the same variant, so that each [`ReflectChange`]
+/// describes a value that actually differs.
+/// Values of a different shape, such as lists of a different length, as well as maps and sets, are
+/// changed as a whole, and replaced rather than merged with the existing elements when the diff
+/// is applied.
+///
+/// Values are compared with [`PartialReflect::reflect_partial_eq`] and are only considered equal
+/// if it returns `Some(true)`, so leaf values that do not reflect equality are always changed.
+/// See [`reflect_deep_eq`] to compare values without computing their changes.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, ReflectDiff};
+/// #[derive(Reflect, Clone, PartialEq, Debug)]
+/// struct Player {
+///     name: String,
+///     position: (f32, f32),
+/// }
+///
+/// let old = Player { name: "Ferris".to_string(), position: (0.0, 0.0) };
+/// let new = Player { name: "Ferris".to_string(), position: (1.0, 0.0) };
+///
+/// let diff = ReflectDiff::new(&old, &new);
+/// assert_eq!(diff.changes.len(), 1);
+/// assert_eq!(diff.changes[0].path, ".position.0");
+///
+/// let mut value = old.clone();
+/// diff.apply(&mut value).unwrap();
+/// assert_eq!(value, new);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ReflectDiff {
+    /// The changes turning the old value into the new one.
+    pub changes: Vec<ReflectChange>,
+}
+
+/// An error returned when a [`ReflectDiff`] could not be applied to a value.
+#[derive(Debug, Display, From, Error)]
+pub enum ApplyDiffError<'a> {
+    /// The path of a change does not exist in the value.
+    #[display("{_0}")]
+    Path(#[error(not(source))] ReflectPathError<'a>),
+    /// The value of a change could not be applied.
+    #[display("{_0}")]
+    Apply(ApplyError),
+}
+
+impl ReflectDiff {
+    /// Computes the changes turning `old` into `new`.
+    pub fn new(old: &dyn PartialReflect, new: &dyn PartialReflect) -> Self {
+        let mut diff = Self::default();
+        diff_values(old, new, &mut String::new(), &mut |path, old, new| {
+            diff.changes.push(ReflectChange {
+                path: path.to_string(),
+                old: old.clone_value(),
+                new: new.clone_value(),
+            });
+        });
+        diff
+    }
+
+    /// Returns `true` if the values are structurally equal.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Applies the new values of the changes to `value`.
+    pub fn apply(&self, value: &mut dyn PartialReflect) -> Result<(), ApplyDiffError<'_>> {
+        for change in &self.changes {
+            reflect_replace(
+                change.path.as_str().reflect_element_mut(value)?,
+                change.new.as_ref(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Applies the old values of the changes to `value`, undoing [`apply`](Self::apply).
+    pub fn revert(&self, value: &mut dyn PartialReflect) -> Result<(), ApplyDiffError<'_>> {
+        for change in self.changes.iter().rev() {
+            reflect_replace(
+                change.path.as_str().reflect_element_mut(value)?,
+                change.old.as_ref(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Applies `value` to `target`, removing the elements of lists, maps and sets that are not in
+/// `value` instead of merging them as [`PartialReflect::try_apply`] does.
+///
+/// This is how the changes of a [`ReflectDiff`] are applied. If applying `value` fails, `target`
+/// keeps its elements.
+pub fn reflect_replace(
+    target: &mut dyn PartialReflect,
+    value: &dyn PartialReflect,
+) -> Result<(), ApplyError> {
+    if !matches!(
+        (target.reflect_kind(), value.reflect_kind()),
+        (ReflectKind::List, ReflectKind::List)
+            | (ReflectKind::Map, ReflectKind::Map)
+            | (ReflectKind::Set, ReflectKind::Set)
+    ) {
+        return target.try_apply(value);
+    }
+
+    let old = target.clone_value();
+    drain(target);
+    if let Err(err) = target.try_apply(value) {
+        // The old elements came from `target`, so applying them back doesn't fail.
+        drain(target);
+        let _ = target.try_apply(old.as_ref());
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Removes all of the elements of a list, map or set.
+fn drain(target: &mut dyn PartialReflect) {
+    match target.reflect_mut() {
+        ReflectMut::List(list) => {
+            list.drain();
+        }
+        ReflectMut::Map(map) => {
+            map.drain();
+        }
+        ReflectMut::Set(set) => {
+            set.drain();
+        }
+        _ => {}
+    }
+}
+
+/// Compares `a` and `b` structurally.
+///
+/// Unlike [`PartialReflect::reflect_partial_eq`], this descends into structs, tuples, tuple
+/// structs, lists, arrays and enums itself, so that a difference is found even if another field
+/// does not reflect equality. Maps, sets and opaque values are compared with
+/// [`PartialReflect::reflect_partial_eq`].
+///
+/// Returns `None` if no difference was found but some of the values could not be compared, such
+/// as opaque types not registered with `#[reflect(PartialEq)]`.
+pub fn reflect_deep_eq(a: &dyn PartialReflect, b: &dyn PartialReflect) -> Option<bool> {
+    let Some(fields) = paired_fields(a, b) else {
+        return a.reflect_partial_eq(b);
+    };
+    let mut result = Some(true);
+    for (_, a, b) in fields {
+        match reflect_deep_eq(a, b) {
+            Some(false) => return Some(false),
+            None => result = None,
+            Some(true) => {}
+        }
+    }
+    result
+}
+
+/// Hashes `value` structurally, consistently with [`reflect_deep_eq`].
+///
+/// Structs, tuples, tuple structs, lists, arrays, enums, maps and sets are hashed from their
+/// fields and elements, so that a value and its dynamic representation have the same hash.
+/// Opaque values are hashed with [`PartialReflect::reflect_hash`].
+///
+/// Returns `None` if some of the values could not be hashed, such as opaque types not registered
+/// with `#[reflect(Hash)]`.
+pub fn reflect_deep_hash(value: &dyn PartialReflect) -> Option<u64> {
+    let mut hasher = reflect_hasher();
+    core::mem::discriminant(&value.reflect_kind()).hash(&mut hasher);
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            for (i, field) in value.iter_fields().enumerate() {
+                value.name_at(i).hash(&mut hasher);
+                reflect_deep_hash(field)?.hash(&mut hasher);
+            }
+        }
+        ReflectRef::TupleStruct(value) => {
+            value.field_len().hash(&mut hasher);
+            for field in value.iter_fields() {
+                reflect_deep_hash(field)?.hash(&mut hasher);
+            }
+        }
+        ReflectRef::Tuple(value) => {
+            value.field_len().hash(&mut hasher);
+            for field in value.iter_fields() {
+                reflect_deep_hash(field)?.hash(&mut hasher);
+            }
+        }
+        ReflectRef::List(value) => {
+            value.len().hash(&mut hasher);
+            for element in value.iter() {
+                reflect_deep_hash(element)?.hash(&mut hasher);
+            }
+        }
+        ReflectRef::Array(value) => {
+            value.len().hash(&mut hasher);
+            for element in value.iter() {
+                reflect_deep_hash(element)?.hash(&mut hasher);
+            }
+        }
+        ReflectRef::Enum(value) => {
+            value.variant_name().hash(&mut hasher);
+            for field in value.iter_fields() {
+                field.name().hash(&mut hasher);
+                reflect_deep_hash(field.value())?.hash(&mut hasher);
+            }
+        }
+        // The entries of maps and sets are unordered, so their hashes are combined commutatively.
+        ReflectRef::Map(value) => {
+            let mut entries = 0u64;
+            for (key, value) in value.iter() {
+                let mut entry = reflect_hasher();
+                reflect_deep_hash(key)?.hash(&mut entry);
+                reflect_deep_hash(value)?.hash(&mut entry);
+                entries = entries.wrapping_add(entry.finish());
+            }
+            value.len().hash(&mut hasher);
+            entries.hash(&mut hasher);
+        }
+        ReflectRef::Set(value) => {
+            let mut entries = 0u64;
+            for value in value.iter() {
+                entries = entries.wrapping_add(reflect_deep_hash(value)?);
+            }
+            value.len().hash(&mut hasher);
+            entries.hash(&mut hasher);
+        }
+        _ => return value.reflect_hash(),
+    }
+    Some(hasher.finish())
+}
+
+/// How a field or element of a value is accessed in a [`ReflectPath`].
+enum FieldAccess<'a> {
+    Name(&'a str),
+    Index(usize),
+    Element(usize),
+}
+
+impl fmt::Display for FieldAccess<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Name(name) => write!(f, ".{name}"),
+            Self::Index(index) => write!(f, ".{index}"),
+            Self::Element(index) => write!(f, "[{index}]"),
+        }
+    }
+}
+
+/// The fields or elements of two values of the same shape, with how they are accessed.
+type PairedFields<'a> = Vec<(
+    FieldAccess<'a>,
+    &'a dyn PartialReflect,
+    &'a dyn PartialReflect,
+)>;
+
+/// Pairs up the fields or elements of `a` and `b` if they are structs, tuples, tuple structs,
+/// lists, arrays or enums of the same shape.
+fn paired_fields<'a>(
+    a: &'a dyn PartialReflect,
+    b: &'a dyn PartialReflect,
+) -> Option<PairedFields<'a>> {
+    match (a.reflect_ref(), b.reflect_ref()) {
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) if a.field_len() == b.field_len() => (0..b
+            .field_len())
+            .map(|i| {
+                let name = b.name_at(i)?;
+                (a.name_at(i)? == name).then_some(())?;
+                Some((FieldAccess::Name(name), a.field_at(i)?, b.field_at(i)?))
+            })
+            .collect(),
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b))
+            if a.field_len() == b.field_len() =>
+        {
+            (0..b.field_len())
+                .map(|i| Some((FieldAccess::Index(i), a.field(i)?, b.field(i)?)))
+                .collect()
+        }
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) if a.field_len() == b.field_len() => (0..b
+            .field_len())
+            .map(|i| Some((FieldAccess::Index(i), a.field(i)?, b.field(i)?)))
+            .collect(),
+        (ReflectRef::List(a), ReflectRef::List(b)) if a.len() == b.len() => (0..b.len())
+            .map(|i| Some((FieldAccess::Element(i), a.get(i)?, b.get(i)?)))
+            .collect(),
+        (ReflectRef::Array(a), ReflectRef::Array(b)) if a.len() == b.len() => (0..b.len())
+            .map(|i| Some((FieldAccess::Element(i), a.get(i)?, b.get(i)?)))
+            .collect(),
+        (ReflectRef::Enum(a), ReflectRef::Enum(b))
+            if a.variant_name() == b.variant_name() && a.field_len() == b.field_len() =>
+        {
+            (0..b.field_len())
+                .map(|i| {
+                    let access = match b.name_at(i) {
+                        Some(name) => {
+                            (a.name_at(i)? == name).then_some(())?;
+                            FieldAccess::Name(name)
+                        }
+                        None => FieldAccess::Index(i),
+                    };
+                    Some((access, a.field_at(i)?, b.field_at(i)?))
+                })
+                .collect()
+        }
+        _ => None,
+    }
+}
+
+/// Calls `on_change` with the path, old and new value of every value that differs between `old`
+/// and `new`.
+fn diff_values(
+    old: &dyn PartialReflect,
+    new: &dyn PartialReflect,
+    path: &mut String,
+    on_change: &mut dyn FnMut(&str, &dyn PartialReflect, &dyn PartialReflect),
+) {
+    if old.reflect_partial_eq(new) == Some(true) {
+        return;
+    }
+
+    // Values of a different shape, or missing some of their fields, change as a whole.
+    let Some(fields) = paired_fields(old, new) else {
+        on_change(path, old, new);
+        return;
+    };
+
+    let mut changed = false;
+    for (access, old, new) in fields {
+        let len = path.len();
+        write!(path, "{access}").unwrap();
+        diff_values(old, new, path, &mut |path, old, new| {
+            changed = true;
+            on_change(path, old, new);
+        });
+        path.truncate(len);
+    }
+    // The values differ even though none of their fields do, for example because of a custom
+    // `PartialEq` implementation.
+    if !changed {
+        on_change(path, old, new);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_reflect, Reflect};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Shape {
+        Circle { radius: f32 },
+        Square(f32),
+        Point,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Level {
+        name: String,
+        shapes: Vec<Shape>,
+        size: [u32; 2],
+        tags: HashMap<String, u32>,
+    }
+
+    fn level() -> Level {
+        Level {
+            name: "start".to_string(),
+            shapes: vec![Shape::Circle { radius: 1.0 }, Shape::Square(2.0)],
+            size: [4, 4],
+            tags: HashMap::default(),
+        }
+    }
+
+    fn paths(diff: &ReflectDiff) -> Vec<&str> {
+        diff.changes
+            .iter()
+            .map(|change| change.path.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn should_diff_nested_values() {
+        let old = level();
+        let mut new = level();
+        new.shapes[0] = Shape::Circle { radius: 3.0 };
+        new.shapes[1] = Shape::Square(5.0);
+        new.size[1] = 8;
+
+        let diff = ReflectDiff::new(&old, &new);
+        assert_eq!(
+            paths(&diff),
+            [".shapes[0].radius", ".shapes[1].0", ".size[1]"]
+        );
+        assert_eq!(diff.changes[2].old.try_downcast_ref::<u32>(), Some(&4));
+        assert_eq!(diff.changes[2].new.try_downcast_ref::<u32>(), Some(&8));
+        assert!(ReflectDiff::new(&old, &old.clone()).is_empty());
+    }
+
+    #[test]
+    fn should_replace_values_of_a_different_shape() {
+        let old = level();
+        let mut new = level();
+        new.shapes[1] = Shape::Point;
+        new.tags.insert("boss".to_string(), 1);
+        let diff = ReflectDiff::new(&old, &new);
+        assert_eq!(paths(&diff), [".shapes[1]", ".tags"]);
+
+        new.shapes.push(Shape::Point);
+        let diff = ReflectDiff::new(&old, &new);
+        assert_eq!(paths(&diff), [".shapes", ".tags"]);
+    }
+
+    #[test]
+    fn should_apply_and_revert_diff() {
+        let old = level();
+        let mut new = level();
+        new.name = "end".to_string();
+        new.shapes.push(Shape::Point);
+        new.tags.insert("boss".to_string(), 1);
+        let diff = ReflectDiff::new(&old, &new);
+
+        let mut value = old.clone();
+        diff.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+        diff.revert(&mut value).unwrap();
+        assert_eq!(value, old);
+
+        // The diff can be applied to a dynamic representation of the value.
+        let mut dynamic = old.clone_value();
+        diff.apply(dynamic.as_mut()).unwrap();
+        assert_eq!(dynamic.reflect_partial_eq(&new), Some(true));
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(opaque)]
+    struct Opaque;
+
+    #[derive(Reflect, Clone, Debug)]
+    struct Item {
+        id: Opaque,
+        count: u32,
+    }
+
+    #[test]
+    fn should_compare_values_deeply() {
+        let item = Item {
+            id: Opaque,
+            count: 1,
+        };
+        let other = Item {
+            count: 2,
+            ..item.clone()
+        };
+        assert_eq!(item.reflect_partial_eq(&other), None);
+        assert_eq!(reflect_deep_eq(&item, &other), Some(false));
+        assert_eq!(reflect_deep_eq(&item, &item.clone()), None);
+        assert_eq!(
+            reflect_deep_eq(&level(), level().clone_value().as_ref()),
+            Some(true)
+        );
+
+        // Values that do not reflect equality are changed, whatever their hash.
+        let diff = ReflectDiff::new(&item, &item.clone());
+        assert_eq!(paths(&diff), [".id"]);
+    }
+
+    #[test]
+    fn should_hash_values_deeply() {
+        let mut tags = HashMap::default();
+        tags.insert("boss".to_string(), 1u32);
+        tags.insert("hidden".to_string(), 2);
+        let value = (vec![1u32, 2], Some("name".to_string()), tags);
+        let hash = reflect_deep_hash(&value);
+        assert!(hash.is_some());
+        assert_eq!(reflect_deep_hash(value.clone_value().as_ref()), hash);
+
+        let mut other = value.clone();
+        other.0[1] = 3;
+        assert_ne!(reflect_deep_hash(&other), hash);
+        other = value.clone();
+        other.2.insert("boss".to_string(), 3);
+        assert_ne!(reflect_deep_hash(&other), hash);
+
+        let item = Item {
+            id: Opaque,
+            count: 1,
+        };
+        assert_eq!(reflect_deep_hash(&item), None);
+    }
+
+    #[test]
+    fn should_fail_to_apply_to_a_value_without_the_path() {
+        let diff = ReflectDiff::new(
+            &level(),
+            &Level {
+                size: [1, 1],
+                ..level()
+            },
+        );
+        let mut value = (1u32, 2u32);
+        assert!(matches!(
+            diff.apply(&mut value),
+            Err(ApplyDiffError::Path(_))
+        ));
+    }
+}
@@ -556,6 +556,7 @@
 extern crate alloc;
 
 mod array;
+mod diff;
 mod fields;
 mod from_reflect;
 #[cfg(feature = "functions")]
@@ -619,6 +620,7 @@ pub mod prelude {
 }
 
 pub use array::*;
+pub use diff::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
@@ -4,25 +4,31 @@ use core::any::TypeId;
 
 use anyhow::{anyhow, Result as AnyhowResult};
 use bevy_ecs::{
+    change_detection::DetectChangesMut,
     component::ComponentId,
     entity::Entity,
     event::EventCursor,
     query::QueryBuilder,
-    reflect::{AppTypeRegistry, ReflectComponent},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
     removal_detection::RemovedComponentEntity,
-    system::{In, Local},
-    world::{EntityRef, EntityWorldMut, FilteredEntityRef, World},
+    schedule::{ScheduleGraphExport, Schedules},
+    system::{In, Local, Resource},
+    world::{EntityRef, EntityWorldMut, FilteredEntityRef, Mut, World},
 };
-use bevy_hierarchy::BuildChildren as _;
+use bevy_hierarchy::{BuildChildren as _, Children, Parent};
 use bevy_reflect::{
-    serde::{ReflectSerializer, TypedReflectDeserializer},
-    PartialReflect, TypeRegistration, TypeRegistry,
+    serde::{ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer},
+    PartialReflect, ReflectFromReflect, ReflectPath as _, TypeInfo, TypeRegistration, TypeRegistry,
 };
 use bevy_utils::HashMap;
 use serde::{de::DeserializeSeed as _, Deserialize, Serialize};
 use serde_json::{Map, Value};
 
-use crate::{error_codes, BrpError, BrpResult};
+use crate::{
+    error_codes,
+    schemas::json_schema::{export_type_registry, JsonSchemaFilter},
+    BrpError, BrpResult,
+};
 
 /// The method path for a `bevy/get` request.
 pub const BRP_GET_METHOD: &str = "bevy/get";
@@ -54,6 +60,33 @@ pub const BRP_GET_AND_WATCH_METHOD: &str = "bevy/get+watch";
 /// The method path for a `bevy/list+watch` request.
 pub const BRP_LIST_AND_WATCH_METHOD: &str = "bevy/list+watch";
 
+/// The method path for a `bevy/schedules` request.
+pub const BRP_SCHEDULES_METHOD: &str = "bevy/schedules";
+
+/// The method path for a `bevy/mutate_component` request.
+pub const BRP_MUTATE_COMPONENT_METHOD: &str = "bevy/mutate_component";
+
+/// The method path for a `bevy/batch` request.
+pub const BRP_BATCH_METHOD: &str = "bevy/batch";
+
+/// The method path for a `bevy/get_resource` request.
+pub const BRP_GET_RESOURCE_METHOD: &str = "bevy/get_resource";
+
+/// The method path for a `bevy/insert_resource` request.
+pub const BRP_INSERT_RESOURCE_METHOD: &str = "bevy/insert_resource";
+
+/// The method path for a `bevy/mutate_resource` request.
+pub const BRP_MUTATE_RESOURCE_METHOD: &str = "bevy/mutate_resource";
+
+/// The method path for a `bevy/list_resources` request.
+pub const BRP_LIST_RESOURCES_METHOD: &str = "bevy/list_resources";
+
+/// The method path for a `bevy/get_resource+watch` request.
+pub const BRP_GET_RESOURCE_AND_WATCH_METHOD: &str = "bevy/get_resource+watch";
+
+/// The method path for a `bevy/registry/schema` request.
+pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "bevy/registry/schema";
+
 /// `bevy/get`: Retrieves one or more components from the entity with the given
 /// ID.
 ///
@@ -160,6 +193,31 @@ pub struct BrpInsertParams {
     pub components: HashMap<String, Value>,
 }
 
+/// `bevy/mutate_component`: Changes a single field of a component of an entity.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpMutateComponentParams {
+    /// The ID of the entity whose component is to be mutated.
+    pub entity: Entity,
+
+    /// The [full path] of the component type that is to be mutated.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub component: String,
+
+    /// The [reflection path] of the field to change within the component, e.g. `.translation.x`.
+    ///
+    /// An empty path changes the whole component.
+    ///
+    /// [reflection path]: bevy_reflect::ReflectPath
+    #[serde(default)]
+    pub path: String,
+
+    /// The serialized value that the field is set to.
+    pub value: Value,
+}
+
 /// `bevy/reparent`: Assign a new parent to one or more entities.
 ///
 /// The server responds with a null.
@@ -187,6 +245,175 @@ pub struct BrpListParams {
     pub entity: Entity,
 }
 
+/// `bevy/schedules`: Exports the graphs of the app's schedules, with their systems, sets,
+/// dependencies, conflicts and ambiguities.
+///
+/// The server responds with a [`BrpSchedulesResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BrpSchedulesParams {
+    /// The labels of the schedules to export, as formatted by their [`Debug`] implementation
+    /// (e.g. `Update`). If empty, every schedule is exported.
+    #[serde(default)]
+    pub schedules: Vec<String>,
+
+    /// The format of the exported graphs.
+    #[serde(default)]
+    pub format: BrpScheduleFormat,
+}
+
+/// The format of the graphs returned by `bevy/schedules`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrpScheduleFormat {
+    /// Each graph is a JSON object with the fields of [`ScheduleGraphExport`].
+    #[default]
+    Json,
+    /// Each graph is a string in the DOT language, as produced by [`ScheduleGraphExport::to_dot`].
+    Dot,
+}
+
+/// `bevy/batch`: Applies a list of operations to the world atomically.
+///
+/// The operations are applied in order, within a single frame. If one of them fails, the effects
+/// of the previous ones are rolled back and the server responds with the error of the failed
+/// operation. Otherwise, the server responds with a [`BrpBatchResponse`].
+///
+/// Components added along with inserted ones, such as required components, are removed by the
+/// rollback as well, and reparented entities are restored at their previous index among their
+/// parent's children. Other changes made by hooks and observers in response to the operations are
+/// not rolled back.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpBatchParams {
+    /// The operations to apply, in order.
+    pub operations: Vec<BrpBatchOperation>,
+}
+
+/// A single operation of a `bevy/batch` request.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum BrpBatchOperation {
+    /// Creates a new entity with the given components, like `bevy/spawn`.
+    Spawn {
+        /// A name that the following operations of the batch can use to refer to the new entity.
+        #[serde(default)]
+        id: Option<String>,
+
+        /// A map from each component's [full type path] to its serialized value.
+        ///
+        /// [full type path]: bevy_reflect::TypePath::type_path
+        #[serde(default)]
+        components: HashMap<String, Value>,
+    },
+
+    /// Adds one or more components to an entity, like `bevy/insert`.
+    Insert {
+        /// The entity that components are to be added to.
+        entity: BrpBatchEntity,
+
+        /// A map from each component's [full type path] to its serialized value.
+        ///
+        /// [full type path]: bevy_reflect::TypePath::type_path
+        components: HashMap<String, Value>,
+    },
+
+    /// Deletes one or more components from an entity, like `bevy/remove`.
+    Remove {
+        /// The entity from which components are to be removed.
+        entity: BrpBatchEntity,
+
+        /// The [full type paths] of the components that are to be removed.
+        ///
+        /// [full type paths]: bevy_reflect::TypePath::type_path
+        components: Vec<String>,
+    },
+
+    /// Assigns a new parent to one or more entities, like `bevy/reparent`.
+    Reparent {
+        /// The entities that are to become the new children of the `parent`.
+        entities: Vec<BrpBatchEntity>,
+
+        /// The entity that will become the new parent of the `entities`.
+        ///
+        /// If this is `None`, then the entities are removed from their parents.
+        #[serde(default)]
+        parent: Option<BrpBatchEntity>,
+    },
+
+    /// Despawns an entity, like `bevy/destroy`.
+    ///
+    /// Entities are despawned once all operations of the batch have succeeded, and can't be
+    /// used by the operations following this one.
+    Destroy {
+        /// The entity to despawn.
+        entity: BrpBatchEntity,
+    },
+}
+
+/// An entity referred to by an operation of a `bevy/batch` request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum BrpBatchEntity {
+    /// The ID of an entity that already exists in the world.
+    Entity(Entity),
+    /// The `id` given to an entity spawned by a previous operation of the same batch.
+    Spawned(String),
+}
+
+/// `bevy/registry/schema`: Exports the types registered in the [`AppTypeRegistry`] as
+/// JSON Schema.
+///
+/// The server responds with a JSON Schema document whose `$defs` contain one schema per type,
+/// as produced by [`export_type_registry`].
+pub type BrpJsonSchemaParams = JsonSchemaFilter;
+
+/// `bevy/get_resource`: Retrieves the value of a resource.
+///
+/// The server responds with a [`BrpGetResourceResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpGetResourceParams {
+    /// The [full path] of the resource type that is to be requested.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+}
+
+/// `bevy/insert_resource`: Inserts a resource into the world, replacing its current value if
+/// it already exists.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpInsertResourceParams {
+    /// The [full path] of the resource type that is to be inserted.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+
+    /// The serialized value of the resource.
+    pub value: Value,
+}
+
+/// `bevy/mutate_resource`: Changes a single field of a resource.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpMutateResourceParams {
+    /// The [full path] of the resource type that is to be mutated.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+
+    /// The [reflection path] of the field to change within the resource, e.g. `.color.red`.
+    ///
+    /// An empty path changes the whole resource.
+    ///
+    /// [reflection path]: bevy_reflect::ReflectPath
+    #[serde(default)]
+    pub path: String,
+
+    /// The serialized value that the field is set to.
+    pub value: Value,
+}
+
 /// Describes the data that is to be fetched in a query.
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct BrpQuery {
@@ -291,6 +518,35 @@ pub struct BrpListWatchingResponse {
     removed: Vec<String>,
 }
 
+/// The response to a `bevy/schedules` request, associating each schedule label to its graph.
+pub type BrpSchedulesResponse = HashMap<String, Value>;
+
+/// The response to a `bevy/batch` request.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BrpBatchResponse {
+    /// The IDs of the entities spawned by the batch, keyed by the `id` of their `spawn` operation.
+    pub entities: HashMap<String, Entity>,
+}
+
+/// The response to a `bevy/get_resource` request, and a single response from a
+/// `bevy/get_resource+watch` request.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpGetResourceResponse {
+    /// The serialized value of the resource.
+    pub value: Value,
+}
+
+/// The response to a `bevy/list_resources` request.
+pub type BrpListResourcesResponse = Vec<String>;
+
+/// The graphs of the schedules that were in [`Schedules`] the last times [`cache_schedule_graphs`] ran,
+/// along with the [generation](bevy_ecs::schedule::Schedule::generation) they were exported from.
+///
+/// Schedules are removed from [`Schedules`] while they run, so this is used to export the
+/// schedule that processes remote requests, and those that run it.
+#[derive(Resource, Default)]
+pub struct RemoteScheduleGraphs(HashMap<String, (u64, ScheduleGraphExport)>);
+
 /// The response to a `bevy/query` request.
 pub type BrpQueryResponse = Vec<BrpQueryRow>;
 
@@ -683,6 +939,40 @@ pub fn process_remote_destroy_request(
     Ok(Value::Null)
 }
 
+/// Handles a `bevy/mutate_component` request coming from a client.
+pub fn process_remote_mutate_component_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpMutateComponentParams {
+        entity,
+        component,
+        path,
+        value,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let reflect_component =
+        get_reflect_component(&type_registry, &component).map_err(BrpError::component_error)?;
+    let mut entity_world_mut = get_entity_mut(world, entity)?;
+    let Some(mut reflected) = reflect_component.reflect_mut(&mut entity_world_mut) else {
+        return Err(BrpError::component_not_present(&component, entity));
+    };
+
+    // Only flag the component as changed if the mutation succeeds.
+    apply_at_path(
+        reflected.bypass_change_detection().as_partial_reflect_mut(),
+        &path,
+        value,
+        &type_registry,
+    )?;
+    reflected.set_changed();
+
+    Ok(Value::Null)
+}
+
 /// Handles a `bevy/reparent` request coming from a client.
 pub fn process_remote_reparent_request(
     In(params): In<Option<Value>>,
@@ -793,6 +1083,568 @@ pub fn process_remote_list_watching_request(
     }
 }
 
+/// Handles a `bevy/schedules` request coming from a client.
+pub fn process_remote_schedules_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let BrpSchedulesParams { schedules, format } =
+        params.map(parse).transpose()?.unwrap_or_default();
+
+    let mut exports = HashMap::new();
+    if let Some(cache) = world.get_resource::<RemoteScheduleGraphs>() {
+        for (label, (_, export)) in &cache.0 {
+            exports.insert(label.clone(), export.clone());
+        }
+    }
+    // Prefer up-to-date exports of the schedules that aren't running.
+    if let Some(all_schedules) = world.get_resource::<Schedules>() {
+        for (label, schedule) in all_schedules.iter() {
+            exports.insert(
+                format!("{label:?}"),
+                schedule.export_graph(world.components()),
+            );
+        }
+    }
+
+    if !schedules.is_empty() {
+        if let Some(missing) = schedules.iter().find(|label| !exports.contains_key(*label)) {
+            return Err(BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: format!("Schedule `{missing}` not found"),
+                data: None,
+            });
+        }
+        exports.retain(|label, _| schedules.contains(label));
+    }
+
+    let mut response = BrpSchedulesResponse::default();
+    for (label, export) in exports {
+        let graph = match format {
+            BrpScheduleFormat::Json => serde_json::to_value(export).map_err(BrpError::internal)?,
+            BrpScheduleFormat::Dot => Value::String(export.to_dot()),
+        };
+        response.insert(label, graph);
+    }
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Stores the graphs of the schedules in [`Schedules`] in [`RemoteScheduleGraphs`], so that
+/// `bevy/schedules` can export them while they're running.
+///
+/// Graphs are only exported again when the schedule was rebuilt since they were cached.
+///
+/// The [`RemotePlugin`](crate::RemotePlugin) runs this in [`First`](bevy_app::First) and
+/// [`Last`](bevy_app::Last), so that each of them is cached while the other one runs, and once
+/// before the app starts to cache the [`Main`](bevy_app::Main) schedule.
+pub fn cache_schedule_graphs(world: &mut World) {
+    world.resource_scope(|world, mut cache: Mut<RemoteScheduleGraphs>| {
+        let Some(schedules) = world.get_resource::<Schedules>() else {
+            return;
+        };
+        for (label, schedule) in schedules.iter() {
+            let label = format!("{label:?}");
+            let generation = schedule.generation();
+            if cache
+                .0
+                .get(&label)
+                .is_some_and(|(cached_generation, _)| *cached_generation == generation)
+            {
+                continue;
+            }
+            let export = schedule.export_graph(world.components());
+            cache.0.insert(label, (generation, export));
+        }
+    });
+}
+
+/// Handles a `bevy/batch` request coming from a client.
+pub fn process_remote_batch_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let BrpBatchParams { operations } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let mut batch = RemoteBatch::default();
+    for (index, operation) in operations.into_iter().enumerate() {
+        if let Err(mut err) = batch.apply(world, &type_registry, operation) {
+            batch.rollback(world, &type_registry);
+
+            // Tell the client which operation failed.
+            let mut data = Map::new();
+            data.insert("operation".to_owned(), Value::from(index));
+            if let Some(inner) = err.data.take() {
+                data.insert("data".to_owned(), inner);
+            }
+            err.data = Some(Value::Object(data));
+            return Err(err);
+        }
+    }
+
+    let response = batch.commit(world);
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// The state of a `bevy/batch` request that is being applied.
+#[derive(Default)]
+struct RemoteBatch {
+    /// The entities spawned by the batch that were given an `id`.
+    spawned: HashMap<String, Entity>,
+    /// The entities to despawn once all operations have succeeded.
+    destroyed: Vec<Entity>,
+    /// How to revert each change made so far, in the order they were made.
+    undo: Vec<RemoteBatchUndo>,
+}
+
+/// A change made by a `bevy/batch` request that will be reverted if a later operation fails.
+enum RemoteBatchUndo {
+    /// The entity was spawned.
+    Despawn(Entity),
+    /// A component was inserted or removed. `previous` is its value before the change, if any.
+    RestoreComponent {
+        entity: Entity,
+        reflect_component: ReflectComponent,
+        previous: Option<Box<dyn PartialReflect>>,
+    },
+    /// Components were inserted into the entity, which only had the `previous` components before,
+    /// so that the components they required are removed along with them.
+    RemoveAddedComponents {
+        entity: Entity,
+        previous: Vec<ComponentId>,
+    },
+    /// The entity was reparented. `previous` is its parent before the change and its index among
+    /// the children of that parent, if any.
+    RestoreParent {
+        entity: Entity,
+        previous: Option<(Entity, usize)>,
+    },
+}
+
+impl RemoteBatch {
+    /// Applies a single operation, recording how to revert it.
+    fn apply(
+        &mut self,
+        world: &mut World,
+        type_registry: &TypeRegistry,
+        operation: BrpBatchOperation,
+    ) -> BrpResult<()> {
+        match operation {
+            BrpBatchOperation::Spawn { id, components } => {
+                if let Some(id) = &id {
+                    if self.spawned.contains_key(id) {
+                        return Err(BrpError {
+                            code: error_codes::INVALID_PARAMS,
+                            message: format!("Entity id `{id}` is used by more than one spawn"),
+                            data: None,
+                        });
+                    }
+                }
+
+                let reflect_components = deserialize_components(type_registry, components)
+                    .map_err(BrpError::component_error)?;
+
+                let entity = world.spawn_empty().id();
+                self.undo.push(RemoteBatchUndo::Despawn(entity));
+                if let Some(id) = id {
+                    self.spawned.insert(id, entity);
+                }
+                insert_reflected_components(
+                    type_registry,
+                    world.entity_mut(entity),
+                    reflect_components,
+                )
+                .map_err(BrpError::component_error)?;
+            }
+            BrpBatchOperation::Insert { entity, components } => {
+                let entity = self.resolve(world, &entity)?;
+                let reflect_components = deserialize_components(type_registry, components)
+                    .map_err(BrpError::component_error)?;
+
+                self.undo.push(RemoteBatchUndo::RemoveAddedComponents {
+                    entity,
+                    previous: world.entity(entity).archetype().components().collect(),
+                });
+                for reflected in reflect_components {
+                    let reflect_component =
+                        get_reflect_component(type_registry, represented_type_path(&*reflected))
+                            .map_err(BrpError::component_error)?;
+                    let previous = reflect_component
+                        .reflect(world.entity(entity))
+                        .map(PartialReflect::clone_value);
+                    self.undo.push(RemoteBatchUndo::RestoreComponent {
+                        entity,
+                        reflect_component: reflect_component.clone(),
+                        previous,
+                    });
+                    reflect_component.insert(
+                        &mut world.entity_mut(entity),
+                        &*reflected,
+                        type_registry,
+                    );
+                }
+            }
+            BrpBatchOperation::Remove { entity, components } => {
+                let entity = self.resolve(world, &entity)?;
+
+                for component_path in components {
+                    let reflect_component = get_reflect_component(type_registry, &component_path)
+                        .map_err(BrpError::component_error)?;
+                    let Some(previous) = reflect_component
+                        .reflect(world.entity(entity))
+                        .map(PartialReflect::clone_value)
+                    else {
+                        continue;
+                    };
+                    self.undo.push(RemoteBatchUndo::RestoreComponent {
+                        entity,
+                        reflect_component: reflect_component.clone(),
+                        previous: Some(previous),
+                    });
+                    reflect_component.remove(&mut world.entity_mut(entity));
+                }
+            }
+            BrpBatchOperation::Reparent { entities, parent } => {
+                let parent = parent
+                    .map(|parent| self.resolve(world, &parent))
+                    .transpose()?;
+                let entities = entities
+                    .iter()
+                    .map(|entity| self.resolve(world, entity))
+                    .collect::<BrpResult<Vec<_>>>()?;
+
+                for entity in entities {
+                    if Some(entity) == parent {
+                        return Err(BrpError::self_reparent(entity));
+                    }
+                    let previous = world.get::<Parent>(entity).map(|parent| {
+                        let index = world
+                            .get::<Children>(parent.get())
+                            .and_then(|children| children.iter().position(|&c| c == entity))
+                            .unwrap_or_default();
+                        (parent.get(), index)
+                    });
+                    self.undo
+                        .push(RemoteBatchUndo::RestoreParent { entity, previous });
+                    match parent {
+                        Some(parent) => {
+                            world.entity_mut(parent).add_child(entity);
+                        }
+                        None => {
+                            world.entity_mut(entity).remove_parent();
+                        }
+                    }
+                }
+            }
+            BrpBatchOperation::Destroy { entity } => {
+                let entity = self.resolve(world, &entity)?;
+                self.destroyed.push(entity);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the entity an operation refers to, if it exists and hasn't been destroyed.
+    fn resolve(&self, world: &World, entity: &BrpBatchEntity) -> BrpResult<Entity> {
+        let entity = match entity {
+            BrpBatchEntity::Entity(entity) => *entity,
+            BrpBatchEntity::Spawned(id) => *self.spawned.get(id).ok_or_else(|| BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: format!("No entity with id `{id}` was spawned by this batch"),
+                data: None,
+            })?,
+        };
+
+        if self.destroyed.contains(&entity) || world.get_entity(entity).is_err() {
+            return Err(BrpError::entity_not_found(entity));
+        }
+        Ok(entity)
+    }
+
+    /// Reverts all changes made by the batch, in reverse order.
+    fn rollback(self, world: &mut World, type_registry: &TypeRegistry) {
+        for undo in self.undo.into_iter().rev() {
+            match undo {
+                RemoteBatchUndo::Despawn(entity) => {
+                    world.despawn(entity);
+                }
+                RemoteBatchUndo::RestoreComponent {
+                    entity,
+                    reflect_component,
+                    previous,
+                } => {
+                    let Ok(mut entity_world_mut) = world.get_entity_mut(entity) else {
+                        continue;
+                    };
+                    match previous {
+                        Some(previous) => {
+                            reflect_component.insert(
+                                &mut entity_world_mut,
+                                &*previous,
+                                type_registry,
+                            );
+                        }
+                        None => reflect_component.remove(&mut entity_world_mut),
+                    }
+                }
+                RemoteBatchUndo::RemoveAddedComponents { entity, previous } => {
+                    let Ok(mut entity_world_mut) = world.get_entity_mut(entity) else {
+                        continue;
+                    };
+                    let added: Vec<ComponentId> = entity_world_mut
+                        .archetype()
+                        .components()
+                        .filter(|component_id| !previous.contains(component_id))
+                        .collect();
+                    for component_id in added {
+                        entity_world_mut.remove_by_id(component_id);
+                    }
+                }
+                RemoteBatchUndo::RestoreParent { entity, previous } => {
+                    if world.get_entity(entity).is_err() {
+                        continue;
+                    }
+                    match previous {
+                        Some((parent, index)) => {
+                            let Ok(mut parent_world_mut) = world.get_entity_mut(parent) else {
+                                continue;
+                            };
+                            // Other children may have been moved by the batch as well.
+                            let len = parent_world_mut.get::<Children>().map_or(0, |children| {
+                                children.iter().filter(|&&c| c != entity).count()
+                            });
+                            parent_world_mut.insert_children(index.min(len), &[entity]);
+                        }
+                        None => {
+                            world.entity_mut(entity).remove_parent();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Despawns the destroyed entities, completing the batch.
+    fn commit(self, world: &mut World) -> BrpBatchResponse {
+        for entity in self.destroyed {
+            world.despawn(entity);
+        }
+
+        BrpBatchResponse {
+            entities: self.spawned,
+        }
+    }
+}
+
+/// Handles a `bevy/get_resource` request coming from a client.
+pub fn process_remote_get_resource_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpGetResourceParams { resource } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let response = reflect_resource(&resource, world, &type_registry)?;
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/get_resource+watch` request coming from a client.
+///
+/// A response is sent whenever the resource is added or changed. Nothing is sent while the
+/// resource doesn't exist.
+pub fn process_remote_get_resource_watching_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult<Option<Value>> {
+    let BrpGetResourceParams { resource } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let type_registration = get_resource_type_registration(&type_registry, &resource)
+        .map_err(BrpError::resource_error)?;
+    let Some(ticks) = world
+        .components()
+        .get_resource_id(type_registration.type_id())
+        .and_then(|component_id| world.get_resource_change_ticks_by_id(component_id))
+    else {
+        return Ok(None);
+    };
+    if !ticks.is_changed(world.last_change_tick(), world.read_change_tick()) {
+        return Ok(None);
+    }
+
+    let response = reflect_resource(&resource, world, &type_registry)?;
+    Ok(Some(
+        serde_json::to_value(response).map_err(BrpError::internal)?,
+    ))
+}
+
+/// Handles a `bevy/insert_resource` request coming from a client.
+pub fn process_remote_insert_resource_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpInsertResourceParams { resource, value } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let type_registration = get_resource_type_registration(&type_registry, &resource)
+        .map_err(BrpError::resource_error)?;
+    let reflect_resource =
+        get_reflect_resource(type_registration, &resource).map_err(BrpError::resource_error)?;
+    let reflected = TypedReflectDeserializer::new(type_registration, &type_registry)
+        .deserialize(&value)
+        .map_err(|err| {
+            BrpError::resource_error(format!("Invalid value for `{resource}`: {err}"))
+        })?;
+
+    reflect_resource.insert(world, &*reflected, &type_registry);
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/mutate_resource` request coming from a client.
+pub fn process_remote_mutate_resource_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpMutateResourceParams {
+        resource,
+        path,
+        value,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let type_registration = get_resource_type_registration(&type_registry, &resource)
+        .map_err(BrpError::resource_error)?;
+    let reflect_resource =
+        get_reflect_resource(type_registration, &resource).map_err(BrpError::resource_error)?;
+    let Some(mut reflected) = reflect_resource.reflect_mut(world) else {
+        return Err(BrpError::resource_not_present(&resource));
+    };
+
+    // Only flag the resource as changed if the mutation succeeds.
+    apply_at_path(
+        reflected.bypass_change_detection().as_partial_reflect_mut(),
+        &path,
+        value,
+        &type_registry,
+    )?;
+    reflected.set_changed();
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/list_resources` request coming from a client.
+pub fn process_remote_list_resources_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let mut response = BrpListResourcesResponse::default();
+    for registered_type in type_registry.iter() {
+        if registered_type.data::<ReflectResource>().is_some() {
+            response.push(registered_type.type_info().type_path().to_owned());
+        }
+    }
+    response.sort();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/registry/schema` request coming from a client.
+pub fn process_remote_registry_schema_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let filter: BrpJsonSchemaParams = params.map(parse).transpose()?.unwrap_or_default();
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+    Ok(export_type_registry(&type_registry, &filter))
+}
+
+/// Reflect the resource with the given type path into a [`BrpGetResourceResponse`].
+fn reflect_resource(
+    resource_path: &str,
+    world: &World,
+    type_registry: &TypeRegistry,
+) -> BrpResult<BrpGetResourceResponse> {
+    let type_registration = get_resource_type_registration(type_registry, resource_path)
+        .map_err(BrpError::resource_error)?;
+    let reflect_resource =
+        get_reflect_resource(type_registration, resource_path).map_err(BrpError::resource_error)?;
+
+    let Some(reflected) = reflect_resource.reflect(world) else {
+        return Err(BrpError::resource_not_present(resource_path));
+    };
+
+    let serializer = TypedReflectSerializer::new(reflected.as_partial_reflect(), type_registry);
+    let value = serde_json::to_value(&serializer).map_err(BrpError::resource_error)?;
+
+    Ok(BrpGetResourceResponse { value })
+}
+
+/// Sets the value at the given [reflection path] within `target` to the deserialized `value`.
+///
+/// The value is replaced as a whole when its type is registered with [`ReflectFromReflect`], so
+/// that lists, maps and sets nested in it don't keep their previous elements. Otherwise, it is
+/// applied with [`PartialReflect::try_apply`].
+///
+/// [reflection path]: bevy_reflect::ReflectPath
+fn apply_at_path(
+    target: &mut dyn PartialReflect,
+    path: &str,
+    value: Value,
+    type_registry: &TypeRegistry,
+) -> BrpResult<()> {
+    let field = path
+        .reflect_element_mut(target)
+        .map_err(|err| BrpError::invalid_path(path, &err))?;
+
+    let invalid_value = |message: String| BrpError {
+        code: error_codes::INVALID_PARAMS,
+        message,
+        data: None,
+    };
+
+    let Some(field_type) = field
+        .get_represented_type_info()
+        .and_then(|type_info| type_registry.get(type_info.type_id()))
+    else {
+        return Err(invalid_value(format!(
+            "The type of the value at `{path}` (`{}`) isn't registered",
+            field.reflect_type_path()
+        )));
+    };
+
+    let value = TypedReflectDeserializer::new(field_type, type_registry)
+        .deserialize(&value)
+        .map_err(|err| invalid_value(format!("Invalid value for `{path}`: {err}")))?;
+    if let Some(value) = field_type
+        .data::<ReflectFromReflect>()
+        .and_then(|reflect_from_reflect| reflect_from_reflect.from_reflect(&*value))
+    {
+        if let Some(field) = field.try_as_reflect_mut() {
+            return field.set(value).map_err(|value| {
+                invalid_value(format!(
+                    "Could not set value at `{path}` to `{}`",
+                    value.reflect_type_path()
+                ))
+            });
+        }
+    }
+    field
+        .try_apply(&*value)
+        .map_err(|err| invalid_value(format!("Could not apply value at `{path}`: {err}")))
+}
+
 /// Immutably retrieves an entity from the [`World`], returning an error if the
 /// entity isn't present.
 fn get_entity(world: &World, entity: Entity) -> Result<EntityRef<'_>, BrpError> {
@@ -923,7 +1775,7 @@ fn deserialize_components(
         let reflected: Box<dyn PartialReflect> =
             TypedReflectDeserializer::new(component_type, type_registry)
                 .deserialize(&component)
-                .unwrap();
+                .map_err(|err| anyhow!("Invalid value for `{}`: {}", component_path, err))?;
         reflect_components.push(reflected);
     }
 
@@ -939,13 +1791,24 @@ fn insert_reflected_components(
 ) -> AnyhowResult<()> {
     for reflected in reflect_components {
         let reflect_component =
-            get_reflect_component(type_registry, reflected.reflect_type_path())?;
+            get_reflect_component(type_registry, represented_type_path(&*reflected))?;
         reflect_component.insert(&mut entity_world_mut, &*reflected, type_registry);
     }
 
     Ok(())
 }
 
+/// Returns the type path of the type represented by a reflected value.
+///
+/// Deserialized values are usually dynamic types, like [`DynamicStruct`](bevy_reflect::DynamicStruct),
+/// which represent the component they were deserialized as.
+fn represented_type_path(reflected: &dyn PartialReflect) -> &str {
+    reflected
+        .get_represented_type_info()
+        .map(TypeInfo::type_path)
+        .unwrap_or_else(|| reflected.reflect_type_path())
+}
+
 /// Given a component's type path, return the associated [`ReflectComponent`] from the given
 /// `type_registry` if possible.
 fn get_reflect_component<'r>(
@@ -959,6 +1822,27 @@ fn get_reflect_component<'r>(
         .ok_or_else(|| anyhow!("Component `{}` isn't reflectable", component_path))
 }
 
+/// Given a resource's type path, return the associated [`TypeRegistration`] from the given
+/// `type_registry` if possible.
+fn get_resource_type_registration<'r>(
+    type_registry: &'r TypeRegistry,
+    resource_path: &str,
+) -> AnyhowResult<&'r TypeRegistration> {
+    type_registry
+        .get_with_type_path(resource_path)
+        .ok_or_else(|| anyhow!("Unknown resource type: `{}`", resource_path))
+}
+
+/// Given a resource's [`TypeRegistration`], return the associated [`ReflectResource`] if possible.
+fn get_reflect_resource<'r>(
+    type_registration: &'r TypeRegistration,
+    resource_path: &str,
+) -> AnyhowResult<&'r ReflectResource> {
+    type_registration
+        .data::<ReflectResource>()
+        .ok_or_else(|| anyhow!("Resource `{}` isn't reflectable", resource_path))
+}
+
 /// Given a component's type path, return the associated [`TypeRegistration`] from the given
 /// `type_registry` if possible.
 fn get_component_type_registration<'r>(
@@ -969,3 +1853,275 @@ fn get_component_type_registration<'r>(
         .get_with_type_path(component_path)
         .ok_or_else(|| anyhow!("Unknown component type: `{}`", component_path))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{
+        change_detection::DetectChanges, component::Component, reflect::ReflectComponent,
+        system::Resource,
+    };
+    use bevy_reflect::{Reflect, TypePath};
+    use serde_json::json;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Debug, PartialEq, Default)]
+    #[reflect(Component)]
+    struct Armor(u32);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    #[require(Armor)]
+    struct Shield(u32);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Stats {
+        speed: f32,
+        levels: Vec<u32>,
+    }
+
+    #[derive(Resource, Reflect, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Config {
+        volume: f32,
+        title: String,
+    }
+
+    fn world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Health>();
+            registry.register::<Armor>();
+            registry.register::<Shield>();
+            registry.register::<Stats>();
+            registry.register::<Config>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn batch_rolls_back_when_an_operation_fails() {
+        let mut world = world();
+        let entity = world.spawn(Health(10)).id();
+
+        let err = process_remote_batch_request(
+            In(Some(json!({
+                "operations": [
+                    {
+                        "op": "spawn",
+                        "id": "child",
+                        "components": { Health::type_path(): 1 },
+                    },
+                    {
+                        "op": "insert",
+                        "entity": entity,
+                        "components": { Health::type_path(): 20, Armor::type_path(): 5 },
+                    },
+                    {
+                        "op": "remove",
+                        "entity": entity,
+                        "components": [Health::type_path()],
+                    },
+                    { "op": "reparent", "entities": ["child"], "parent": entity },
+                    { "op": "destroy", "entity": entity },
+                    {
+                        "op": "insert",
+                        "entity": "child",
+                        "components": { "unregistered::Component": 0 },
+                    },
+                ]
+            }))),
+            &mut world,
+        )
+        .unwrap_err();
+        assert_eq!(err.code, error_codes::COMPONENT_ERROR);
+        assert_eq!(err.data.unwrap()["operation"], 5);
+
+        assert_eq!(world.entities().len(), 1);
+        let entity = world.entity(entity);
+        assert_eq!(entity.get::<Health>(), Some(&Health(10)));
+        assert!(!entity.contains::<Armor>());
+        assert!(entity.get::<Children>().is_none());
+    }
+
+    #[test]
+    fn batch_rollback_removes_required_components_and_restores_child_order() {
+        let mut world = world();
+        let entity = world.spawn(Health(10)).id();
+        let parent = world.spawn_empty().id();
+        let children = [(); 3].map(|_| world.spawn_empty().set_parent(parent).id());
+
+        let err = process_remote_batch_request(
+            In(Some(json!({
+                "operations": [
+                    {
+                        "op": "insert",
+                        "entity": entity,
+                        "components": { Shield::type_path(): 1 },
+                    },
+                    { "op": "reparent", "entities": [children[1]], "parent": entity },
+                    { "op": "reparent", "entities": [children[0]], "parent": null },
+                    { "op": "destroy", "entity": "missing" },
+                ]
+            }))),
+            &mut world,
+        )
+        .unwrap_err();
+        assert_eq!(err.data.unwrap()["operation"], 3);
+
+        let entity = world.entity(entity);
+        assert!(!entity.contains::<Shield>());
+        assert!(!entity.contains::<Armor>());
+        assert!(!entity.contains::<Children>());
+        assert_eq!(&**world.get::<Children>(parent).unwrap(), &children);
+    }
+
+    #[test]
+    fn resource_methods() {
+        let mut world = world();
+        let params = || Some(json!({ "resource": Config::type_path() }));
+
+        let err = process_remote_get_resource_request(In(params()), &world).unwrap_err();
+        assert_eq!(err.code, error_codes::RESOURCE_NOT_PRESENT);
+        assert_eq!(
+            process_remote_get_resource_watching_request(In(params()), &world).unwrap(),
+            None
+        );
+
+        process_remote_insert_resource_request(
+            In(Some(json!({
+                "resource": Config::type_path(),
+                "value": { "volume": 0.5, "title": "Game" },
+            }))),
+            &mut world,
+        )
+        .unwrap();
+        assert_eq!(
+            world.resource::<Config>(),
+            &Config {
+                volume: 0.5,
+                title: "Game".to_owned()
+            }
+        );
+        assert_eq!(
+            process_remote_get_resource_request(In(params()), &world).unwrap(),
+            json!({ "value": { "volume": 0.5, "title": "Game" } })
+        );
+        assert!(
+            process_remote_get_resource_watching_request(In(params()), &world)
+                .unwrap()
+                .is_some()
+        );
+
+        world.clear_trackers();
+        assert_eq!(
+            process_remote_get_resource_watching_request(In(params()), &world).unwrap(),
+            None
+        );
+
+        process_remote_mutate_resource_request(
+            In(Some(json!({
+                "resource": Config::type_path(),
+                "path": ".volume",
+                "value": 1.0,
+            }))),
+            &mut world,
+        )
+        .unwrap();
+        assert_eq!(world.resource::<Config>().volume, 1.0);
+        assert_eq!(
+            process_remote_get_resource_watching_request(In(params()), &world).unwrap(),
+            Some(json!({ "value": { "volume": 1.0, "title": "Game" } }))
+        );
+
+        let err = process_remote_mutate_resource_request(
+            In(Some(json!({
+                "resource": Config::type_path(),
+                "path": ".volume",
+                "value": "loud",
+            }))),
+            &mut world,
+        )
+        .unwrap_err();
+        assert_eq!(err.code, error_codes::INVALID_PARAMS);
+        assert_eq!(world.resource::<Config>().volume, 1.0);
+
+        let err = process_remote_insert_resource_request(
+            In(Some(json!({ "resource": Health::type_path(), "value": 1 }))),
+            &mut world,
+        )
+        .unwrap_err();
+        assert_eq!(err.code, error_codes::RESOURCE_ERROR);
+
+        assert_eq!(
+            process_remote_list_resources_request(In(None), &world).unwrap(),
+            json!([Config::type_path()])
+        );
+    }
+
+    #[test]
+    fn mutate_component_paths() {
+        let mut world = world();
+        let entity = world
+            .spawn(Stats {
+                speed: 1.0,
+                levels: vec![1, 2],
+            })
+            .id();
+        world.clear_trackers();
+
+        let mutate = |world: &mut World, path: &str, value: Value| {
+            process_remote_mutate_component_request(
+                In(Some(json!({
+                    "entity": entity,
+                    "component": Stats::type_path(),
+                    "path": path,
+                    "value": value,
+                }))),
+                world,
+            )
+        };
+
+        mutate(&mut world, ".levels[1]", json!(5)).unwrap();
+        assert_eq!(world.get::<Stats>(entity).unwrap().levels, [1, 5]);
+        assert!(world
+            .entity(entity)
+            .get_ref::<Stats>()
+            .unwrap()
+            .is_changed());
+
+        mutate(&mut world, "", json!({ "speed": 2.0, "levels": [] })).unwrap();
+        assert_eq!(
+            world.get::<Stats>(entity),
+            Some(&Stats {
+                speed: 2.0,
+                levels: vec![]
+            })
+        );
+
+        world.clear_trackers();
+        let err = mutate(&mut world, ".strength", json!(1.0)).unwrap_err();
+        assert_eq!(err.code, error_codes::INVALID_PATH);
+        let err = mutate(&mut world, ".levels[3]", json!(1)).unwrap_err();
+        assert_eq!(err.code, error_codes::INVALID_PATH);
+
+        let err = mutate(&mut world, ".speed", json!("fast")).unwrap_err();
+        assert_eq!(err.code, error_codes::INVALID_PARAMS);
+        let err = mutate(&mut world, ".levels", json!(3)).unwrap_err();
+        assert_eq!(err.code, error_codes::INVALID_PARAMS);
+
+        assert_eq!(world.get::<Stats>(entity).unwrap().speed, 2.0);
+        assert!(!world
+            .entity(entity)
+            .get_ref::<Stats>()
+            .unwrap()
+            .is_changed());
+    }
+}
@@ -9,7 +9,7 @@
 #![cfg(not(target_family = "wasm"))]
 
 use crate::{
-    error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult, BrpSender,
+    error_codes, transport, BrpBatch, BrpError, BrpMessage, BrpResponse, BrpResult, BrpSender,
 };
 use anyhow::Result as AnyhowResult;
 use async_channel::{Receiver, Sender};
@@ -347,47 +347,14 @@ async fn process_single_request(
     request: Value,
     request_sender: &Sender<BrpMessage>,
 ) -> AnyhowResult<BrpHttpResponse<BrpResponse, BrpStream>> {
-    // Reach in and get the request ID early so that we can report it even when parsing fails.
-    let id = request.as_object().and_then(|map| map.get("id")).cloned();
-
-    let request: BrpRequest = match serde_json::from_value(request) {
-        Ok(v) => v,
-        Err(err) => {
-            return Ok(BrpHttpResponse::Complete(BrpResponse::new(
-                id,
-                Err(BrpError {
-                    code: error_codes::INVALID_REQUEST,
-                    message: err.to_string(),
-                    data: None,
-                }),
-            )));
-        }
+    let request = match transport::parse_request(request) {
+        Ok(request) => request,
+        Err(response) => return Ok(BrpHttpResponse::Complete(response)),
     };
 
-    if request.jsonrpc != "2.0" {
-        return Ok(BrpHttpResponse::Complete(BrpResponse::new(
-            id,
-            Err(BrpError {
-                code: error_codes::INVALID_REQUEST,
-                message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
-                data: None,
-            }),
-        )));
-    }
-
-    let watch = request.method.contains("+watch");
-    let size = if watch { 8 } else { 1 };
-    let (result_sender, result_receiver) = async_channel::bounded(size);
-
-    let _ = request_sender
-        .send(BrpMessage {
-            method: request.method,
-            params: request.params,
-            sender: result_sender,
-        })
-        .await;
+    let result_receiver = transport::send_request(&request, request_sender).await;
 
-    if watch {
+    if transport::is_watching_method(&request.method) {
         Ok(BrpHttpResponse::Stream(BrpStream {
             id: request.id,
             rx: Box::pin(result_receiver),
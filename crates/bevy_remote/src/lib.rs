@@ -6,6 +6,14 @@
 //! over HTTP. These *remote clients* can inspect and alter the state of the
 //! entity-component system.
 //!
+//! Other transports are available behind cargo features: `websocket` for the
+//! [`RemoteWebSocketPlugin`](websocket::RemoteWebSocketPlugin), `stdio` for the
+//! [`RemoteStdioPlugin`](stdio::RemoteStdioPlugin) and `unix_socket` for the
+//! [`RemoteUnixSocketPlugin`](unix_socket::RemoteUnixSocketPlugin). Unlike HTTP, they hold a
+//! bidirectional session with each client, over which the responses to `+watch` requests are
+//! pushed as they happen. Custom transports can be built from the pieces in the [`transport`]
+//! module.
+//!
 //! The Bevy Remote Protocol is based on the JSON-RPC 2.0 protocol.
 //!
 //! ## Request objects
@@ -189,6 +197,23 @@
 //!
 //! `result`: null.
 //!
+//! ### `bevy/mutate_component`
+//!
+//! Change a single field of a component of an entity, leaving the others untouched.
+//!
+//! `params`:
+//! - `entity`: The ID of the entity whose component will be mutated.
+//! - `component`: The [fully-qualified type name] of the component to mutate.
+//! - `path` (optional): The [reflection path] of the field to change, e.g. `.translation.x`. If omitted
+//!   or empty, the whole component is changed.
+//! - `value`: The serialized value of the field.
+//!
+//! `result`: null.
+//!
+//! If `path` can't be parsed or doesn't lead to a field of the component, the error has the
+//! [`INVALID_PATH`] code and its `data` contains the `path`, as well as the `offset` in the path and
+//! the failing `access` when they're known.
+//!
 //! ### bevy/reparent
 //!
 //! Assign a new parent to one or more entities.
@@ -257,6 +282,109 @@
 //!   in the last tick.
 //!
 //!
+//! ### bevy/schedules
+//!
+//! Export the graphs of the app's schedules, including their systems, system sets, run conditions,
+//! hierarchy, dependencies, allowed ambiguities and conflicts.
+//!
+//! `params` (optional):
+//! - `schedules` (optional): An array of schedule labels (e.g. `Update`) to export. If omitted or empty,
+//!   every schedule is exported.
+//! - `format` (optional): Either `json` (the default) or `dot`.
+//!
+//! `result`: A map associating each schedule label with its graph. With the `json` format, each graph is an
+//! object with the fields of [`ScheduleGraphExport`]. With the `dot` format, each graph is a string in the
+//! [DOT language] of Graphviz.
+//!
+//! ### `bevy/get_resource`
+//!
+//! Retrieve the value of a resource.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to get.
+//!
+//! `result`:
+//! - `value`: The serialized value of the resource.
+//!
+//! ### `bevy/insert_resource`
+//!
+//! Insert a resource into the world, replacing its current value if it already exists.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to insert.
+//! - `value`: The serialized value of the resource.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/mutate_resource`
+//!
+//! Change a single field of a resource, leaving the others untouched.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to mutate.
+//! - `path` (optional): The [reflection path] of the field to change, e.g. `.color.red`. If omitted or
+//!   empty, the whole resource is changed.
+//! - `value`: The serialized value of the field.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/list_resources`
+//!
+//! List all registered reflectable resources.
+//!
+//! `params`: None.
+//!
+//! `result`: An array of [fully-qualified type names] of resources.
+//!
+//! ### `bevy/get_resource+watch`
+//!
+//! Watch the value of a resource. A response is sent each time the resource is added or changed.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to watch.
+//!
+//! `result`:
+//! - `value`: The serialized value of the resource.
+//!
+//! ### `bevy/registry/schema`
+//!
+//! Export the types registered in the app's type registry as a [JSON Schema] document. Each type
+//! is described in the `$defs` of the document, keyed by its [fully-qualified type name], along with
+//! its kind, generic parameters and reflected type data (e.g. `Component` or `Default`).
+//!
+//! `params` (optional):
+//! - `with_crates`: An array of crate names. If not empty, only types from these crates are exported.
+//! - `without_crates`: An array of crate names whose types are not exported.
+//! - `with_reflect_types`: An array of reflected type data names (e.g. `Component`). Only types
+//!   having all of them are exported.
+//! - `without_reflect_types`: An array of reflected type data names. Types having any of them are
+//!   not exported.
+//!
+//! `result`: A JSON Schema document.
+//!
+//! ### bevy/batch
+//!
+//! Apply a list of operations to the world atomically, within a single frame. If any operation fails,
+//! the changes made by the previous ones are rolled back and the error of the failed operation is
+//! returned, with its index in `data.operation`.
+//!
+//! `params`:
+//! - `operations`: An array of operations, applied in order. Each operation is an object whose `op`
+//!   field is one of:
+//!   - `spawn`: Create a new entity with the given `components`, a map associating each component's
+//!     fully-qualified type name with its value. An optional `id` string names the new entity.
+//!   - `insert`: Insert `components`, a map as in `spawn`, into `entity`.
+//!   - `remove`: Remove `components`, an array of fully-qualified type names, from `entity`.
+//!   - `reparent`: Make `entities`, an array of entities, children of the optional `parent`, or
+//!     remove them from their parents if `parent` is omitted.
+//!   - `destroy`: Despawn `entity`. Entities are despawned once all operations have succeeded.
+//!
+//!   Entities can be given either as an entity ID, or as the `id` of an entity spawned by a previous
+//!   operation of the batch.
+//!
+//! `result`:
+//! - `entities`: A map associating the `id` of each `spawn` operation with the ID of the spawned entity.
+//!
 //! ## Custom methods
 //!
 //! In addition to the provided methods, the Bevy Remote Protocol can be extended to include custom
@@ -299,6 +427,11 @@
 //! [the `serde` documentation]: https://serde.rs/
 //! [fully-qualified type names]: bevy_reflect::TypePath::type_path
 //! [fully-qualified type name]: bevy_reflect::TypePath::type_path
+//! [`ScheduleGraphExport`]: bevy_ecs::schedule::ScheduleGraphExport
+//! [DOT language]: https://graphviz.org/doc/info/lang.html
+//! [reflection path]: bevy_reflect::ReflectPath
+//! [`INVALID_PATH`]: error_codes::INVALID_PATH
+//! [JSON Schema]: https://json-schema.org/
 
 use async_channel::{Receiver, Sender};
 use bevy_app::prelude::*;
@@ -309,6 +442,7 @@ use bevy_ecs::{
     system::{Commands, In, IntoSystem, ResMut, Resource, System, SystemId},
     world::World,
 };
+use bevy_reflect::ReflectPathError;
 use bevy_utils::{prelude::default, HashMap};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -317,6 +451,14 @@ use std::sync::RwLock;
 pub mod builtin_methods;
 #[cfg(feature = "http")]
 pub mod http;
+pub mod schemas;
+#[cfg(feature = "stdio")]
+pub mod stdio;
+pub mod transport;
+#[cfg(feature = "unix_socket")]
+pub mod unix_socket;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 const CHANNEL_SIZE: usize = 16;
 
@@ -396,6 +538,10 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_DESTROY_METHOD,
                 builtin_methods::process_remote_destroy_request,
             )
+            .with_method(
+                builtin_methods::BRP_MUTATE_COMPONENT_METHOD,
+                builtin_methods::process_remote_mutate_component_request,
+            )
             .with_method(
                 builtin_methods::BRP_REPARENT_METHOD,
                 builtin_methods::process_remote_reparent_request,
@@ -412,6 +558,38 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_LIST_AND_WATCH_METHOD,
                 builtin_methods::process_remote_list_watching_request,
             )
+            .with_method(
+                builtin_methods::BRP_SCHEDULES_METHOD,
+                builtin_methods::process_remote_schedules_request,
+            )
+            .with_method(
+                builtin_methods::BRP_BATCH_METHOD,
+                builtin_methods::process_remote_batch_request,
+            )
+            .with_method(
+                builtin_methods::BRP_GET_RESOURCE_METHOD,
+                builtin_methods::process_remote_get_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_INSERT_RESOURCE_METHOD,
+                builtin_methods::process_remote_insert_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_MUTATE_RESOURCE_METHOD,
+                builtin_methods::process_remote_mutate_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_LIST_RESOURCES_METHOD,
+                builtin_methods::process_remote_list_resources_request,
+            )
+            .with_method(
+                builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
+                builtin_methods::process_remote_registry_schema_request,
+            )
+            .with_watching_method(
+                builtin_methods::BRP_GET_RESOURCE_AND_WATCH_METHOD,
+                builtin_methods::process_remote_get_resource_watching_request,
+            )
     }
 }
 
@@ -436,7 +614,10 @@ impl Plugin for RemotePlugin {
 
         app.insert_resource(remote_methods)
             .init_resource::<RemoteWatchingRequests>()
+            .init_resource::<builtin_methods::RemoteScheduleGraphs>()
             .add_systems(PreStartup, setup_mailbox_channel)
+            .add_systems(First, builtin_methods::cache_schedule_graphs)
+            .add_systems(Last, builtin_methods::cache_schedule_graphs)
             .add_systems(
                 Update,
                 (
@@ -447,6 +628,11 @@ impl Plugin for RemotePlugin {
                     .chain(),
             );
     }
+
+    fn cleanup(&self, app: &mut App) {
+        // `Main` is never in `Schedules` while the systems caching the graphs run.
+        builtin_methods::cache_schedule_graphs(app.world_mut());
+    }
 }
 
 /// A type to hold the allowed types of systems to be used as method handlers.
@@ -656,6 +842,56 @@ impl BrpError {
         }
     }
 
+    /// An arbitrary resource error. Possibly related to reflection.
+    #[must_use]
+    pub fn resource_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::RESOURCE_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// Resource wasn't found in the world.
+    #[must_use]
+    pub fn resource_not_present(resource: &str) -> Self {
+        Self {
+            code: error_codes::RESOURCE_NOT_PRESENT,
+            message: format!("Resource `{resource}` not present in the world"),
+            data: None,
+        }
+    }
+
+    /// A [reflection path] couldn't be parsed or doesn't lead to a value.
+    ///
+    /// [reflection path]: bevy_reflect::ReflectPath
+    #[must_use]
+    pub fn invalid_path(path: &str, error: &ReflectPathError) -> Self {
+        let mut data = serde_json::Map::new();
+        data.insert("path".to_owned(), Value::from(path));
+        match error {
+            ReflectPathError::InvalidAccess(access_error) => {
+                if let Some(offset) = access_error.offset() {
+                    data.insert("offset".to_owned(), Value::from(*offset));
+                }
+                data.insert(
+                    "access".to_owned(),
+                    Value::from(access_error.access().to_string()),
+                );
+            }
+            ReflectPathError::ParseError { offset, .. } => {
+                data.insert("offset".to_owned(), Value::from(*offset));
+            }
+            ReflectPathError::InvalidDowncast => {}
+        }
+
+        Self {
+            code: error_codes::INVALID_PATH,
+            message: format!("Invalid path `{path}`: {error}"),
+            data: Some(Value::Object(data)),
+        }
+    }
+
     /// Attempt to reparent an entity to itself.
     #[must_use]
     pub fn self_reparent(entity: Entity) -> Self {
@@ -700,6 +936,15 @@ pub mod error_codes {
 
     /// Cannot reparent an entity to itself.
     pub const SELF_REPARENT: i16 = -23404;
+
+    /// Could not reflect or find resource.
+    pub const RESOURCE_ERROR: i16 = -23405;
+
+    /// Could not find resource in the world.
+    pub const RESOURCE_NOT_PRESENT: i16 = -23406;
+
+    /// Could not parse or follow a reflection path.
+    pub const INVALID_PATH: i16 = -23407;
 }
 
 /// The result of a request.
@@ -0,0 +1,376 @@
+//! Export of the [`TypeRegistry`] as [JSON Schema].
+//!
+//! Each registered type is described by a schema of the JSON values that the reflection
+//! (de)serializers used by the Bevy Remote Protocol produce and accept for it. Schemas refer to
+//! the types of their fields with `$ref`s into the `$defs` of the exported document, keyed by
+//! [full type path].
+//!
+//! In addition to the standard JSON Schema keywords, each schema contains:
+//! - `typePath`, `shortPath`, `crateName` and `modulePath`: The [`TypePath`] of the type.
+//! - `kind`: The [`ReflectKind`] of the type, e.g. `Struct` or `Enum`.
+//! - `reflectTypes`: The known type data registered for the type, e.g. `Component` for
+//!   [`ReflectComponent`] or `Default` for [`ReflectDefault`].
+//! - `generics`: The generic parameters of the type, if any.
+//! - `keyType`: For maps, a reference to the type of the keys.
+//!
+//! Types registered with [`ReflectSerialize`] are (de)serialized with their `serde`
+//! implementation rather than their reflected shape, so their schema accepts any value unless they
+//! are primitive types.
+//!
+//! [JSON Schema]: https://json-schema.org/
+//! [full type path]: bevy_reflect::TypePath::type_path
+//! [`TypePath`]: bevy_reflect::TypePath
+//! [`ReflectKind`]: bevy_reflect::ReflectKind
+
+use bevy_ecs::reflect::{
+    ReflectBundle, ReflectComponent, ReflectFromWorld, ReflectMapEntities, ReflectResource,
+    ReflectVisitEntities, ReflectVisitEntitiesMut,
+};
+use bevy_reflect::{
+    prelude::ReflectDefault, serde::SerializationData, GenericInfo, NamedField, ReflectDeserialize,
+    ReflectFromReflect, ReflectSerialize, Type, TypeInfo, TypeRegistration, TypeRegistry,
+    UnnamedField, VariantInfo,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+/// The version of JSON Schema that exported schemas conform to.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Limits which registered types are exported by [`export_type_registry`].
+///
+/// An empty list doesn't filter anything.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct JsonSchemaFilter {
+    /// Only export types from these crates.
+    #[serde(default)]
+    pub with_crates: Vec<String>,
+
+    /// Don't export types from these crates.
+    #[serde(default)]
+    pub without_crates: Vec<String>,
+
+    /// Only export types that have all of these reflect types, as listed in `reflectTypes`.
+    #[serde(default)]
+    pub with_reflect_types: Vec<String>,
+
+    /// Don't export types that have any of these reflect types, as listed in `reflectTypes`.
+    #[serde(default)]
+    pub without_reflect_types: Vec<String>,
+}
+
+impl JsonSchemaFilter {
+    /// Returns `true` if the type of `registration` should be exported.
+    pub fn matches(&self, registration: &TypeRegistration) -> bool {
+        let crate_name = registration
+            .type_info()
+            .type_path_table()
+            .crate_name()
+            .unwrap_or_default();
+        if !self.with_crates.is_empty() && !self.with_crates.iter().any(|c| c == crate_name) {
+            return false;
+        }
+        if self.without_crates.iter().any(|c| c == crate_name) {
+            return false;
+        }
+
+        let reflect_types = reflect_types(registration);
+        self.with_reflect_types
+            .iter()
+            .all(|name| reflect_types.contains(&name.as_str()))
+            && !self
+                .without_reflect_types
+                .iter()
+                .any(|name| reflect_types.contains(&name.as_str()))
+    }
+}
+
+/// Exports the types of `type_registry` that pass the `filter` as a JSON Schema document, with
+/// one schema per type in its `$defs`.
+pub fn export_type_registry(type_registry: &TypeRegistry, filter: &JsonSchemaFilter) -> Value {
+    let mut defs = Map::new();
+    for registration in type_registry.iter() {
+        if filter.matches(registration) {
+            defs.insert(
+                registration.type_info().type_path().to_owned(),
+                export_type(registration),
+            );
+        }
+    }
+    defs.sort_keys();
+
+    json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "$defs": defs,
+    })
+}
+
+/// Returns the JSON Schema of the type of `registration`.
+pub fn export_type(registration: &TypeRegistration) -> Value {
+    let type_info = registration.type_info();
+    let table = type_info.type_path_table();
+
+    let mut schema = Map::new();
+    schema.insert("typePath".into(), table.path().into());
+    schema.insert("shortPath".into(), table.short_path().into());
+    if let Some(crate_name) = table.crate_name() {
+        schema.insert("crateName".into(), crate_name.into());
+    }
+    if let Some(module_path) = table.module_path() {
+        schema.insert("modulePath".into(), module_path.into());
+    }
+    schema.insert("kind".into(), format!("{:?}", type_info.kind()).into());
+    schema.insert("reflectTypes".into(), reflect_types(registration).into());
+
+    let generics = type_info
+        .generics()
+        .iter()
+        .map(|generic| {
+            let mut info = Map::new();
+            info.insert("name".into(), generic.name().to_string().into());
+            info.insert("type".into(), type_ref(generic.ty()));
+            if let GenericInfo::Const(_) = generic {
+                info.insert("const".into(), true.into());
+            }
+            Value::Object(info)
+        })
+        .collect::<Vec<_>>();
+    if !generics.is_empty() {
+        schema.insert("generics".into(), generics.into());
+    }
+
+    let serialization_data = registration.data::<SerializationData>();
+    let shape = match type_info {
+        _ if registration.contains::<ReflectSerialize>() => opaque_schema(table.path()),
+        TypeInfo::Struct(info) => object_schema(info.iter(), serialization_data),
+        // Tuple structs with a single field are serialized as that field, unless it is skipped.
+        TypeInfo::TupleStruct(info) => match (info.field_at(0), serialization_data) {
+            (Some(field), None) if info.field_len() == 1 => type_ref(field.ty()),
+            _ => tuple_schema(info.iter(), serialization_data),
+        },
+        TypeInfo::Tuple(info) => tuple_schema(info.iter(), None),
+        TypeInfo::List(info) => json!({ "type": "array", "items": type_ref(&info.item_ty()) }),
+        TypeInfo::Set(info) => json!({
+            "type": "array",
+            "items": type_ref(&info.value_ty()),
+            "uniqueItems": true,
+        }),
+        TypeInfo::Array(info) => json!({
+            "type": "array",
+            "items": type_ref(&info.item_ty()),
+            "minItems": info.capacity(),
+            "maxItems": info.capacity(),
+        }),
+        TypeInfo::Map(info) => json!({
+            "type": "object",
+            "additionalProperties": type_ref(&info.value_ty()),
+            "keyType": type_ref(&info.key_ty()),
+        }),
+        TypeInfo::Enum(info) if table.module_path() == Some("core::option") => {
+            // `Option` is serialized as either `null` or its inner value.
+            let some = info
+                .variant("Some")
+                .and_then(|variant| variant.as_tuple_variant().ok())
+                .and_then(|variant| variant.field_at(0));
+            match some {
+                Some(field) => json!({ "oneOf": [{ "type": "null" }, type_ref(field.ty())] }),
+                None => json!({}),
+            }
+        }
+        TypeInfo::Enum(info) => {
+            let variants = info.iter().map(variant_schema).collect::<Vec<_>>();
+            json!({ "oneOf": variants })
+        }
+        TypeInfo::Opaque(_) => opaque_schema(table.path()),
+    };
+    if let Value::Object(shape) = shape {
+        schema.extend(shape);
+    }
+
+    Value::Object(schema)
+}
+
+/// Returns the names of the known type data registered for the type of `registration`.
+pub fn reflect_types(registration: &TypeRegistration) -> Vec<&'static str> {
+    let known = [
+        ("Component", registration.contains::<ReflectComponent>()),
+        ("Resource", registration.contains::<ReflectResource>()),
+        ("Bundle", registration.contains::<ReflectBundle>()),
+        ("Default", registration.contains::<ReflectDefault>()),
+        ("FromWorld", registration.contains::<ReflectFromWorld>()),
+        ("FromReflect", registration.contains::<ReflectFromReflect>()),
+        ("Serialize", registration.contains::<ReflectSerialize>()),
+        ("Deserialize", registration.contains::<ReflectDeserialize>()),
+        ("MapEntities", registration.contains::<ReflectMapEntities>()),
+        (
+            "VisitEntities",
+            registration.contains::<ReflectVisitEntities>(),
+        ),
+        (
+            "VisitEntitiesMut",
+            registration.contains::<ReflectVisitEntitiesMut>(),
+        ),
+    ];
+    known
+        .into_iter()
+        .filter_map(|(name, registered)| registered.then_some(name))
+        .collect()
+}
+
+/// A reference to the schema of `ty` within the exported document.
+fn type_ref(ty: &Type) -> Value {
+    json!({ "$ref": format!("#/$defs/{}", ty.path()) })
+}
+
+/// The schema of a JSON object with the given named fields.
+///
+/// Fields skipped by `serialization_data` are never serialized and are not required, but are
+/// still accepted by the deserializer.
+fn object_schema<'a>(
+    fields: impl Iterator<Item = &'a NamedField>,
+    serialization_data: Option<&SerializationData>,
+) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for (index, field) in fields.enumerate() {
+        properties.insert(field.name().into(), type_ref(field.ty()));
+        if !serialization_data.is_some_and(|data| data.is_field_skipped(index)) {
+            required.push(Value::from(field.name()));
+        }
+    }
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+/// The schema of a JSON array of the given unnamed fields, without those skipped by
+/// `serialization_data`.
+fn tuple_schema<'a>(
+    fields: impl Iterator<Item = &'a UnnamedField>,
+    serialization_data: Option<&SerializationData>,
+) -> Value {
+    let items = fields
+        .filter(|field| {
+            !serialization_data.is_some_and(|data| data.is_field_skipped(field.index()))
+        })
+        .map(|field| type_ref(field.ty()))
+        .collect::<Vec<_>>();
+    let len = items.len();
+    json!({
+        "type": "array",
+        "prefixItems": items,
+        "items": false,
+        "minItems": len,
+        "maxItems": len,
+    })
+}
+
+/// The schema of a single enum variant.
+///
+/// Unit variants are serialized as their name, others as an object associating their name to
+/// their fields, or to their only field for single-field tuple variants.
+fn variant_schema(variant: &VariantInfo) -> Value {
+    let fields = match variant {
+        VariantInfo::Unit(info) => return json!({ "const": info.name() }),
+        VariantInfo::Struct(info) => object_schema(info.iter(), None),
+        VariantInfo::Tuple(info) => match info.field_at(0) {
+            Some(field) if info.field_len() == 1 => type_ref(field.ty()),
+            _ => tuple_schema(info.iter(), None),
+        },
+    };
+    json!({
+        "type": "object",
+        "properties": { variant.name(): fields },
+        "required": [variant.name()],
+        "additionalProperties": false,
+    })
+}
+
+/// The schema of an opaque type, which is only known for primitive types.
+fn opaque_schema(type_path: &str) -> Value {
+    match type_path {
+        "bool" => json!({ "type": "boolean" }),
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => {
+            json!({ "type": "integer", "minimum": 0 })
+        }
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => json!({ "type": "integer" }),
+        "f32" | "f64" => json!({ "type": "number" }),
+        "char" => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        "alloc::string::String"
+        | "&str"
+        | "alloc::borrow::Cow<str>"
+        | "std::path::PathBuf"
+        | "bevy_utils::Cow<str>" => json!({ "type": "string" }),
+        _ => json!({}),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_reflect::{Reflect, TypePath};
+
+    #[derive(Reflect, Default)]
+    struct Settings {
+        volume: f32,
+        #[reflect(skip_serializing)]
+        cache: Vec<u8>,
+    }
+
+    #[derive(Reflect)]
+    struct Meters(f32);
+
+    #[derive(Reflect, Default)]
+    struct Tagged(u32, #[reflect(skip_serializing)] u32);
+
+    #[derive(Reflect, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    #[reflect(Serialize, Deserialize)]
+    struct Custom {
+        inner: u32,
+    }
+
+    fn schema_of<T: bevy_reflect::GetTypeRegistration>() -> Value {
+        let mut registry = TypeRegistry::new();
+        registry.register::<T>();
+        export_type(registry.get(core::any::TypeId::of::<T>()).unwrap())
+    }
+
+    #[test]
+    fn skipped_fields_are_not_required() {
+        let schema = schema_of::<Settings>();
+        assert_eq!(schema["required"], json!(["volume"]));
+        assert!(schema["properties"].get("cache").is_some());
+
+        let schema = schema_of::<Tagged>();
+        assert_eq!(
+            schema["prefixItems"],
+            json!([{ "$ref": format!("#/$defs/{}", u32::type_path()) }])
+        );
+    }
+
+    #[test]
+    fn tuples_and_newtypes() {
+        assert_eq!(
+            schema_of::<Meters>()["$ref"],
+            format!("#/$defs/{}", f32::type_path())
+        );
+
+        let schema = schema_of::<(u32,)>();
+        assert_eq!(schema["type"], "array");
+        assert_eq!(schema["minItems"], 1);
+        assert_eq!(schema["maxItems"], 1);
+    }
+
+    #[test]
+    fn serde_types_have_an_open_schema() {
+        let schema = schema_of::<Custom>();
+        assert_eq!(schema["kind"], "Struct");
+        assert!(schema.get("type").is_none());
+        assert!(schema.get("properties").is_none());
+
+        assert_eq!(schema_of::<u32>()["type"], "integer");
+    }
+}
@@ -0,0 +1,3 @@
+//! Schemas describing the types that can be sent over the Bevy Remote Protocol.
+
+pub mod json_schema;
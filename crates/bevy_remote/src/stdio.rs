@@ -0,0 +1,49 @@
+//! The BRP transport using newline-delimited JSON-RPC over the standard input and output.
+//!
+//! Adding the [`RemoteStdioPlugin`] to your [`App`] causes Bevy to read requests from its
+//! standard input and write responses to its standard output, one JSON value per line. This lets
+//! a parent process, such as an editor or a test harness, drive a headless app without binding
+//! a port.
+//!
+//! The standard input is processed as a single [`BrpSession`]: responses to `+watch` requests are
+//! written every time the watched data changes, using the `id` of the request.
+//!
+//! Nothing else should be written to the standard output while this transport is in use. Note that
+//! the [`LogPlugin`] writes to the standard error.
+//!
+//! [`LogPlugin`]: https://docs.rs/bevy/latest/bevy/log/struct.LogPlugin.html
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{transport::serve_lines, BrpSender};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::system::Res;
+use bevy_tasks::IoTaskPool;
+use blocking::Unblock;
+
+#[cfg(doc)]
+use crate::transport::BrpSession;
+
+/// Add this plugin to your [`App`] to allow a parent process to inspect and modify entities over
+/// the standard input and output. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+#[derive(Default)]
+pub struct RemoteStdioPlugin;
+
+impl Plugin for RemoteStdioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, start_stdio_server);
+    }
+}
+
+/// A system that starts serving the Bevy Remote Protocol over the standard input and output.
+fn start_stdio_server(request_sender: Res<BrpSender>) {
+    IoTaskPool::get()
+        .spawn(serve_lines(
+            Unblock::new(std::io::stdin()),
+            Unblock::new(std::io::stdout()),
+            request_sender.clone(),
+        ))
+        .detach();
+}
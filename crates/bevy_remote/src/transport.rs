@@ -0,0 +1,399 @@
+//! Building blocks shared by the BRP transports.
+//!
+//! A transport is responsible for accepting connections from clients and moving JSON-RPC
+//! messages between them and the [`BrpSender`](crate::BrpSender). Request-response transports
+//! like HTTP handle each request on its own, using [`parse_request`] and
+//! [`send_request`]. Bidirectional transports hold a [`BrpSession`] per client instead, which
+//! processes incoming messages in the background and pushes responses, including every result of
+//! `+watch` requests, back to the client as they become available.
+//!
+//! Transports that exchange newline-delimited JSON over a byte stream, such as the standard
+//! input and output or Unix sockets, can be served entirely by [`serve_lines`].
+
+use crate::{error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult};
+use anyhow::Result as AnyhowResult;
+use async_channel::{Receiver, Sender};
+use bevy_tasks::{
+    futures_lite::{future, AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, StreamExt},
+    IoTaskPool,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+/// The number of results of a `+watch` request that can be buffered before the client reads them.
+///
+/// This is also the number of serialized responses a [`BrpSession`] buffers before it stops
+/// forwarding results until the client catches up.
+const WATCH_CHANNEL_SIZE: usize = 8;
+
+/// Parses and validates a single JSON-RPC request.
+///
+/// If the request is invalid, returns the error response to send back to the client instead.
+pub fn parse_request(request: Value) -> Result<BrpRequest, BrpResponse> {
+    // Reach in and get the request ID early so that we can report it even when parsing fails.
+    let id = request.as_object().and_then(|map| map.get("id")).cloned();
+
+    let request: BrpRequest = serde_json::from_value(request).map_err(|err| {
+        BrpResponse::new(
+            id.clone(),
+            Err(BrpError {
+                code: error_codes::INVALID_REQUEST,
+                message: err.to_string(),
+                data: None,
+            }),
+        )
+    })?;
+
+    if request.jsonrpc != "2.0" {
+        return Err(BrpResponse::new(
+            id,
+            Err(BrpError {
+                code: error_codes::INVALID_REQUEST,
+                message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
+                data: None,
+            }),
+        ));
+    }
+
+    Ok(request)
+}
+
+/// Returns `true` if `method` produces a stream of results rather than a single one.
+pub fn is_watching_method(method: &str) -> bool {
+    method.contains("+watch")
+}
+
+/// Sends `request` to be processed by the app, returning the channel its results are sent on.
+///
+/// Watching requests keep being processed as long as the returned receiver exists.
+pub async fn send_request(
+    request: &BrpRequest,
+    request_sender: &Sender<BrpMessage>,
+) -> Receiver<BrpResult> {
+    let size = if is_watching_method(&request.method) {
+        WATCH_CHANNEL_SIZE
+    } else {
+        1
+    };
+    let (result_sender, result_receiver) = async_channel::bounded(size);
+
+    let _ = request_sender
+        .send(BrpMessage {
+            method: request.method.clone(),
+            params: request.params.clone(),
+            sender: result_sender,
+        })
+        .await;
+
+    result_receiver
+}
+
+/// A bidirectional connection between the app and a single client.
+///
+/// Each message received from the client is handed to [`BrpSession::process_message`], and the
+/// serialized responses are read from the [`Receiver`] returned by [`BrpSession::new`] and sent
+/// to the client. Responses may be sent in a different order than the requests were received.
+///
+/// Unlike with HTTP, `+watch` requests don't tie up the connection: a response is sent each time
+/// the watched data changes, with the `id` of the original request, until the session is dropped.
+/// When the client falls behind, results of `+watch` requests are dropped rather than buffered
+/// without bound.
+#[derive(Debug)]
+pub struct BrpSession {
+    request_sender: Sender<BrpMessage>,
+    responses: Sender<String>,
+    /// Never sent on, this closes when the session is dropped to stop ongoing `+watch` requests.
+    _open: Sender<()>,
+    closed: Receiver<()>,
+}
+
+impl BrpSession {
+    /// Creates a new session sending its requests to `request_sender`, and the receiver of the
+    /// serialized responses to send to the client.
+    pub fn new(request_sender: Sender<BrpMessage>) -> (Self, Receiver<String>) {
+        let (responses, response_receiver) = async_channel::bounded(WATCH_CHANNEL_SIZE);
+        let (open, closed) = async_channel::bounded(1);
+        let session = Self {
+            request_sender,
+            responses,
+            _open: open,
+            closed,
+        };
+        (session, response_receiver)
+    }
+
+    /// Processes a message received from the client, containing either a single request or a
+    /// batch of requests.
+    ///
+    /// The requests are processed in the background; this doesn't wait for their responses.
+    pub fn process_message(&self, message: &str) {
+        let batch: Result<BrpBatch, _> = serde_json::from_str(message);
+        let request_sender = self.request_sender.clone();
+        let responses = self.responses.clone();
+        let closed = self.closed.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                match batch {
+                    Ok(BrpBatch::Single(request)) => {
+                        process_session_request(request, &request_sender, &responses, closed).await;
+                    }
+                    Ok(BrpBatch::Batch(requests)) => {
+                        let batch_responses =
+                            process_session_batch(requests, &request_sender).await;
+                        send_serialized(&responses, &batch_responses).await;
+                    }
+                    Err(err) => {
+                        let response = BrpResponse::new(
+                            None,
+                            Err(BrpError {
+                                code: error_codes::PARSE_ERROR,
+                                message: err.to_string(),
+                                data: None,
+                            }),
+                        );
+                        send_serialized(&responses, &response).await;
+                    }
+                }
+            })
+            .detach();
+    }
+}
+
+/// Processes a single request of a [`BrpSession`], forwarding its results to the client.
+async fn process_session_request(
+    request: Value,
+    request_sender: &Sender<BrpMessage>,
+    responses: &Sender<String>,
+    closed: Receiver<()>,
+) {
+    let request = match parse_request(request) {
+        Ok(request) => request,
+        Err(response) => {
+            send_serialized(responses, &response).await;
+            return;
+        }
+    };
+
+    let results = send_request(&request, request_sender).await;
+    let forward = async {
+        while let Ok(result) = results.recv().await {
+            if !send_serialized(responses, &BrpResponse::new(request.id.clone(), result)).await {
+                break;
+            }
+        }
+    };
+    // Stop forwarding results as soon as the session ends, dropping `results` so that the app
+    // stops processing the request.
+    future::or(forward, async {
+        let _ = closed.recv().await;
+    })
+    .await;
+}
+
+/// Processes a batch of requests of a [`BrpSession`], returning all of their responses at once.
+async fn process_session_batch(
+    requests: Vec<Value>,
+    request_sender: &Sender<BrpMessage>,
+) -> Vec<BrpResponse> {
+    let mut batch_responses = Vec::with_capacity(requests.len());
+    for request in requests {
+        let request = match parse_request(request) {
+            Ok(request) => request,
+            Err(response) => {
+                batch_responses.push(response);
+                continue;
+            }
+        };
+
+        if is_watching_method(&request.method) {
+            batch_responses.push(BrpResponse::new(
+                request.id,
+                Err(BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: "Streaming can not be used in batch requests".to_string(),
+                    data: None,
+                }),
+            ));
+            continue;
+        }
+
+        let results = send_request(&request, request_sender).await;
+        let result = results
+            .recv()
+            .await
+            .unwrap_or_else(|err| Err(BrpError::internal(err)));
+        batch_responses.push(BrpResponse::new(request.id, result));
+    }
+    batch_responses
+}
+
+/// Serializes `value` and sends it on `responses`, waiting for the client to catch up if needed.
+///
+/// Returns `false` if the session has ended.
+async fn send_serialized(responses: &Sender<String>, value: &impl Serialize) -> bool {
+    let serialized = match serde_json::to_string(value) {
+        Ok(serialized) => serialized,
+        Err(err) => {
+            let response = BrpResponse::new(None, Err(BrpError::internal(err)));
+            // Serializing a response with a plain error can't fail.
+            serde_json::to_string(&response).unwrap_or_default()
+        }
+    };
+    responses.send(serialized).await.is_ok()
+}
+
+/// Serves a single client over a byte stream carrying newline-delimited JSON messages.
+///
+/// Each line read from `reader` is processed by a [`BrpSession`], and each response is written to
+/// `writer` on its own line. Returns once `reader` reaches its end or either stream fails.
+pub async fn serve_lines(
+    reader: impl AsyncRead + Unpin,
+    mut writer: impl AsyncWrite + Unpin,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    enum Event {
+        Line(Option<std::io::Result<String>>),
+        Response(Option<String>),
+    }
+
+    let (session, responses) = BrpSession::new(request_sender);
+    let mut lines = bevy_tasks::futures_lite::io::BufReader::new(reader).lines();
+    loop {
+        let event = future::or(async { Event::Line(lines.next().await) }, async {
+            Event::Response(responses.recv().await.ok())
+        })
+        .await;
+
+        match event {
+            Event::Line(Some(line)) => {
+                let line = line?;
+                if !line.trim().is_empty() {
+                    session.process_message(&line);
+                }
+            }
+            Event::Line(None) | Event::Response(None) => return Ok(()),
+            Event::Response(Some(mut response)) => {
+                response.push('\n');
+                writer.write_all(response.as_bytes()).await?;
+                writer.flush().await?;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_tasks::{
+        tick_global_task_pools_on_main_thread, AsyncComputeTaskPool, ComputeTaskPool, TaskPool,
+    };
+    use core::time::Duration;
+    use serde_json::json;
+
+    fn session() -> (BrpSession, Receiver<String>, Receiver<BrpMessage>) {
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        ComputeTaskPool::get_or_init(TaskPool::new);
+        IoTaskPool::get_or_init(TaskPool::new);
+        let (request_sender, request_receiver) = async_channel::unbounded();
+        let (session, responses) = BrpSession::new(request_sender);
+        (session, responses, request_receiver)
+    }
+
+    /// Runs the tasks of the session until `receiver` receives a value.
+    fn recv<T>(receiver: &Receiver<T>) -> T {
+        for _ in 0..1000 {
+            tick_global_task_pools_on_main_thread();
+            if let Ok(value) = receiver.try_recv() {
+                return value;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        panic!("nothing was received");
+    }
+
+    fn response(responses: &Receiver<String>) -> Value {
+        serde_json::from_str(&recv(responses)).unwrap()
+    }
+
+    #[test]
+    fn parse_invalid_requests() {
+        let response = parse_request(json!({ "jsonrpc": "1.0", "id": 3, "method": "a" }));
+        let response = serde_json::to_value(response.unwrap_err()).unwrap();
+        assert_eq!(response["id"], 3);
+        assert_eq!(response["error"]["code"], error_codes::INVALID_REQUEST);
+
+        let response = parse_request(json!({ "jsonrpc": "2.0", "id": 4 }));
+        let response = serde_json::to_value(response.unwrap_err()).unwrap();
+        assert_eq!(response["id"], 4);
+
+        let request = parse_request(json!({ "jsonrpc": "2.0", "id": 5, "method": "a" }));
+        assert_eq!(request.unwrap().method, "a");
+    }
+
+    #[test]
+    fn session_responds_to_requests() {
+        let (session, responses, requests) = session();
+
+        session.process_message("not json");
+        assert_eq!(
+            response(&responses)["error"]["code"],
+            error_codes::PARSE_ERROR
+        );
+
+        session.process_message(r#"{ "jsonrpc": "2.0", "id": 1, "method": "bevy/list" }"#);
+        let message = recv(&requests);
+        assert_eq!(message.method, "bevy/list");
+        message.sender.try_send(Ok(json!(["a"]))).unwrap();
+        let single = response(&responses);
+        assert_eq!(single["id"], 1);
+        assert_eq!(single["result"], json!(["a"]));
+
+        session.process_message(
+            r#"[
+                { "jsonrpc": "2.0", "id": 2, "method": "bevy/list" },
+                { "jsonrpc": "2.0", "id": 3, "method": "bevy/list+watch" }
+            ]"#,
+        );
+        let message = recv(&requests);
+        message.sender.try_send(Ok(json!([]))).unwrap();
+        let batch = response(&responses);
+        assert_eq!(batch[0]["id"], 2);
+        assert_eq!(batch[0]["result"], json!([]));
+        assert_eq!(batch[1]["id"], 3);
+        assert_eq!(batch[1]["error"]["code"], error_codes::INVALID_REQUEST);
+    }
+
+    #[test]
+    fn session_applies_backpressure_to_watching_requests() {
+        let (session, responses, requests) = session();
+
+        session.process_message(r#"{ "jsonrpc": "2.0", "id": 1, "method": "bevy/get+watch" }"#);
+        let message = recv(&requests);
+
+        // The client doesn't read any response, so results stop being accepted once both the
+        // results of the request and the responses of the session are full.
+        let mut sent = 0;
+        for _ in 0..1000 {
+            tick_global_task_pools_on_main_thread();
+            if message.sender.try_send(Ok(json!(sent))).is_ok() {
+                sent += 1;
+            } else {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        }
+        assert!(sent <= 2 * WATCH_CHANNEL_SIZE + 1);
+        assert!(responses.len() <= WATCH_CHANNEL_SIZE);
+        assert_eq!(response(&responses)["result"], 0);
+
+        // Dropping the session stops the request.
+        drop(session);
+        for _ in 0..1000 {
+            tick_global_task_pools_on_main_thread();
+            if message.sender.is_closed() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert!(message.sender.is_closed());
+    }
+}
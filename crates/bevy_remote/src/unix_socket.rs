@@ -0,0 +1,69 @@
+//! The BRP transport using newline-delimited JSON-RPC over a Unix domain socket.
+//!
+//! Adding the [`RemoteUnixSocketPlugin`] to your [`App`] causes Bevy to accept connections on a
+//! Unix socket while your app is running. Clients exchange one JSON value per line, and each
+//! connection is a bidirectional [`BrpSession`]: responses to `+watch` requests are sent every
+//! time the watched data changes, using the `id` of the request, until the connection is closed.
+
+#![cfg(unix)]
+
+use crate::{transport::serve_lines, BrpMessage, BrpSender};
+use anyhow::Result as AnyhowResult;
+use async_channel::Sender;
+use async_io::Async;
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::system::Res;
+use bevy_tasks::IoTaskPool;
+use std::{
+    os::unix::{fs::FileTypeExt, net::UnixListener},
+    path::PathBuf,
+};
+
+#[cfg(doc)]
+use crate::transport::BrpSession;
+
+/// Add this plugin to your [`App`] to allow remote connections over a Unix domain socket to
+/// inspect and modify entities. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// A socket left at the path by a previous run of the app is replaced.
+pub struct RemoteUnixSocketPlugin {
+    /// The path of the socket that Bevy will listen on.
+    path: PathBuf,
+}
+
+impl RemoteUnixSocketPlugin {
+    /// Creates a plugin listening on the socket at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Plugin for RemoteUnixSocketPlugin {
+    fn build(&self, app: &mut App) {
+        let path = self.path.clone();
+        app.add_systems(Startup, move |request_sender: Res<BrpSender>| {
+            IoTaskPool::get()
+                .spawn(server_main(path.clone(), request_sender.clone()))
+                .detach();
+        });
+    }
+}
+
+/// The Bevy Remote Protocol Unix socket server main loop.
+async fn server_main(path: PathBuf, request_sender: Sender<BrpMessage>) -> AnyhowResult<()> {
+    if std::fs::symlink_metadata(&path).is_ok_and(|metadata| metadata.file_type().is_socket()) {
+        std::fs::remove_file(&path)?;
+    }
+
+    let listener = Async::<UnixListener>::bind(&path)?;
+    loop {
+        let (client, _) = listener.accept().await?;
+
+        let request_sender = request_sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = serve_lines(&client, &client, request_sender).await;
+            })
+            .detach();
+    }
+}
@@ -0,0 +1,131 @@
+//! The BRP transport using JSON-RPC over WebSocket.
+//!
+//! Adding the [`RemoteWebSocketPlugin`] to your [`App`] causes Bevy to accept WebSocket
+//! connections (by default, on port 15703) while your app is running.
+//!
+//! Each connection is a bidirectional session: clients send requests or batches of requests as
+//! text messages, and receive each response as a text message. Responses to `+watch` requests are
+//! pushed to the client every time the watched data changes, using the `id` of the request,
+//! until the connection is closed.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{transport::BrpSession, BrpMessage, BrpSender};
+use anyhow::Result as AnyhowResult;
+use async_channel::Sender;
+use async_io::Async;
+use async_tungstenite::tungstenite::Message;
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::system::Res;
+use bevy_tasks::{
+    futures_lite::{future, StreamExt},
+    IoTaskPool,
+};
+use core::net::{IpAddr, Ipv4Addr};
+use std::net::{TcpListener, TcpStream};
+
+/// The default port that Bevy will listen on for WebSocket connections.
+pub const DEFAULT_PORT: u16 = 15703;
+
+/// The default host address that Bevy will use for its WebSocket server.
+pub const DEFAULT_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+
+/// Add this plugin to your [`App`] to allow remote connections over WebSocket to inspect and
+/// modify entities. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+///
+/// The defaults are:
+/// - [`DEFAULT_ADDR`] : 127.0.0.1.
+/// - [`DEFAULT_PORT`] : 15703.
+pub struct RemoteWebSocketPlugin {
+    /// The address that Bevy will bind to.
+    address: IpAddr,
+    /// The port that Bevy will listen on.
+    port: u16,
+}
+
+impl Default for RemoteWebSocketPlugin {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_ADDR,
+            port: DEFAULT_PORT,
+        }
+    }
+}
+
+impl Plugin for RemoteWebSocketPlugin {
+    fn build(&self, app: &mut App) {
+        let (address, port) = (self.address, self.port);
+        app.add_systems(Startup, move |request_sender: Res<BrpSender>| {
+            IoTaskPool::get()
+                .spawn(server_main(address, port, request_sender.clone()))
+                .detach();
+        });
+    }
+}
+
+impl RemoteWebSocketPlugin {
+    /// Set the IP address that the server will use.
+    #[must_use]
+    pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Set the remote port that the server will listen on.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+}
+
+/// The Bevy Remote Protocol WebSocket server main loop.
+async fn server_main(
+    address: IpAddr,
+    port: u16,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    let listener = Async::<TcpListener>::bind((address, port))?;
+    loop {
+        let (client, _) = listener.accept().await?;
+
+        let request_sender = request_sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = handle_client(client, request_sender).await;
+            })
+            .detach();
+    }
+}
+
+async fn handle_client(
+    client: Async<TcpStream>,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    enum Event {
+        Message(Option<Result<Message, async_tungstenite::tungstenite::Error>>),
+        Response(Option<String>),
+    }
+
+    let mut socket = async_tungstenite::accept_async(client).await?;
+    let (session, responses) = BrpSession::new(request_sender);
+    loop {
+        let event = future::or(async { Event::Message(socket.next().await) }, async {
+            Event::Response(responses.recv().await.ok())
+        })
+        .await;
+
+        match event {
+            Event::Message(Some(message)) => match message? {
+                Message::Text(text) => session.process_message(text.as_str()),
+                Message::Binary(bytes) => session.process_message(&String::from_utf8_lossy(&bytes)),
+                Message::Close(_) => return Ok(()),
+                Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {}
+            },
+            Event::Message(None) | Event::Response(None) => return Ok(()),
+            Event::Response(Some(response)) => socket.send(Message::text(response)).await?,
+        }
+    }
+}
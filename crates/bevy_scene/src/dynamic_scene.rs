@@ -1,4 +1,4 @@
-use crate::{ron, DynamicSceneBuilder, Scene, SceneSpawnError};
+use crate::{ron, DynamicPrefab, DynamicSceneBuilder, Scene, SceneSpawnError};
 use bevy_asset::Asset;
 use bevy_ecs::reflect::ReflectResource;
 use bevy_ecs::{
@@ -26,6 +26,9 @@ pub struct DynamicScene {
     pub resources: Vec<Box<dyn PartialReflect>>,
     /// Entities contained in the dynamic scene.
     pub entities: Vec<DynamicEntity>,
+    /// Other dynamic scenes nested in this scene, spawned along with it by the
+    /// [`SceneSpawner`](crate::SceneSpawner).
+    pub prefabs: Vec<DynamicPrefab>,
 }
 
 /// A reflection-powered serializable representation of an entity and its components.
@@ -58,6 +61,9 @@ impl DynamicScene {
     /// This method will return a [`SceneSpawnError`] if a type either is not registered
     /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
     /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait.
+    ///
+    /// The [`prefabs`](Self::prefabs) of the scene are not written, they are spawned by the
+    /// [`SceneSpawner`](crate::SceneSpawner).
     pub fn write_to_world_with(
         &self,
         world: &mut World,
@@ -181,6 +187,18 @@ impl DynamicScene {
     pub fn serialize(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
         serialize_ron(SceneSerializer::new(self, registry))
     }
+
+    /// Serialize this dynamic scene into the readable variant of the Bevy scene format, meant for
+    /// scene files that are edited by hand and kept under version control.
+    ///
+    /// Entities are keyed by their name, default fields are omitted and the output is sorted, see
+    /// [`SceneSerializer::readable`]. The scene is loaded by the [`SceneLoader`] like any other scene.
+    ///
+    /// [`SceneLoader`]: crate::SceneLoader
+    #[cfg(feature = "serialize")]
+    pub fn serialize_readable(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
+        serialize_ron(SceneSerializer::readable(self, registry))
+    }
 }
 
 /// Serialize a given Rust data structure into rust object notation (ron).
@@ -209,6 +209,7 @@ impl<'w> DynamicSceneBuilder<'w> {
         DynamicScene {
             resources: self.extracted_resources.into_values().collect(),
             entities: self.extracted_scene.into_values().collect(),
+            prefabs: Vec::new(),
         }
     }
 
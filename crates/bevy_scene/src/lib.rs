@@ -17,9 +17,13 @@ mod bundle;
 mod components;
 mod dynamic_scene;
 mod dynamic_scene_builder;
+mod prefab;
 mod scene;
 mod scene_filter;
 mod scene_loader;
+mod scene_patch;
+#[cfg(feature = "serialize")]
+mod scene_saver;
 mod scene_spawner;
 
 #[cfg(feature = "serialize")]
@@ -28,14 +32,18 @@ pub mod serde;
 /// Rusty Object Notation, a crate used to serialize and deserialize bevy scenes.
 pub use bevy_asset::ron;
 
-use bevy_ecs::schedule::IntoSystemConfigs;
+use bevy_ecs::{schedule::IntoSystemConfigs, world::FromWorld};
 pub use bundle::*;
 pub use components::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
+pub use prefab::*;
 pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
+pub use scene_patch::*;
+#[cfg(feature = "serialize")]
+pub use scene_saver::*;
 pub use scene_spawner::*;
 
 /// The scene prelude.
@@ -63,11 +71,17 @@ impl Plugin for ScenePlugin {
         app.init_asset::<DynamicScene>()
             .init_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
+            .init_asset_loader::<BinarySceneLoader>()
             .init_resource::<SceneSpawner>()
             .register_type::<SceneRoot>()
             .register_type::<DynamicSceneRoot>()
             .add_systems(SpawnScene, (scene_spawner, scene_spawner_system).chain());
 
+        let binary_scene_saver = BinarySceneSaver::from_world(app.world_mut());
+        app.register_asset_processor(BinarySceneProcessor::from(binary_scene_saver));
+        let binary_scene_saver = BinarySceneSaver::from_world(app.world_mut());
+        app.register_asset_saver(binary_scene_saver);
+
         // Register component hooks for DynamicSceneRoot
         app.world_mut()
             .register_component_hooks::<DynamicSceneRoot>()
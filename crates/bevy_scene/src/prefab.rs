@@ -0,0 +1,173 @@
+use crate::{DynamicScene, SceneSpawnError};
+use bevy_asset::{AssetPath, Handle};
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap},
+    reflect::ReflectComponent,
+    world::World,
+};
+use bevy_reflect::{reflect_replace, GetPath, PartialReflect, TypePath, TypeRegistry};
+use bevy_utils::tracing::warn;
+
+/// A reference from a [`DynamicScene`] to another dynamic scene, the prefab, which is spawned as
+/// part of every instance of the scene.
+///
+/// Instances of the scene keep track of the prefab: when it is modified, for example after being
+/// hot reloaded, every spawned instance of the prefab is updated and its [`overrides`](Self::overrides)
+/// are applied again.
+pub struct DynamicPrefab {
+    /// The prefab scene to spawn.
+    ///
+    /// When the scene is deserialized this is a default handle, which is replaced by the
+    /// [`SceneLoader`](crate::SceneLoader) with a handle loaded from [`path`](Self::path).
+    pub scene: Handle<DynamicScene>,
+    /// The asset path of the prefab scene, used to serialize this reference.
+    ///
+    /// Defaults to the path of [`scene`](Self::scene).
+    pub path: Option<AssetPath<'static>>,
+    /// The entity of the scene that the root entities of the prefab are added as children to.
+    ///
+    /// If `None`, the root entities of the prefab are spawned without a parent.
+    pub parent: Option<Entity>,
+    /// The changes made to the entities of this instance of the prefab.
+    pub overrides: Vec<PrefabOverride>,
+}
+
+impl DynamicPrefab {
+    /// Creates a reference to the prefab `scene`, without any override.
+    pub fn new(scene: Handle<DynamicScene>) -> Self {
+        Self {
+            path: scene.path().cloned(),
+            scene,
+            parent: None,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Sets the entity of the scene that the root entities of the prefab are added as children to.
+    pub fn with_parent(mut self, parent: Entity) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Adds an override to this instance of the prefab.
+    pub fn with_override(mut self, prefab_override: PrefabOverride) -> Self {
+        self.overrides.push(prefab_override);
+        self
+    }
+}
+
+/// A change made to a component of an entity of a prefab instance, applied every time the prefab
+/// is spawned or updated.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::entity::Entity;
+/// # use bevy_scene::PrefabOverride;
+/// # use bevy_transform::components::Transform;
+/// // Moves the entity `0` of the prefab along the x axis, keeping the rest of its transform.
+/// let prefab_override =
+///     PrefabOverride::new::<Transform>(Entity::from_raw(0), ".translation.x", Box::new(3.0f32));
+/// ```
+#[derive(Debug)]
+pub struct PrefabOverride {
+    /// The entity of the prefab scene the override applies to.
+    pub entity: Entity,
+    /// The type path of the overridden component.
+    pub component: String,
+    /// The [reflection path](bevy_reflect::ReflectPath) of the overridden value within the
+    /// component, or an empty string to override the whole component.
+    pub path: String,
+    /// The value to apply.
+    pub value: Box<dyn PartialReflect>,
+}
+
+impl Clone for PrefabOverride {
+    fn clone(&self) -> Self {
+        Self {
+            entity: self.entity,
+            component: self.component.clone(),
+            path: self.path.clone(),
+            value: self.value.clone_value(),
+        }
+    }
+}
+
+impl PrefabOverride {
+    /// Creates an override of the value at `path` in the component `C` of the prefab `entity`.
+    pub fn new<C: TypePath>(
+        entity: Entity,
+        path: impl Into<String>,
+        value: Box<dyn PartialReflect>,
+    ) -> Self {
+        Self {
+            entity,
+            component: C::type_path().to_string(),
+            path: path.into(),
+            value,
+        }
+    }
+
+    /// Returns `true` if this override replaces the same value as `other`.
+    pub fn overrides_same_value(&self, other: &PrefabOverride) -> bool {
+        self.entity == other.entity && self.component == other.component && self.path == other.path
+    }
+
+    /// Applies the override to the world entity that the prefab entity is mapped to in `entity_map`.
+    ///
+    /// Overrides of entities, components or values that are no longer part of the prefab, or of
+    /// components that are no longer registered, are skipped with a warning, so that editing a
+    /// prefab never prevents its instances from spawning.
+    pub fn apply(
+        &self,
+        world: &mut World,
+        entity_map: &EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        let Some(reflect_component) = type_registry
+            .get_with_type_path(&self.component)
+            .and_then(|registration| registration.data::<ReflectComponent>())
+        else {
+            warn!(
+                "Skipping override of `{}` on entity {}, which is not a registered component",
+                self.component, self.entity
+            );
+            return Ok(());
+        };
+
+        let Some(mut entity) = entity_map
+            .get(&self.entity)
+            .and_then(|&entity| world.get_entity_mut(entity).ok())
+        else {
+            warn!(
+                "Skipping override of `{}` on entity {}, which is not part of the prefab",
+                self.component, self.entity
+            );
+            return Ok(());
+        };
+
+        if self.path.is_empty() {
+            reflect_component.apply_or_insert(&mut entity, self.value.as_ref(), type_registry);
+            return Ok(());
+        }
+
+        let Some(mut component) = reflect_component.reflect_mut(&mut entity) else {
+            warn!(
+                "Skipping override of `{}` on entity {}, which does not have this component",
+                self.component, self.entity
+            );
+            return Ok(());
+        };
+        let result = match component.reflect_path_mut(self.path.as_str()) {
+            Ok(value) => reflect_replace(value, self.value.as_ref()).map_err(|err| err.to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+        if let Err(err) = result {
+            warn!(
+                "Skipping override of `{}{}` on entity {}: {}",
+                self.component, self.path, self.entity, err
+            );
+        }
+        Ok(())
+    }
+}
@@ -38,8 +38,35 @@ pub enum SceneLoaderError {
     /// A [RON Error](ron::error::SpannedError)
     #[display("Could not parse RON: {_0}")]
     RonSpannedError(ron::error::SpannedError),
+    /// A [binary scene](BinarySceneLoader) error
+    #[cfg(feature = "serialize")]
+    #[display("Could not parse binary scene: {_0}")]
+    Postcard(postcard::Error),
+    /// The file doesn't start with the header of [binary scenes](BinarySceneLoader).
+    #[display("Not a binary scene")]
+    #[from(ignore)]
+    NotABinaryScene,
+    /// The binary scene was written with another version of the format.
+    #[display("Binary scene format version {version} is not supported, expected version {BINARY_SCENE_VERSION}")]
+    UnsupportedBinarySceneVersion {
+        /// The version of the format of the binary scene.
+        version: u8,
+    },
+    /// The binary scene has bytes left after the scene.
+    #[display("The binary scene has {_0} unexpected trailing bytes")]
+    #[error(ignore)]
+    #[from(ignore)]
+    TrailingBytes(usize),
 }
 
+/// The bytes at the start of every [binary scene](BinarySceneLoader), followed by
+/// [`BINARY_SCENE_VERSION`].
+pub const BINARY_SCENE_MAGIC: [u8; 4] = *b"BSCN";
+
+/// The version of the [binary scene](BinarySceneLoader) format, increased when the format
+/// changes in an incompatible way.
+pub const BINARY_SCENE_VERSION: u8 = 2;
+
 #[cfg(feature = "serialize")]
 impl AssetLoader for SceneLoader {
     type Asset = DynamicScene;
@@ -50,7 +77,7 @@ impl AssetLoader for SceneLoader {
         &self,
         reader: &mut dyn Reader,
         _settings: &(),
-        _load_context: &mut LoadContext<'_>,
+        load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
@@ -58,12 +85,91 @@ impl AssetLoader for SceneLoader {
         let scene_deserializer = SceneDeserializer {
             type_registry: &self.type_registry.read(),
         };
-        Ok(scene_deserializer
+        let mut scene = scene_deserializer
             .deserialize(&mut deserializer)
-            .map_err(|e| deserializer.span_error(e))?)
+            .map_err(|e| deserializer.span_error(e))?;
+        load_prefabs(&mut scene, load_context);
+        Ok(scene)
     }
 
     fn extensions(&self) -> &[&str] {
         &["scn", "scn.ron"]
     }
 }
+
+/// Asset loader for a Bevy dynamic scene in the binary format (`.scnb`), which is much faster to
+/// load than the RON format.
+///
+/// Binary scenes start with [`BINARY_SCENE_MAGIC`] and [`BINARY_SCENE_VERSION`], followed by the
+/// scene serialized with [`SceneSerializer`](crate::serde::SceneSerializer) in the
+/// [postcard](https://crates.io/crates/postcard) format. Unlike RON, the format doesn't describe
+/// itself: scenes must be loaded with the same types as they were saved with. The list of
+/// prefabs is always written, even if it is empty, so that truncated scenes fail to load.
+///
+/// Binary scenes are written by the [`BinarySceneSaver`](crate::BinarySceneSaver), usually
+/// with the [`BinarySceneProcessor`](crate::BinarySceneProcessor) to convert RON scenes for shipping.
+#[derive(Debug)]
+pub struct BinarySceneLoader {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneLoader {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneLoader {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl AssetLoader for BinarySceneLoader {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type Error = SceneLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let bytes = bytes
+            .strip_prefix(&BINARY_SCENE_MAGIC)
+            .ok_or(SceneLoaderError::NotABinaryScene)?;
+        let (&version, bytes) = bytes
+            .split_first()
+            .ok_or(SceneLoaderError::NotABinaryScene)?;
+        if version != BINARY_SCENE_VERSION {
+            return Err(SceneLoaderError::UnsupportedBinarySceneVersion { version });
+        }
+
+        let mut deserializer = postcard::Deserializer::from_bytes(bytes);
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &self.type_registry.read(),
+        };
+        let mut scene = scene_deserializer.deserialize_with_prefabs(&mut deserializer)?;
+        let trailing = deserializer.finalize()?;
+        if !trailing.is_empty() {
+            return Err(SceneLoaderError::TrailingBytes(trailing.len()));
+        }
+        load_prefabs(&mut scene, load_context);
+        Ok(scene)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["scnb"]
+    }
+}
+
+/// Loads the scenes of the prefabs of a deserialized `scene` from their path.
+#[cfg(feature = "serialize")]
+fn load_prefabs(scene: &mut DynamicScene, load_context: &mut LoadContext) {
+    for prefab in &mut scene.prefabs {
+        if let Some(path) = &prefab.path {
+            prefab.scene = load_context.load(path.clone());
+        }
+    }
+}
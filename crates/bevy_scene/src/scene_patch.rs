@@ -0,0 +1,400 @@
+use crate::{DynamicEntity, DynamicScene, PrefabOverride, SceneSpawnError};
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap, EntityHashSet, SceneEntityMapper},
+    reflect::{ReflectComponent, ReflectMapEntities},
+    world::World,
+};
+use bevy_reflect::{PartialReflect, ReflectDiff, TypeInfo, TypeRegistry};
+use core::fmt;
+
+/// The difference between two [`DynamicScene`]s, which turns the first scene into the second one
+/// when applied.
+///
+/// Entities are matched by their identifier in the scenes, and components by their type. Changes
+/// to the fields of a component are described down to the values that differ, so that patches
+/// applied to a world only touch what was edited. A patch can be displayed to review it.
+///
+/// Swapping the scenes given to [`diff`](Self::diff) gives the patch that undoes the changes.
+/// To compare a scene with an instance of it that was edited in the world, see
+/// [`SceneSpawner::extract_instance`](crate::SceneSpawner::extract_instance).
+///
+/// Resources and [`prefabs`](DynamicScene::prefabs) are not compared.
+#[derive(Debug, Default)]
+pub struct ScenePatch {
+    /// Entities of the second scene that are not in the first one.
+    pub added_entities: Vec<Entity>,
+    /// Entities of the first scene that are not in the second one.
+    pub removed_entities: Vec<Entity>,
+    /// Components inserted or replaced as a whole, including the components of added entities.
+    ///
+    /// Components that reference entities are always replaced as a whole, so that the references
+    /// can be mapped to the entities of the world.
+    pub inserted_components: Vec<(Entity, Box<dyn PartialReflect>)>,
+    /// Type paths of the components removed from entities present in both scenes.
+    pub removed_components: Vec<(Entity, String)>,
+    /// Values changed within components present in both scenes, identified by their
+    /// [reflection path](bevy_reflect::ReflectPath).
+    pub changed_values: Vec<PrefabOverride>,
+}
+
+impl Clone for ScenePatch {
+    fn clone(&self) -> Self {
+        Self {
+            added_entities: self.added_entities.clone(),
+            removed_entities: self.removed_entities.clone(),
+            inserted_components: self
+                .inserted_components
+                .iter()
+                .map(|(entity, component)| (*entity, component.clone_value()))
+                .collect(),
+            removed_components: self.removed_components.clone(),
+            changed_values: self.changed_values.clone(),
+        }
+    }
+}
+
+impl ScenePatch {
+    /// Computes the patch turning the scene `from` into the scene `to`.
+    ///
+    /// Components are compared with [`ReflectDiff`].
+    pub fn diff(from: &DynamicScene, to: &DynamicScene, type_registry: &TypeRegistry) -> Self {
+        let from_entities = from
+            .entities
+            .iter()
+            .map(|entity| (entity.entity, entity))
+            .collect::<EntityHashMap<&DynamicEntity>>();
+        let to_entities = to
+            .entities
+            .iter()
+            .map(|entity| entity.entity)
+            .collect::<EntityHashSet>();
+
+        let mut patch = ScenePatch {
+            removed_entities: from
+                .entities
+                .iter()
+                .map(|entity| entity.entity)
+                .filter(|entity| !to_entities.contains(entity))
+                .collect(),
+            ..Default::default()
+        };
+
+        for to_entity in &to.entities {
+            let entity = to_entity.entity;
+            let Some(from_entity) = from_entities.get(&entity) else {
+                patch.added_entities.push(entity);
+                patch.inserted_components.extend(
+                    to_entity
+                        .components
+                        .iter()
+                        .map(|component| (entity, component.clone_value())),
+                );
+                continue;
+            };
+
+            for from_component in &from_entity.components {
+                let type_path = component_type_path(from_component.as_ref());
+                if !to_entity
+                    .components
+                    .iter()
+                    .any(|component| component_type_path(component.as_ref()) == type_path)
+                {
+                    patch
+                        .removed_components
+                        .push((entity, type_path.to_string()));
+                }
+            }
+
+            for to_component in &to_entity.components {
+                let type_path = component_type_path(to_component.as_ref());
+                let from_component = from_entity
+                    .components
+                    .iter()
+                    .find(|component| component_type_path(component.as_ref()) == type_path);
+                let maps_entities =
+                    type_registry
+                        .get_with_type_path(type_path)
+                        .is_some_and(|registration| {
+                            registration.data::<ReflectMapEntities>().is_some()
+                        });
+
+                match from_component {
+                    Some(from_component) if !maps_entities => {
+                        let diff = ReflectDiff::new(from_component.as_ref(), to_component.as_ref());
+                        for change in diff.changes {
+                            if change.path.is_empty() {
+                                patch.inserted_components.push((entity, change.new));
+                            } else {
+                                patch.changed_values.push(PrefabOverride {
+                                    entity,
+                                    component: type_path.to_string(),
+                                    path: change.path,
+                                    value: change.new,
+                                });
+                            }
+                        }
+                    }
+                    Some(from_component)
+                        if from_component.reflect_partial_eq(to_component.as_ref())
+                            == Some(true) => {}
+                    _ => patch
+                        .inserted_components
+                        .push((entity, to_component.clone_value())),
+                }
+            }
+        }
+
+        patch
+    }
+
+    /// Returns `true` if the patch does not change anything.
+    pub fn is_empty(&self) -> bool {
+        self.added_entities.is_empty()
+            && self.removed_entities.is_empty()
+            && self.inserted_components.is_empty()
+            && self.removed_components.is_empty()
+            && self.changed_values.is_empty()
+    }
+
+    /// Applies the patch to the world entities that the scene entities are mapped to in `entity_map`.
+    ///
+    /// Added entities are spawned and inserted in `entity_map`, removed entities are despawned
+    /// and removed from it. Entity references in inserted components are mapped like when
+    /// [writing a scene to a world](DynamicScene::write_to_world_with).
+    ///
+    /// Changes to entities that are missing from the world are skipped with a warning, see
+    /// [`PrefabOverride::apply`].
+    pub fn apply(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        for &entity in &self.added_entities {
+            entity_map
+                .entry(entity)
+                .or_insert_with(|| world.spawn_empty().id());
+        }
+
+        for (entity, component) in &self.inserted_components {
+            let Some(&entity) = entity_map.get(entity) else {
+                continue;
+            };
+            let mut component = component.clone_value();
+            let type_info = component.get_represented_type_info().ok_or_else(|| {
+                SceneSpawnError::NoRepresentedType {
+                    type_path: component.reflect_type_path().to_string(),
+                }
+            })?;
+            let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
+                SceneSpawnError::UnregisteredButReflectedType {
+                    type_path: type_info.type_path().to_string(),
+                }
+            })?;
+            let reflect_component = registration.data::<ReflectComponent>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredComponent {
+                    type_path: type_info.type_path().to_string(),
+                }
+            })?;
+
+            if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
+                    map_entities.map_entities(component.as_partial_reflect_mut(), mapper);
+                });
+            }
+
+            if let Ok(mut entity) = world.get_entity_mut(entity) {
+                reflect_component.apply_or_insert(
+                    &mut entity,
+                    component.as_partial_reflect(),
+                    type_registry,
+                );
+            }
+        }
+
+        for change in &self.changed_values {
+            change.apply(world, entity_map, type_registry)?;
+        }
+
+        for (entity, type_path) in &self.removed_components {
+            let reflect_component = type_registry
+                .get_with_type_path(type_path)
+                .and_then(|registration| registration.data::<ReflectComponent>())
+                .ok_or_else(|| SceneSpawnError::UnregisteredComponent {
+                    type_path: type_path.clone(),
+                })?;
+            if let Some(mut entity) = entity_map
+                .get(entity)
+                .and_then(|&entity| world.get_entity_mut(entity).ok())
+            {
+                reflect_component.remove(&mut entity);
+            }
+        }
+
+        for entity in &self.removed_entities {
+            if let Some(entity) = entity_map.remove(entity) {
+                world.despawn(entity);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ScenePatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entity in &self.added_entities {
+            writeln!(f, "+ {entity}")?;
+        }
+        for entity in &self.removed_entities {
+            writeln!(f, "- {entity}")?;
+        }
+        for (entity, component) in &self.inserted_components {
+            writeln!(f, "+ {entity} {component:?}")?;
+        }
+        for (entity, type_path) in &self.removed_components {
+            writeln!(f, "- {entity} {type_path}")?;
+        }
+        for change in &self.changed_values {
+            writeln!(
+                f,
+                "~ {} {}{}: {:?}",
+                change.entity, change.component, change.path, change.value
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn component_type_path(component: &dyn PartialReflect) -> &str {
+    component
+        .get_represented_type_info()
+        .map(TypeInfo::type_path)
+        .unwrap_or_else(|| component.reflect_type_path())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{component::Component, reflect::AppTypeRegistry};
+    use bevy_reflect::{Reflect, TypePath};
+
+    #[derive(Component, Reflect, Default, Clone, PartialEq, Debug)]
+    #[reflect(Component, PartialEq)]
+    struct Stats {
+        health: u32,
+        speed: f32,
+        tags: Vec<String>,
+    }
+
+    #[derive(Component, Reflect, Default, Clone, PartialEq, Debug)]
+    #[reflect(Component, PartialEq)]
+    struct Marker;
+
+    fn world() -> World {
+        let mut world = World::new();
+        let type_registry = AppTypeRegistry::default();
+        {
+            let mut type_registry = type_registry.write();
+            type_registry.register::<Stats>();
+            type_registry.register::<Marker>();
+        }
+        world.insert_resource(type_registry);
+        world
+    }
+
+    #[test]
+    fn diff_and_apply_scenes() {
+        let mut world = world();
+        let stats = Stats {
+            health: 10,
+            speed: 1.0,
+            tags: vec!["enemy".to_string()],
+        };
+        let a = world.spawn((stats.clone(), Marker)).id();
+        let b = world.spawn(Marker).id();
+        let from = DynamicScene::from_world(&world);
+
+        let mut edited = stats.clone();
+        edited.health = 5;
+        edited.tags[0] = "boss".to_string();
+        world
+            .entity_mut(a)
+            .insert(edited.clone())
+            .remove::<Marker>();
+        world.despawn(b);
+        let c = world.spawn(stats.clone()).id();
+        let to = DynamicScene::from_world(&world);
+
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        let patch = ScenePatch::diff(&from, &to, &type_registry);
+        assert_eq!(patch.added_entities, vec![c]);
+        assert_eq!(patch.removed_entities, vec![b]);
+        assert_eq!(patch.inserted_components.len(), 1);
+        assert_eq!(
+            patch.removed_components,
+            vec![(a, Marker::type_path().to_string())]
+        );
+        let paths = patch
+            .changed_values
+            .iter()
+            .map(|change| change.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, [".health", ".tags[0]"]);
+
+        let (mut target, mut entity_map) = world_from(&from);
+        let removed = entity_map[&b];
+        patch
+            .apply(&mut target, &mut entity_map, &type_registry)
+            .unwrap();
+
+        assert_eq!(target.get::<Stats>(entity_map[&a]), Some(&edited));
+        assert!(target.get::<Marker>(entity_map[&a]).is_none());
+        assert!(target.get_entity(removed).is_err());
+        assert_eq!(target.get::<Stats>(entity_map[&c]), Some(&stats));
+
+        let undo = ScenePatch::diff(&to, &from, &type_registry);
+        undo.apply(&mut target, &mut entity_map, &type_registry)
+            .unwrap();
+        assert_eq!(target.get::<Stats>(entity_map[&a]), Some(&stats));
+        assert!(target.get::<Marker>(entity_map[&a]).is_some());
+        assert!(!entity_map.contains_key(&c));
+        assert!(ScenePatch::diff(&from, &from, &type_registry).is_empty());
+    }
+
+    #[test]
+    fn apply_replaces_shorter_lists() {
+        let mut world = world();
+        let stats = Stats {
+            tags: vec!["enemy".to_string(), "boss".to_string()],
+            ..Default::default()
+        };
+        let a = world.spawn(stats.clone()).id();
+        let from = DynamicScene::from_world(&world);
+        world.get_mut::<Stats>(a).unwrap().tags.pop();
+        let to = DynamicScene::from_world(&world);
+
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        let patch = ScenePatch::diff(&from, &to, &type_registry);
+        assert_eq!(patch.changed_values.len(), 1);
+        assert_eq!(patch.changed_values[0].path, ".tags");
+
+        let (mut target, mut entity_map) = world_from(&from);
+        patch
+            .apply(&mut target, &mut entity_map, &type_registry)
+            .unwrap();
+        assert_eq!(
+            target.get::<Stats>(entity_map[&a]).unwrap().tags,
+            ["enemy".to_string()]
+        );
+    }
+
+    fn world_from(scene: &DynamicScene) -> (World, EntityHashMap<Entity>) {
+        let mut target = world();
+        let mut entity_map = EntityHashMap::default();
+        scene.write_to_world(&mut target, &mut entity_map).unwrap();
+        (target, entity_map)
+    }
+}
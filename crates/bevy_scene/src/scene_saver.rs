@@ -0,0 +1,329 @@
+use crate::{
+    serde::{BinarySceneSerializer, SceneSerializer},
+    BinarySceneLoader, DynamicScene, SceneLoader, BINARY_SCENE_MAGIC, BINARY_SCENE_VERSION,
+};
+use bevy_asset::{
+    io::Writer,
+    processor::LoadTransformAndSave,
+    saver::{AssetSaver, SavedAsset},
+    transformer::IdentityAssetTransformer,
+    AsyncWriteExt,
+};
+use bevy_ecs::{
+    reflect::AppTypeRegistry,
+    world::{FromWorld, World},
+};
+use bevy_reflect::TypeRegistryArc;
+use derive_more::derive::{Display, Error, From};
+
+/// Asset saver writing dynamic scenes in the binary format read by the [`BinarySceneLoader`].
+///
+/// The type registry must contain all of the types of the saved scenes.
+///
+/// The [`ScenePlugin`](crate::ScenePlugin) registers it to save dynamic scenes with
+/// [`AssetServer::save`](bevy_asset::AssetServer::save).
+#[derive(Debug)]
+pub struct BinarySceneSaver {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneSaver {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+/// Possible errors that can be produced by [`BinarySceneSaver`]
+#[non_exhaustive]
+#[derive(Debug, Error, Display, From)]
+pub enum BinarySceneSaverError {
+    /// An [IO Error](std::io::Error)
+    #[display("Error while trying to write the scene file: {_0}")]
+    Io(std::io::Error),
+    /// A [postcard Error](postcard::Error)
+    #[display("Could not serialize the scene: {_0}")]
+    Postcard(postcard::Error),
+}
+
+impl AssetSaver for BinarySceneSaver {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type OutputLoader = BinarySceneLoader;
+    type Error = BinarySceneSaverError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Self::Asset>,
+        _settings: &(),
+    ) -> Result<(), Self::Error> {
+        let mut bytes = BINARY_SCENE_MAGIC.to_vec();
+        bytes.push(BINARY_SCENE_VERSION);
+        let bytes = postcard::to_extend(
+            &BinarySceneSerializer(SceneSerializer::new(&asset, &self.type_registry.read())),
+            bytes,
+        )?;
+        writer.write_all(&bytes).await?;
+        Ok(())
+    }
+}
+
+/// Asset processor converting scenes loaded by the [`SceneLoader`] to the binary format, so that
+/// they load faster when shipped.
+///
+/// The processor is registered by the [`ScenePlugin`](crate::ScenePlugin), and can be enabled
+/// for all RON scenes with
+/// [`set_default_asset_processor`](bevy_asset::AssetApp::set_default_asset_processor):
+///
+/// ```no_run
+/// # use bevy_app::App;
+/// # use bevy_asset::AssetApp;
+/// # use bevy_scene::BinarySceneProcessor;
+/// # let mut app = App::new();
+/// app.set_default_asset_processor::<BinarySceneProcessor>("scn.ron");
+/// ```
+pub type BinarySceneProcessor =
+    LoadTransformAndSave<SceneLoader, IdentityAssetTransformer<DynamicScene>, BinarySceneSaver>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScenePlugin;
+    use bevy_app::App;
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader, MemoryAssetWriter},
+            AssetReader, AssetSource, AssetSourceId,
+        },
+        processor::AssetProcessor,
+        AssetApp, AssetMode, AssetPlugin, AssetServer, Assets, AsyncReadExt, ErasedLoadedAsset,
+        Handle, LoadState, LoadedAsset,
+    };
+    use bevy_core::TaskPoolPlugin;
+    use bevy_ecs::{component::Component, reflect::ReflectComponent};
+    use bevy_reflect::Reflect;
+    use std::path::Path;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    fn load(app: &mut App, path: &'static str) -> Handle<DynamicScene> {
+        let handle = app.world().resource::<AssetServer>().load(path);
+        for _ in 0..1000 {
+            app.update();
+            match app.world().resource::<AssetServer>().load_state(&handle) {
+                LoadState::Loading | LoadState::NotLoaded => {
+                    std::thread::sleep(core::time::Duration::from_millis(1));
+                }
+                _ => break,
+            }
+        }
+        handle
+    }
+
+    #[test]
+    fn save_and_load_binary_scene() {
+        let mut world = World::new();
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Health>();
+        world.insert_resource(type_registry.clone());
+        world.spawn(Health(3));
+
+        let asset = ErasedLoadedAsset::from(LoadedAsset::from(DynamicScene::from_world(&world)));
+        let saver = BinarySceneSaver::from_world(&mut world);
+        let mut bytes = Vec::new();
+        bevy_tasks::block_on(saver.save(&mut bytes, SavedAsset::from_loaded(&asset).unwrap(), &()))
+            .unwrap();
+        assert!(bytes.starts_with(&BINARY_SCENE_MAGIC));
+
+        // a scene cut off before its empty list of prefabs, and a scene followed by other bytes
+        let truncated = bytes[..bytes.len() - 1].to_vec();
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+
+        let dir = Dir::default();
+        dir.insert_asset(Path::new("level.scnb"), bytes);
+        dir.insert_asset(Path::new("invalid.scnb"), b"BSCN\x00".to_vec());
+        dir.insert_asset(Path::new("truncated.scnb"), truncated);
+        dir.insert_asset(Path::new("trailing.scnb"), trailing);
+
+        let mut app = App::new();
+        app.insert_resource(type_registry)
+            .register_asset_source(
+                AssetSourceId::Default,
+                AssetSource::build()
+                    .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+            )
+            .add_plugins((
+                TaskPoolPlugin::default(),
+                AssetPlugin::default(),
+                ScenePlugin,
+            ));
+
+        let level = load(&mut app, "level.scnb");
+        let scene = app
+            .world()
+            .resource::<Assets<DynamicScene>>()
+            .get(&level)
+            .expect("binary scene should be loaded");
+        let [entity] = scene.entities.as_slice() else {
+            panic!("expected a single entity");
+        };
+        assert!(entity.components[0]
+            .try_downcast_ref::<Health>()
+            .is_some_and(|health| health.0 == 3));
+
+        for path in ["invalid.scnb", "truncated.scnb", "trailing.scnb"] {
+            let invalid = load(&mut app, path);
+            let state = app.world().resource::<AssetServer>().load_state(&invalid);
+            assert!(matches!(state, LoadState::Failed(_)), "{path}: {state:?}");
+        }
+    }
+
+    #[test]
+    fn process_ron_scene_to_binary() {
+        // the processor writes its log in the asset root
+        std::env::set_var(
+            "BEVY_ASSET_ROOT",
+            std::env::temp_dir().join("bevy_scene_process_ron_scene_to_binary"),
+        );
+
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Health>();
+        let mut world = World::new();
+        world.insert_resource(type_registry.clone());
+        world.spawn(Health(7));
+        let ron = DynamicScene::from_world(&world)
+            .serialize(&type_registry.read())
+            .unwrap();
+
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("level.scn.ron"), &ron);
+        let (reader_dir, writer_dir) = (dir.clone(), dir.clone());
+        let processed_dir = Dir::default();
+        let (processed_reader_dir, processed_writer_dir) =
+            (processed_dir.clone(), processed_dir.clone());
+
+        let mut app = App::new();
+        app.insert_resource(type_registry)
+            .register_asset_source(
+                AssetSourceId::Default,
+                AssetSource::build()
+                    .with_reader(move || {
+                        Box::new(MemoryAssetReader {
+                            root: reader_dir.clone(),
+                        })
+                    })
+                    .with_writer(move |_| {
+                        Some(Box::new(MemoryAssetWriter {
+                            root: writer_dir.clone(),
+                        }))
+                    })
+                    .with_processed_reader(move || {
+                        Box::new(MemoryAssetReader {
+                            root: processed_reader_dir.clone(),
+                        })
+                    })
+                    .with_processed_writer(move |_| {
+                        Some(Box::new(MemoryAssetWriter {
+                            root: processed_writer_dir.clone(),
+                        }))
+                    }),
+            )
+            .add_plugins((
+                TaskPoolPlugin::default(),
+                AssetPlugin {
+                    mode: AssetMode::Processed,
+                    ..Default::default()
+                },
+                ScenePlugin,
+            ))
+            .set_default_asset_processor::<BinarySceneProcessor>("scn.ron");
+
+        let level = load(&mut app, "level.scn.ron");
+        let processor = app.world().resource::<AssetProcessor>();
+        let failed = bevy_tasks::block_on(processor.get_failed_assets());
+        assert!(failed.is_empty(), "{failed:?}");
+        let processed = bevy_tasks::block_on(async {
+            let reader = MemoryAssetReader {
+                root: processed_dir,
+            };
+            let mut bytes = Vec::new();
+            let mut asset = reader.read(Path::new("level.scn.ron")).await.unwrap();
+            asset.read_to_end(&mut bytes).await.unwrap();
+            bytes
+        });
+        assert!(processed.starts_with(&BINARY_SCENE_MAGIC));
+
+        let scene = app
+            .world()
+            .resource::<Assets<DynamicScene>>()
+            .get(&level)
+            .expect("processed scene should be loaded");
+        assert!(scene.entities[0].components[0]
+            .try_downcast_ref::<Health>()
+            .is_some_and(|health| health.0 == 7));
+    }
+
+    #[test]
+    fn save_scene_at_runtime() {
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Health>();
+        let dir = Dir::default();
+        let (reader_dir, writer_dir) = (dir.clone(), dir.clone());
+
+        let mut app = App::new();
+        app.insert_resource(type_registry.clone())
+            .register_asset_source(
+                AssetSourceId::Default,
+                AssetSource::build()
+                    .with_reader(move || {
+                        Box::new(MemoryAssetReader {
+                            root: reader_dir.clone(),
+                        })
+                    })
+                    .with_writer(move |_| {
+                        Some(Box::new(MemoryAssetWriter {
+                            root: writer_dir.clone(),
+                        }))
+                    }),
+            )
+            .add_plugins((
+                TaskPoolPlugin::default(),
+                AssetPlugin::default(),
+                ScenePlugin,
+            ));
+
+        let mut world = World::new();
+        world.insert_resource(type_registry);
+        world.spawn(Health(5));
+        let scene = DynamicScene::from_world(&world);
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(scene);
+
+        // scenes aren't `Clone`, they are saved from `Assets` by reference
+        let scenes = app.world().resource::<Assets<DynamicScene>>();
+        let task = app
+            .world()
+            .resource::<AssetServer>()
+            .save("saved.scn", scenes.get(&handle).unwrap());
+        bevy_tasks::block_on(task).unwrap();
+        assert!(dir.get_asset(Path::new("saved.scn")).is_some());
+
+        let saved = load(&mut app, "saved.scn");
+        let scene = app
+            .world()
+            .resource::<Assets<DynamicScene>>()
+            .get(&saved)
+            .expect("saved scene should be loaded");
+        assert!(scene.entities[0].components[0]
+            .try_downcast_ref::<Health>()
+            .is_some_and(|health| health.0 == 5));
+    }
+}
@@ -1,15 +1,15 @@
-use crate::{DynamicScene, Scene};
-use bevy_asset::{AssetEvent, AssetId, Assets, Handle};
+use crate::{DynamicScene, DynamicSceneBuilder, PrefabOverride, Scene, SceneFilter, ScenePatch};
+use bevy_asset::{AssetEvent, AssetId, AssetServer, Assets, Handle};
 use bevy_ecs::{
-    entity::{Entity, EntityHashMap},
+    entity::{Entity, EntityHashMap, EntityMapper},
     event::{Event, EventCursor, Events},
-    reflect::AppTypeRegistry,
+    reflect::{AppTypeRegistry, ReflectMapEntities},
     system::Resource,
     world::{Command, Mut, World},
 };
 use bevy_hierarchy::{AddChild, BuildChildren, DespawnRecursiveExt, Parent};
 use bevy_reflect::Reflect;
-use bevy_utils::{HashMap, HashSet};
+use bevy_utils::{tracing::error, HashMap, HashSet};
 use derive_more::derive::{Display, Error};
 use uuid::Uuid;
 
@@ -26,10 +26,17 @@ pub struct SceneInstanceReady {
 }
 
 /// Information about a scene instance.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct InstanceInfo {
     /// Mapping of entities from the scene world to the instance world.
     pub entity_map: EntityHashMap<Entity>,
+    /// Instances of the [`prefabs`](DynamicScene::prefabs) nested in the scene, in the same order.
+    pub prefab_instances: Vec<InstanceId>,
+    /// Entity the root entities of the instance are added as children to, if it is a prefab instance.
+    pub parent: Option<Entity>,
+    /// Overrides applied to the entities of the instance every time it is spawned or updated, if
+    /// it is a prefab instance.
+    pub overrides: Vec<PrefabOverride>,
 }
 
 /// Unique id identifying a scene instance.
@@ -37,6 +44,15 @@ pub struct InstanceInfo {
 #[reflect(Debug, PartialEq, Hash)]
 pub struct InstanceId(Uuid);
 
+impl InstanceInfo {
+    fn from_entity_map(entity_map: EntityHashMap<Entity>) -> Self {
+        Self {
+            entity_map,
+            ..Default::default()
+        }
+    }
+}
+
 impl InstanceId {
     fn new() -> Self {
         InstanceId(Uuid::new_v4())
@@ -54,6 +70,7 @@ impl InstanceId {
 /// - [`spawn_queued_scenes`](Self::spawn_queued_scenes)
 /// - [`despawn_queued_scenes`](Self::despawn_queued_scenes)
 /// - [`despawn_queued_instances`](Self::despawn_queued_instances)
+/// - [`add_prefab_override_sync`](Self::add_prefab_override_sync)
 ///
 /// Deferred methods: (Scene operations will be processed when the [`scene_spawner_system`] is run)
 /// - [`spawn_dynamic`](Self::spawn_dynamic)
@@ -72,6 +89,10 @@ pub struct SceneSpawner {
     scenes_to_despawn: Vec<AssetId<DynamicScene>>,
     instances_to_despawn: Vec<InstanceId>,
     scenes_with_parent: Vec<(InstanceId, Entity)>,
+    /// Prefab instances whose scene wasn't loaded yet when they were spawned.
+    prefab_instances_to_write: Vec<(AssetId<DynamicScene>, InstanceId)>,
+    /// The scenes whose prefabs are being spawned or updated, outermost first.
+    expanding_scenes: Vec<AssetId<DynamicScene>>,
 }
 
 /// Errors that can occur when spawning a scene.
@@ -126,6 +147,12 @@ pub enum SceneSpawnError {
         /// Id of the non-existent scene.
         id: AssetId<Scene>,
     },
+    /// Dynamic scene nests itself as a prefab, directly or through other prefabs.
+    #[display("scene {id} nests itself as a prefab, directly or through other prefabs")]
+    RecursivePrefab {
+        /// Id of the dynamic scene nesting itself.
+        id: AssetId<DynamicScene>,
+    },
 }
 
 impl SceneSpawner {
@@ -193,6 +220,9 @@ impl SceneSpawner {
     /// Immediately despawns a scene instance, removing all its entities from the world.
     pub fn despawn_instance_sync(&mut self, world: &mut World, instance_id: &InstanceId) {
         if let Some(instance) = self.spawned_instances.remove(instance_id) {
+            for prefab_instance in &instance.prefab_instances {
+                self.despawn_prefab_instance_sync(world, prefab_instance);
+            }
             for &entity in instance.entity_map.values() {
                 if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
                     entity_mut.remove_parent();
@@ -202,6 +232,13 @@ impl SceneSpawner {
         }
     }
 
+    fn despawn_prefab_instance_sync(&mut self, world: &mut World, instance_id: &InstanceId) {
+        for instance_ids in self.spawned_dynamic_scenes.values_mut() {
+            instance_ids.remove(instance_id);
+        }
+        self.despawn_instance_sync(world, instance_id);
+    }
+
     /// Immediately spawns a new instance of the provided dynamic scene.
     pub fn spawn_dynamic_sync(
         &mut self,
@@ -213,9 +250,13 @@ impl SceneSpawner {
         Self::spawn_dynamic_internal(world, id, &mut entity_map)?;
         let instance_id = InstanceId::new();
         self.spawned_instances
-            .insert(instance_id, InstanceInfo { entity_map });
+            .insert(instance_id, InstanceInfo::from_entity_map(entity_map));
         let spawned = self.spawned_dynamic_scenes.entry(id).or_default();
         spawned.insert(instance_id);
+        if let Err(err) = self.update_prefab_instances(world, id, instance_id) {
+            self.despawn_prefab_instance_sync(world, &instance_id);
+            return Err(err);
+        }
         Ok(instance_id)
     }
 
@@ -233,6 +274,272 @@ impl SceneSpawner {
         })
     }
 
+    /// Writes the dynamic scene to the entities of a spawned instance, then updates the prefabs
+    /// nested in it and applies the overrides of the instance.
+    fn write_dynamic_instance(
+        &mut self,
+        world: &mut World,
+        id: AssetId<DynamicScene>,
+        instance_id: InstanceId,
+    ) -> Result<(), SceneSpawnError> {
+        let Some(instance_info) = self.spawned_instances.get_mut(&instance_id) else {
+            return Ok(());
+        };
+        match Self::spawn_dynamic_internal(world, id, &mut instance_info.entity_map) {
+            // The prefab isn't loaded yet, the instance is written once it is.
+            Err(SceneSpawnError::NonExistentScene { .. }) => {
+                if !self.prefab_instances_to_write.contains(&(id, instance_id)) {
+                    self.prefab_instances_to_write.push((id, instance_id));
+                }
+                return Ok(());
+            }
+            result => result?,
+        }
+        self.update_prefab_instances(world, id, instance_id)?;
+
+        let instance_info = &self.spawned_instances[&instance_id];
+        if let Some(parent) = instance_info
+            .parent
+            .filter(|&parent| world.get_entity(parent).is_ok())
+        {
+            for &entity in instance_info.entity_map.values() {
+                // Only the root entities of the prefab don't have a parent.
+                if world
+                    .get_entity(entity)
+                    .is_ok_and(|entity| !entity.contains::<Parent>())
+                {
+                    AddChild {
+                        parent,
+                        child: entity,
+                    }
+                    .apply(world);
+                }
+            }
+        }
+
+        if !instance_info.overrides.is_empty() {
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            let type_registry = type_registry.read();
+            for prefab_override in &instance_info.overrides {
+                prefab_override.apply(world, &instance_info.entity_map, &type_registry)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Spawns, updates and despawns the prefab instances nested in an instance of a dynamic scene,
+    /// to match the [`prefabs`](DynamicScene::prefabs) of the scene.
+    ///
+    /// Fails if the scene nests itself, which would spawn prefab instances forever.
+    fn update_prefab_instances(
+        &mut self,
+        world: &mut World,
+        id: AssetId<DynamicScene>,
+        instance_id: InstanceId,
+    ) -> Result<(), SceneSpawnError> {
+        if self.expanding_scenes.contains(&id) {
+            return Err(SceneSpawnError::RecursivePrefab { id });
+        }
+        self.expanding_scenes.push(id);
+        let result = self.update_prefab_instances_internal(world, id, instance_id);
+        self.expanding_scenes.pop();
+        result
+    }
+
+    fn update_prefab_instances_internal(
+        &mut self,
+        world: &mut World,
+        id: AssetId<DynamicScene>,
+        instance_id: InstanceId,
+    ) -> Result<(), SceneSpawnError> {
+        let prefabs = world
+            .resource::<Assets<DynamicScene>>()
+            .get(id)
+            .map(|scene| {
+                scene
+                    .prefabs
+                    .iter()
+                    .map(|prefab| (prefab.scene.id(), prefab.parent, prefab.overrides.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let Some(instance_info) = self.spawned_instances.get_mut(&instance_id) else {
+            return Ok(());
+        };
+        let mut old_prefab_instances =
+            core::mem::take(&mut instance_info.prefab_instances).into_iter();
+
+        let mut prefab_instances = Vec::with_capacity(prefabs.len());
+        let mut result = Ok(());
+        for (prefab_id, parent, overrides) in prefabs {
+            // Reuse the instance of the same prefab from the previous version of the scene,
+            // so that the entities of the prefab are updated instead of respawned.
+            let prefab_instance = match old_prefab_instances.next() {
+                Some(old_instance)
+                    if self
+                        .spawned_dynamic_scenes
+                        .get(&prefab_id)
+                        .is_some_and(|instance_ids| instance_ids.contains(&old_instance)) =>
+                {
+                    old_instance
+                }
+                old_instance => {
+                    if let Some(old_instance) = old_instance {
+                        self.despawn_prefab_instance_sync(world, &old_instance);
+                    }
+                    let prefab_instance = InstanceId::new();
+                    self.spawned_instances
+                        .insert(prefab_instance, InstanceInfo::default());
+                    self.spawned_dynamic_scenes
+                        .entry(prefab_id)
+                        .or_default()
+                        .insert(prefab_instance);
+                    prefab_instance
+                }
+            };
+
+            let parent = parent
+                .and_then(|parent| self.spawned_instances[&instance_id].entity_map.get(&parent))
+                .copied();
+            if let Some(prefab_info) = self.spawned_instances.get_mut(&prefab_instance) {
+                prefab_info.parent = parent;
+                prefab_info.overrides = overrides;
+            }
+            prefab_instances.push(prefab_instance);
+            result = self.write_dynamic_instance(world, prefab_id, prefab_instance);
+            if result.is_err() {
+                break;
+            }
+        }
+
+        // On failure, the prefab instances are all kept with the instance, so that despawning it
+        // despawns them too.
+        if result.is_ok() {
+            for old_instance in old_prefab_instances {
+                self.despawn_prefab_instance_sync(world, &old_instance);
+            }
+        } else {
+            prefab_instances.extend(old_prefab_instances);
+        }
+        if let Some(instance_info) = self.spawned_instances.get_mut(&instance_id) {
+            instance_info.prefab_instances = prefab_instances;
+        }
+        result
+    }
+
+    /// Immediately applies an override to the entities of a prefab instance, replacing the override
+    /// of the same value if there is one.
+    ///
+    /// The override is applied again when the prefab is updated, for example after being hot
+    /// reloaded, until the scene the prefab is nested in is updated, which resets the overrides to
+    /// those of its [`DynamicPrefab`](crate::DynamicPrefab).
+    /// See [`iter_prefab_instances`](Self::iter_prefab_instances) to get the prefab instances of a scene instance.
+    pub fn add_prefab_override_sync(
+        &mut self,
+        world: &mut World,
+        instance_id: InstanceId,
+        prefab_override: PrefabOverride,
+    ) -> Result<(), SceneSpawnError> {
+        let Some(instance_info) = self.spawned_instances.get_mut(&instance_id) else {
+            return Ok(());
+        };
+        {
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            let type_registry = type_registry.read();
+            prefab_override.apply(world, &instance_info.entity_map, &type_registry)?;
+        }
+        instance_info
+            .overrides
+            .retain(|other| !other.overrides_same_value(&prefab_override));
+        instance_info.overrides.push(prefab_override);
+        Ok(())
+    }
+
+    /// Extracts the current state of the entities of a spawned instance as a [`DynamicScene`], using
+    /// the entities of the scene the instance was spawned from.
+    ///
+    /// Diffing the scene the instance was spawned from with the extracted scene gives the
+    /// [`ScenePatch`] of the changes made to the instance in the world. Entities spawned in the
+    /// world after the instance and the entities of nested prefab instances are not extracted,
+    /// and entities of the instance which were despawned are missing from the extracted scene.
+    ///
+    /// For instances of a [`DynamicScene`], only the types of components found in the scene are
+    /// extracted, so that the components the world adds to the entities, like required components
+    /// or the [`Parent`] of the root entities of prefab instances, aren't part of the diff.
+    /// Instances of a [`Scene`] extract all of their reflected components.
+    ///
+    /// Returns `None` if the instance is not spawned.
+    pub fn extract_instance(&self, world: &World, instance_id: InstanceId) -> Option<DynamicScene> {
+        let instance_info = self.spawned_instances.get(&instance_id)?;
+        let scene_entities = instance_info
+            .entity_map
+            .iter()
+            .map(|(&scene_entity, &entity)| (entity, scene_entity))
+            .collect::<EntityHashMap<_>>();
+
+        let mut builder = DynamicSceneBuilder::from_world(world);
+        let source_scene = self
+            .spawned_dynamic_scenes
+            .iter()
+            .find(|(_, instance_ids)| instance_ids.contains(&instance_id))
+            .and_then(|(&id, _)| world.get_resource::<Assets<DynamicScene>>()?.get(id));
+        if let Some(source_scene) = source_scene {
+            let filter = source_scene
+                .entities
+                .iter()
+                .flat_map(|entity| &entity.components)
+                .filter_map(|component| component.get_represented_type_info())
+                .fold(SceneFilter::deny_all(), |filter, type_info| {
+                    filter.allow_by_id(type_info.type_id())
+                });
+            builder = builder.with_component_filter(filter);
+        }
+        let mut scene = builder
+            .extract_entities(
+                instance_info
+                    .entity_map
+                    .values()
+                    .copied()
+                    .filter(|&entity| world.get_entity(entity).is_ok()),
+            )
+            .build();
+
+        // Map the entities and the references to them back to the entities of the scene.
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let mut mapper = InstanceEntityMapper(&scene_entities);
+        for entity in &mut scene.entities {
+            entity.entity = mapper.map_entity(entity.entity);
+            for component in &mut entity.components {
+                if let Some(map_entities) = component
+                    .get_represented_type_info()
+                    .and_then(|type_info| type_registry.get(type_info.type_id()))
+                    .and_then(|registration| registration.data::<ReflectMapEntities>())
+                {
+                    map_entities.map_entities(component.as_partial_reflect_mut(), &mut mapper);
+                }
+            }
+        }
+        Some(scene)
+    }
+
+    /// Immediately applies a [`ScenePatch`] computed from the scene of a spawned instance to the
+    /// entities of the instance.
+    ///
+    /// Entities added by the patch become part of the instance, and are despawned with it.
+    pub fn patch_instance_sync(
+        &mut self,
+        world: &mut World,
+        instance_id: InstanceId,
+        patch: &ScenePatch,
+    ) -> Result<(), SceneSpawnError> {
+        let Some(instance_info) = self.spawned_instances.get_mut(&instance_id) else {
+            return Ok(());
+        };
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        patch.apply(world, &mut instance_info.entity_map, &type_registry)
+    }
+
     /// Immediately spawns a new instance of the provided scene.
     pub fn spawn_sync(
         &mut self,
@@ -244,7 +551,7 @@ impl SceneSpawner {
         Self::spawn_sync_internal(world, id, &mut entity_map)?;
         let instance_id = InstanceId::new();
         self.spawned_instances
-            .insert(instance_id, InstanceInfo { entity_map });
+            .insert(instance_id, InstanceInfo::from_entity_map(entity_map));
         Ok(instance_id)
     }
 
@@ -269,21 +576,39 @@ impl SceneSpawner {
     /// Iterate through all instances of the provided scenes and update those immediately.
     ///
     /// Useful for updating already spawned scene instances after their corresponding scene has been modified.
+    ///
+    /// Instances that fail to update are despawned, and the other instances are still updated.
+    /// Returns the first error.
     pub fn update_spawned_scenes(
         &mut self,
         world: &mut World,
         scene_ids: &[AssetId<DynamicScene>],
     ) -> Result<(), SceneSpawnError> {
+        let mut result = Ok(());
+        self.update_spawned_scenes_internal(world, scene_ids, &mut |err| {
+            if result.is_ok() {
+                result = Err(err);
+            }
+        });
+        result
+    }
+
+    fn update_spawned_scenes_internal(
+        &mut self,
+        world: &mut World,
+        scene_ids: &[AssetId<DynamicScene>],
+        on_error: &mut dyn FnMut(SceneSpawnError),
+    ) {
         for id in scene_ids {
             if let Some(spawned_instances) = self.spawned_dynamic_scenes.get(id) {
-                for instance_id in spawned_instances {
-                    if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
-                        Self::spawn_dynamic_internal(world, *id, &mut instance_info.entity_map)?;
+                for instance_id in spawned_instances.iter().copied().collect::<Vec<_>>() {
+                    if let Err(err) = self.write_dynamic_instance(world, *id, instance_id) {
+                        self.despawn_prefab_instance_sync(world, &instance_id);
+                        on_error(err);
                     }
                 }
             }
         }
-        Ok(())
     }
 
     /// Immediately despawns all scenes scheduled for despawn by despawning their instances.
@@ -305,8 +630,26 @@ impl SceneSpawner {
         }
     }
 
-    /// Immediately spawns all scenes scheduled for spawn.
+    /// Immediately spawns all scenes scheduled for spawn, and writes the prefab instances whose
+    /// scene was loaded since they were spawned.
+    ///
+    /// Instances that fail to spawn are despawned, and the other scheduled scenes are still spawned.
+    /// Returns the first error.
     pub fn spawn_queued_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
+        let mut result = Ok(());
+        self.spawn_queued_scenes_internal(world, &mut |err| {
+            if result.is_ok() {
+                result = Err(err);
+            }
+        });
+        result
+    }
+
+    fn spawn_queued_scenes_internal(
+        &mut self,
+        world: &mut World,
+        on_error: &mut dyn FnMut(SceneSpawnError),
+    ) {
         let scenes_to_spawn = core::mem::take(&mut self.dynamic_scenes_to_spawn);
 
         for (handle, instance_id, parent) in scenes_to_spawn {
@@ -315,12 +658,18 @@ impl SceneSpawner {
             match Self::spawn_dynamic_internal(world, handle.id(), &mut entity_map) {
                 Ok(_) => {
                     self.spawned_instances
-                        .insert(instance_id, InstanceInfo { entity_map });
+                        .insert(instance_id, InstanceInfo::from_entity_map(entity_map));
                     let spawned = self
                         .spawned_dynamic_scenes
                         .entry(handle.id())
                         .or_insert_with(HashSet::new);
                     spawned.insert(instance_id);
+                    if let Err(err) = self.update_prefab_instances(world, handle.id(), instance_id)
+                    {
+                        self.despawn_failed_instance(world, instance_id);
+                        on_error(err);
+                        continue;
+                    }
 
                     // Scenes with parents need more setup before they are ready.
                     // See `set_scene_instance_parent_sync()`.
@@ -333,7 +682,12 @@ impl SceneSpawner {
                     self.dynamic_scenes_to_spawn
                         .push((handle, instance_id, parent));
                 }
-                Err(err) => return Err(err),
+                Err(err) => {
+                    self.spawned_instances
+                        .insert(instance_id, InstanceInfo::from_entity_map(entity_map));
+                    self.despawn_failed_instance(world, instance_id);
+                    on_error(err);
+                }
             }
         }
 
@@ -345,7 +699,7 @@ impl SceneSpawner {
             match Self::spawn_sync_internal(world, scene_handle.id(), &mut entity_map) {
                 Ok(_) => {
                     self.spawned_instances
-                        .insert(instance_id, InstanceInfo { entity_map });
+                        .insert(instance_id, InstanceInfo::from_entity_map(entity_map));
 
                     // Scenes with parents need more setup before they are ready.
                     // See `set_scene_instance_parent_sync()`.
@@ -358,11 +712,38 @@ impl SceneSpawner {
                     self.scenes_to_spawn
                         .push((scene_handle, instance_id, parent));
                 }
-                Err(err) => return Err(err),
+                Err(err) => {
+                    self.spawned_instances
+                        .insert(instance_id, InstanceInfo::from_entity_map(entity_map));
+                    self.despawn_failed_instance(world, instance_id);
+                    on_error(err);
+                }
             }
         }
 
-        Ok(())
+        // Prefab instances that still aren't loaded are queued again, unless their scene failed to
+        // load.
+        let prefab_instances_to_write = core::mem::take(&mut self.prefab_instances_to_write);
+        let asset_server = world.get_resource::<AssetServer>().cloned();
+        for (id, instance_id) in prefab_instances_to_write {
+            if asset_server
+                .as_ref()
+                .is_some_and(|asset_server| asset_server.load_state(id).is_failed())
+            {
+                continue;
+            }
+            if let Err(err) = self.write_dynamic_instance(world, id, instance_id) {
+                self.despawn_prefab_instance_sync(world, &instance_id);
+                on_error(err);
+            }
+        }
+    }
+
+    /// Despawns a scheduled instance that failed to spawn, so that it isn't added to its parent.
+    fn despawn_failed_instance(&mut self, world: &mut World, instance_id: InstanceId) {
+        self.despawn_prefab_instance_sync(world, &instance_id);
+        self.scenes_with_parent
+            .retain(|(instance, _)| *instance != instance_id);
     }
 
     pub(crate) fn set_scene_instance_parent_sync(&mut self, world: &mut World) {
@@ -420,6 +801,29 @@ impl SceneSpawner {
             .flatten()
             .copied()
     }
+
+    /// Get an iterator over the prefab instances nested in an instance, in the order of the
+    /// [`prefabs`](DynamicScene::prefabs) of its scene.
+    pub fn iter_prefab_instances(
+        &'_ self,
+        instance_id: InstanceId,
+    ) -> impl Iterator<Item = InstanceId> + '_ {
+        self.spawned_instances
+            .get(&instance_id)
+            .map(|instance| instance.prefab_instances.iter())
+            .into_iter()
+            .flatten()
+            .copied()
+    }
+}
+
+/// Maps the entities of an instance back to the entities of its scene.
+struct InstanceEntityMapper<'a>(&'a EntityHashMap<Entity>);
+
+impl EntityMapper for InstanceEntityMapper<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
 }
 
 /// System that handles scheduled scene instance spawning and despawning through a [`SceneSpawner`].
@@ -462,12 +866,14 @@ pub fn scene_spawner_system(world: &mut World) {
 
         scene_spawner.despawn_queued_scenes(world).unwrap();
         scene_spawner.despawn_queued_instances(world);
-        scene_spawner
-            .spawn_queued_scenes(world)
-            .unwrap_or_else(|err| panic!("{}", err));
-        scene_spawner
-            .update_spawned_scenes(world, &updated_spawned_scenes)
-            .unwrap();
+        // A scene failing to spawn, for example because it nests itself, only despawns its instance.
+        let mut log_error = |err| error!("Despawned a scene instance that failed to spawn: {err}");
+        scene_spawner.spawn_queued_scenes_internal(world, &mut log_error);
+        scene_spawner.update_spawned_scenes_internal(
+            world,
+            &updated_spawned_scenes,
+            &mut log_error,
+        );
         scene_spawner.set_scene_instance_parent_sync(world);
     });
 }
@@ -485,7 +891,10 @@ mod tests {
     };
     use bevy_reflect::Reflect;
 
-    use crate::{DynamicSceneBuilder, DynamicSceneRoot, ScenePlugin};
+    use crate::{
+        DynamicEntity, DynamicPrefab, DynamicSceneBuilder, DynamicSceneRoot, PrefabOverride,
+        ScenePlugin,
+    };
 
     use super::*;
 
@@ -749,4 +1158,326 @@ mod tests {
         app.update();
         check(app.world_mut(), 0);
     }
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Stats {
+        health: u32,
+        speed: u32,
+    }
+
+    #[test]
+    fn prefab_overrides_are_kept_when_prefab_is_updated() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin));
+        app.register_type::<ComponentA>().register_type::<Stats>();
+
+        let prefab_entity = Entity::from_raw(0);
+        let scene_entity = Entity::from_raw(0);
+        let asset_server = app.world().resource::<AssetServer>();
+        let prefab = asset_server.add(DynamicScene {
+            entities: vec![DynamicEntity {
+                entity: prefab_entity,
+                components: vec![Box::new(Stats {
+                    health: 10,
+                    speed: 1,
+                })],
+            }],
+            ..Default::default()
+        });
+        let scene = asset_server.add(DynamicScene {
+            entities: vec![DynamicEntity {
+                entity: scene_entity,
+                components: vec![Box::new(ComponentA)],
+            }],
+            prefabs: vec![DynamicPrefab::new(prefab.clone())
+                .with_parent(scene_entity)
+                .with_override(PrefabOverride::new::<Stats>(
+                    prefab_entity,
+                    ".health",
+                    Box::new(50u32),
+                ))],
+            ..Default::default()
+        });
+
+        app.world_mut().spawn(DynamicSceneRoot(scene));
+        app.update();
+
+        // The prefab is spawned as a child of the scene entity, with its override applied.
+        let check = |world: &mut World, expected: Stats| {
+            let (parent, stats) = world.query::<(&Parent, &Stats)>().single(world);
+            assert!(world.get::<ComponentA>(parent.get()).is_some());
+            assert_eq!(&expected, stats);
+        };
+        check(
+            app.world_mut(),
+            Stats {
+                health: 50,
+                speed: 1,
+            },
+        );
+
+        // Edit the prefab, as a hot reload would.
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .get_mut(&prefab)
+            .unwrap()
+            .entities[0]
+            .components = vec![Box::new(Stats {
+            health: 20,
+            speed: 2,
+        })];
+        // The modified event is sent at the end of the frame, and handled during the next one.
+        app.update();
+        app.update();
+
+        // The instance is updated in place and keeps its override.
+        check(
+            app.world_mut(),
+            Stats {
+                health: 50,
+                speed: 2,
+            },
+        );
+    }
+
+    #[test]
+    fn prefabs_are_written_once_loaded() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin));
+        app.register_type::<ComponentA>().register_type::<Stats>();
+
+        let prefab = app
+            .world()
+            .resource::<Assets<DynamicScene>>()
+            .reserve_handle();
+        let scene = app.world().resource::<AssetServer>().add(DynamicScene {
+            entities: vec![DynamicEntity {
+                entity: Entity::from_raw(0),
+                components: vec![Box::new(ComponentA)],
+            }],
+            prefabs: vec![DynamicPrefab::new(prefab.clone())
+                // overrides of components that aren't registered are skipped
+                .with_override(PrefabOverride {
+                    entity: Entity::from_raw(0),
+                    component: "unregistered::Component".to_string(),
+                    path: String::new(),
+                    value: Box::new(0u32),
+                })],
+            ..Default::default()
+        });
+        app.world_mut().spawn(DynamicSceneRoot(scene));
+        app.update();
+        assert_eq!(app.world_mut().query::<&Stats>().iter(app.world()).len(), 0);
+
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(
+                &prefab,
+                DynamicScene {
+                    entities: vec![DynamicEntity {
+                        entity: Entity::from_raw(0),
+                        components: vec![Box::new(Stats::default())],
+                    }],
+                    ..Default::default()
+                },
+            );
+        app.update();
+        assert_eq!(app.world_mut().query::<&Stats>().iter(app.world()).len(), 1);
+    }
+
+    #[test]
+    fn recursive_prefabs() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin));
+
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        let [a, b] = [scenes.reserve_handle(), scenes.reserve_handle()];
+        for (scene, prefab) in [(&a, &b), (&b, &a)] {
+            scenes.insert(
+                scene,
+                DynamicScene {
+                    prefabs: vec![DynamicPrefab::new(prefab.clone())],
+                    ..Default::default()
+                },
+            );
+        }
+
+        let result =
+            app.world_mut()
+                .resource_scope(|world, mut scene_spawner: Mut<SceneSpawner>| {
+                    scene_spawner.spawn_dynamic_sync(world, &a)
+                });
+        assert!(matches!(
+            result,
+            Err(SceneSpawnError::RecursivePrefab { id }) if id == a.id()
+        ));
+    }
+
+    #[test]
+    fn recursive_prefabs_only_despawn_their_instance() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin));
+        app.register_type::<ComponentA>();
+
+        let scene_with_prefab = |prefab: Option<&Handle<DynamicScene>>| DynamicScene {
+            entities: vec![DynamicEntity {
+                entity: Entity::from_raw(0),
+                components: vec![Box::new(ComponentA)],
+            }],
+            prefabs: prefab
+                .map(|prefab| DynamicPrefab::new(prefab.clone()))
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        let recursive = scenes.reserve_handle();
+        scenes.insert(&recursive, scene_with_prefab(Some(&recursive)));
+        let valid = scenes.add(scene_with_prefab(None));
+
+        let mut scene_spawner = app.world_mut().resource_mut::<SceneSpawner>();
+        let recursive_instance = scene_spawner.spawn_dynamic(recursive.clone());
+        let valid_instance = scene_spawner.spawn_dynamic(valid.clone());
+        app.update();
+
+        // The recursive scene is despawned, the scene queued after it is still spawned.
+        let scene_spawner = app.world().resource::<SceneSpawner>();
+        assert!(!scene_spawner.instance_is_ready(recursive_instance));
+        assert!(scene_spawner.instance_is_ready(valid_instance));
+        assert!(scene_spawner.spawned_dynamic_scenes[&recursive.id()].is_empty());
+        let count = |app: &mut App| {
+            app.world_mut()
+                .query::<&ComponentA>()
+                .iter(app.world())
+                .len()
+        };
+        assert_eq!(count(&mut app), 1);
+
+        // Hot reloading a scene so that it nests itself despawns its instances.
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(&valid, scene_with_prefab(Some(&valid)));
+        // Asset events are sent at the end of the frame.
+        app.update();
+        app.update();
+        let scene_spawner = app.world().resource::<SceneSpawner>();
+        assert!(!scene_spawner.instance_is_ready(valid_instance));
+        assert_eq!(count(&mut app), 0);
+    }
+
+    #[test]
+    fn prefabs_that_failed_to_load_are_not_retried() {
+        let mut app = App::new();
+        app.add_plugins((
+            bevy_core::TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ));
+
+        let missing: Handle<DynamicScene> = app
+            .world()
+            .resource::<AssetServer>()
+            .load("missing.scn.ron");
+        let scene = app.world().resource::<AssetServer>().add(DynamicScene {
+            prefabs: vec![DynamicPrefab::new(missing.clone())],
+            ..Default::default()
+        });
+        let instance = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(scene);
+
+        for _ in 0..1000 {
+            app.update();
+            if app
+                .world()
+                .resource::<AssetServer>()
+                .load_state(&missing)
+                .is_failed()
+            {
+                break;
+            }
+            std::thread::sleep(core::time::Duration::from_millis(1));
+        }
+        app.update();
+        let scene_spawner = app.world().resource::<SceneSpawner>();
+        assert_eq!(scene_spawner.iter_prefab_instances(instance).count(), 1);
+        assert!(scene_spawner.prefab_instances_to_write.is_empty());
+    }
+
+    #[test]
+    fn patch_instance_with_edits_of_another_instance() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin));
+        app.register_type::<ComponentA>()
+            .register_type::<Stats>()
+            .register_type::<A>();
+
+        let scene = app.world().resource::<AssetServer>().add(DynamicScene {
+            entities: vec![
+                DynamicEntity {
+                    entity: Entity::from_raw(0),
+                    components: vec![Box::new(Stats {
+                        health: 10,
+                        speed: 1,
+                    })],
+                },
+                DynamicEntity {
+                    entity: Entity::from_raw(1),
+                    components: vec![Box::new(ComponentA)],
+                },
+            ],
+            ..Default::default()
+        });
+        let mut scene_spawner = app.world_mut().resource_mut::<SceneSpawner>();
+        let edited = scene_spawner.spawn_dynamic(scene.clone());
+        let synced = scene_spawner.spawn_dynamic(scene.clone());
+        app.update();
+
+        // Edit the first instance in the world. `A` isn't a component type of the scene, so it
+        // isn't extracted.
+        let world = app.world_mut();
+        let entity_map = &world.resource::<SceneSpawner>().spawned_instances[&edited].entity_map;
+        let (entity, deleted) = (
+            entity_map[&Entity::from_raw(0)],
+            entity_map[&Entity::from_raw(1)],
+        );
+        world.get_mut::<Stats>(entity).unwrap().health = 5;
+        world.entity_mut(entity).insert((ComponentA, A(1)));
+        world.despawn(deleted);
+
+        // Sync the edits to the second instance.
+        world.resource_scope(|world, mut scene_spawner: Mut<SceneSpawner>| {
+            let extracted = scene_spawner.extract_instance(world, edited).unwrap();
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            let patch = {
+                let scenes = world.resource::<Assets<DynamicScene>>();
+                ScenePatch::diff(
+                    scenes.get(&scene).unwrap(),
+                    &extracted,
+                    &type_registry.read(),
+                )
+            };
+            assert_eq!(patch.changed_values.len(), 1);
+            assert_eq!(patch.inserted_components.len(), 1);
+            assert_eq!(patch.removed_entities, vec![Entity::from_raw(1)]);
+
+            scene_spawner
+                .patch_instance_sync(world, synced, &patch)
+                .unwrap();
+            let entity_map = &scene_spawner.spawned_instances[&synced].entity_map;
+            let entity = entity_map[&Entity::from_raw(0)];
+            assert_eq!(
+                world.get::<Stats>(entity),
+                Some(&Stats {
+                    health: 5,
+                    speed: 1
+                })
+            );
+            assert!(world.get::<ComponentA>(entity).is_some());
+            assert!(world.get::<A>(entity).is_none());
+            assert!(!entity_map.contains_key(&Entity::from_raw(1)));
+        });
+    }
 }
@@ -1,19 +1,26 @@
 //! `serde` serialization and deserialization implementation for Bevy scenes.
 
-use crate::{DynamicEntity, DynamicScene};
-use bevy_ecs::entity::Entity;
+use crate::{DynamicEntity, DynamicPrefab, DynamicScene, PrefabOverride};
+use bevy_asset::{AssetPath, Handle};
+use bevy_core::Name;
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap, EntityMapper},
+    reflect::ReflectMapEntities,
+};
 use bevy_reflect::{
     serde::{
-        ReflectDeserializer, TypeRegistrationDeserializer, TypedReflectDeserializer,
-        TypedReflectSerializer,
+        ReflectDeserializer, ReflectSerializer, SerializationData, TypeRegistrationDeserializer,
+        TypedReflectDeserializer, TypedReflectSerializer,
     },
-    PartialReflect, ReflectFromReflect, TypeRegistry,
+    std_traits::ReflectDefault,
+    FromReflect, PartialReflect, ReflectFromReflect, ReflectRef, ReflectSerialize, TypeInfo,
+    TypeRegistry,
 };
-use bevy_utils::HashSet;
-use core::fmt::Formatter;
+use bevy_utils::{HashMap, HashSet};
+use core::{cell::Cell, fmt::Formatter};
 use serde::{
     de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
-    ser::{SerializeMap, SerializeStruct},
+    ser::{self, SerializeMap, SerializeSeq, SerializeStruct},
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
@@ -23,12 +30,34 @@ pub const SCENE_STRUCT: &str = "Scene";
 pub const SCENE_RESOURCES: &str = "resources";
 /// Name of the serialized entities field in a scene struct.
 pub const SCENE_ENTITIES: &str = "entities";
+/// Name of the serialized prefabs field in a scene struct.
+pub const SCENE_PREFABS: &str = "prefabs";
 
 /// Name of the serialized entity struct type.
 pub const ENTITY_STRUCT: &str = "Entity";
 /// Name of the serialized component field in an entity struct.
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 
+/// Name of the serialized prefab struct type.
+pub const PREFAB_STRUCT: &str = "Prefab";
+/// Name of the serialized scene path field in a prefab struct.
+pub const PREFAB_FIELD_SCENE: &str = "scene";
+/// Name of the serialized parent entity field in a prefab struct.
+pub const PREFAB_FIELD_PARENT: &str = "parent";
+/// Name of the serialized overrides field in a prefab struct.
+pub const PREFAB_FIELD_OVERRIDES: &str = "overrides";
+
+/// Name of the serialized prefab override struct type.
+pub const OVERRIDE_STRUCT: &str = "Override";
+/// Name of the serialized entity field in a prefab override struct.
+pub const OVERRIDE_FIELD_ENTITY: &str = "entity";
+/// Name of the serialized component type path field in a prefab override struct.
+pub const OVERRIDE_FIELD_COMPONENT: &str = "component";
+/// Name of the serialized reflection path field in a prefab override struct.
+pub const OVERRIDE_FIELD_PATH: &str = "path";
+/// Name of the serialized value field in a prefab override struct.
+pub const OVERRIDE_FIELD_VALUE: &str = "value";
+
 /// Serializer for a [`DynamicScene`].
 ///
 /// Helper object defining Bevy's serialize format for a [`DynamicScene`] and implementing
@@ -59,6 +88,8 @@ pub struct SceneSerializer<'a> {
     pub scene: &'a DynamicScene,
     /// The type registry containing the types present in the scene.
     pub registry: &'a TypeRegistry,
+    /// Whether the scene is written in the readable format, see [`SceneSerializer::readable`].
+    pub readable: bool,
 }
 
 impl<'a> SceneSerializer<'a> {
@@ -69,7 +100,35 @@ impl<'a> SceneSerializer<'a> {
     ///
     /// [`World`]: bevy_ecs::world::World
     pub fn new(scene: &'a DynamicScene, registry: &'a TypeRegistry) -> Self {
-        SceneSerializer { scene, registry }
+        SceneSerializer {
+            scene,
+            registry,
+            readable: false,
+        }
+    }
+
+    /// Create a new serializer writing the scene in a readable format, meant for scene files that
+    /// are edited by hand and kept under version control:
+    /// - Entities are keyed by their [`Name`] when it is unique within the scene, and by a
+    ///   `"#<n>"` id otherwise, where `n` is the index of their entity in the scene, so that adding
+    ///   or removing entities doesn't change the keys of the other entities. The [`Name`]
+    ///   component itself is not written.
+    /// - Entities referenced by components are written as entity ids, like in the default format:
+    ///   the id of a `"#<n>"` entity has the index `n`, and the id of a named entity is derived
+    ///   from a hash of its name. These ids are stable, but references to named entities aren't
+    ///   readable as their name.
+    /// - Fields of components and resources that are equal to the [`ReflectDefault`] value of their
+    ///   type are omitted, unless the type is serialized with serde.
+    /// - Named entities are written first, sorted by name, followed by the other entities.
+    ///
+    /// Scenes in this format are read by the [`SceneDeserializer`] like any other scene. Formats
+    /// which aren't [human readable](Serializer::is_human_readable) use the default format.
+    pub fn readable(scene: &'a DynamicScene, registry: &'a TypeRegistry) -> Self {
+        SceneSerializer {
+            scene,
+            registry,
+            readable: true,
+        }
     }
 }
 
@@ -78,7 +137,27 @@ impl<'a> Serialize for SceneSerializer<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct(SCENE_STRUCT, 2)?;
+        if self.readable && serializer.is_human_readable() {
+            let scene =
+                ReadableScene::new(self.scene, self.registry).map_err(ser::Error::custom)?;
+            return SceneSerializer::new(&scene.scene, self.registry)
+                .serialize_readable(&scene.keys, serializer);
+        }
+
+        // Scenes without prefabs are written without the field, which is optional, so that they
+        // are written the same as scenes from before prefabs existed.
+        self.serialize_default(self.scene.prefabs.is_empty(), serializer)
+    }
+}
+
+impl<'a> SceneSerializer<'a> {
+    /// Writes the scene in the default format, with or without the list of prefabs.
+    fn serialize_default<S>(&self, skip_prefabs: bool, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state =
+            serializer.serialize_struct(SCENE_STRUCT, if skip_prefabs { 2 } else { 3 })?;
         state.serialize_field(
             SCENE_RESOURCES,
             &SceneMapSerializer {
@@ -93,6 +172,361 @@ impl<'a> Serialize for SceneSerializer<'a> {
                 registry: self.registry,
             },
         )?;
+        if skip_prefabs {
+            state.skip_field(SCENE_PREFABS)?;
+        } else {
+            state.serialize_field(
+                SCENE_PREFABS,
+                &PrefabsSerializer {
+                    prefabs: &self.scene.prefabs,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+
+    /// Writes a scene prepared by [`ReadableScene::new`], with its entities keyed by `keys`.
+    fn serialize_readable<S>(&self, keys: &[String], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_prefabs = self.scene.prefabs.is_empty();
+        let mut state =
+            serializer.serialize_struct(SCENE_STRUCT, if skip_prefabs { 2 } else { 3 })?;
+        state.serialize_field(
+            SCENE_RESOURCES,
+            &ReadableMapSerializer {
+                entries: &self.scene.resources,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(
+            SCENE_ENTITIES,
+            &ReadableEntitiesSerializer {
+                entities: &self.scene.entities,
+                keys,
+                registry: self.registry,
+            },
+        )?;
+        if skip_prefabs {
+            state.skip_field(SCENE_PREFABS)?;
+        } else {
+            state.serialize_field(
+                SCENE_PREFABS,
+                &PrefabsSerializer {
+                    prefabs: &self.scene.prefabs,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes a scene in the default format, always writing the list of prefabs.
+///
+/// Formats which don't know how many fields were written, like postcard, can't tell a scene
+/// without the optional list of prefabs apart from a truncated one. Scenes written by this
+/// serializer are read with [`SceneDeserializer::deserialize_with_prefabs`].
+pub(crate) struct BinarySceneSerializer<'a>(pub SceneSerializer<'a>);
+
+impl<'a> Serialize for BinarySceneSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize_default(false, serializer)
+    }
+}
+
+/// A copy of a scene with its entities replaced by their stable ids and sorted, along with the
+/// key of each entity, used to write scenes in the readable format.
+struct ReadableScene {
+    scene: DynamicScene,
+    keys: Vec<String>,
+}
+
+impl ReadableScene {
+    fn new(scene: &DynamicScene, registry: &TypeRegistry) -> Result<Self, String> {
+        let names = scene
+            .entities
+            .iter()
+            .map(|entity| {
+                entity
+                    .components
+                    .iter()
+                    .find_map(|component| Name::from_reflect(component.as_partial_reflect()))
+            })
+            .collect::<Vec<_>>();
+        let mut name_counts = HashMap::<&str, usize>::default();
+        for name in names.iter().flatten() {
+            *name_counts.entry(name.as_str()).or_default() += 1;
+        }
+
+        // Entities are sorted by key: named entities by name, then the other entities by id.
+        let mut order = (0..scene.entities.len()).collect::<Vec<_>>();
+        let key_name = |index: usize| {
+            names[index]
+                .as_ref()
+                .map(Name::as_str)
+                .filter(|name| name_counts[name] == 1 && is_entity_key_name(name))
+        };
+        order.sort_by_key(|&index| {
+            (
+                key_name(index).is_none(),
+                key_name(index),
+                scene.entities[index].entity,
+            )
+        });
+
+        let mut entity_map = EntityHashMap::default();
+        let mut keyed_entities = HashMap::<Entity, usize>::default();
+        let mut keys = Vec::<String>::with_capacity(order.len());
+        for &index in &order {
+            let (entity, key) = match key_name(index) {
+                Some(name) => (named_entity(name), name.to_string()),
+                None => {
+                    let id = scene.entities[index].entity.index();
+                    (Entity::from_raw(id), format!("#{id}"))
+                }
+            };
+            if let Some(&other) = keyed_entities.get(&entity) {
+                return Err(format!(
+                    "entities `{}` and `{key}` have the same stable id, \
+                    rename one of the entities",
+                    keys[other]
+                ));
+            }
+            keyed_entities.insert(entity, keys.len());
+            entity_map.insert(scene.entities[index].entity, entity);
+            keys.push(key);
+        }
+
+        let mut mapper = ReadableEntityMapper(&entity_map);
+        let mut map_entities = |value: &dyn PartialReflect| {
+            let mut value = value.clone_value();
+            if let Some(map_entities) = value
+                .get_represented_type_info()
+                .and_then(|type_info| registry.get(type_info.type_id()))
+                .and_then(|registration| registration.data::<ReflectMapEntities>())
+            {
+                map_entities.map_entities(value.as_partial_reflect_mut(), &mut mapper);
+            }
+            value
+        };
+
+        let entities = order
+            .iter()
+            .map(|&index| {
+                let scene_entity = &scene.entities[index];
+                let named = key_name(index).is_some();
+                DynamicEntity {
+                    entity: entity_map[&scene_entity.entity],
+                    components: scene_entity
+                        .components
+                        .iter()
+                        // The name of named entities is written as their key.
+                        .filter(|component| !(named && component.represents::<Name>()))
+                        .map(|component| map_entities(component.as_partial_reflect()))
+                        .collect(),
+                }
+            })
+            .collect();
+        let resources = scene
+            .resources
+            .iter()
+            .map(|resource| map_entities(resource.as_partial_reflect()))
+            .collect();
+        let prefabs = scene
+            .prefabs
+            .iter()
+            .map(|prefab| DynamicPrefab {
+                scene: prefab.scene.clone(),
+                path: prefab.path.clone(),
+                parent: prefab.parent.map(|parent| mapper.map_entity(parent)),
+                overrides: prefab.overrides.clone(),
+            })
+            .collect();
+
+        Ok(ReadableScene {
+            scene: DynamicScene {
+                resources,
+                entities,
+                prefabs,
+            },
+            keys,
+        })
+    }
+}
+
+/// Returns `true` if an entity with this name can be keyed by its name in the readable format,
+/// without being mistaken for an id.
+fn is_entity_key_name(name: &str) -> bool {
+    !name.is_empty() && !name.starts_with('#') && name.parse::<u64>().is_err()
+}
+
+/// Returns the stable id of the entity with the given name in the readable format.
+///
+/// The id is derived from a FNV-1a hash of the name, so that it is the same on every platform
+/// and doesn't depend on the other entities of the scene. Its index is always at least `2^31`.
+fn named_entity(name: &str) -> Entity {
+    let hash = name.bytes().fold(0x811c_9dc5u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    Entity::from_raw(0x8000_0000 | (hash % 0x7fff_ffff))
+}
+
+struct ReadableEntityMapper<'a>(&'a EntityHashMap<Entity>);
+
+impl EntityMapper for ReadableEntityMapper<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+/// Serializes entities keyed by their key in the readable format.
+struct ReadableEntitiesSerializer<'a> {
+    entities: &'a [DynamicEntity],
+    keys: &'a [String],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ReadableEntitiesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.entities.len()))?;
+        for (entity, key) in self.entities.iter().zip(self.keys) {
+            state.serialize_entry(
+                key,
+                &ReadableEntitySerializer {
+                    entity,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+struct ReadableEntitySerializer<'a> {
+    entity: &'a DynamicEntity,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ReadableEntitySerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(ENTITY_STRUCT, 1)?;
+        state.serialize_field(
+            ENTITY_FIELD_COMPONENTS,
+            &ReadableMapSerializer {
+                entries: &self.entity.components,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+/// Serializes values sorted by type path, like [`SceneMapSerializer`], omitting their fields
+/// that are equal to their default value.
+struct ReadableMapSerializer<'a> {
+    entries: &'a [Box<dyn PartialReflect>],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ReadableMapSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.get_represented_type_info().unwrap().type_path(),
+                    entry.as_partial_reflect(),
+                )
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|(type_path, _partial_reflect)| *type_path);
+
+        let mut state = serializer.serialize_map(Some(entries.len()))?;
+        for (type_path, value) in entries {
+            state.serialize_entry(
+                type_path,
+                &WithoutDefaultFieldsSerializer {
+                    value,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes a struct without the fields that are equal to the [`ReflectDefault`] value of its
+/// type. Other values, and types serialized with serde, are serialized in full.
+struct WithoutDefaultFieldsSerializer<'a> {
+    value: &'a dyn PartialReflect,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for WithoutDefaultFieldsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Some((registration, default)) = self
+            .value
+            .get_represented_type_info()
+            .and_then(|type_info| self.registry.get(type_info.type_id()))
+            .filter(|registration| registration.data::<ReflectSerialize>().is_none())
+            .and_then(|registration| {
+                Some((
+                    registration,
+                    registration.data::<ReflectDefault>()?.default(),
+                ))
+            })
+        else {
+            return TypedReflectSerializer::new(self.value, self.registry).serialize(serializer);
+        };
+        let (TypeInfo::Struct(struct_info), ReflectRef::Struct(value), ReflectRef::Struct(default)) = (
+            registration.type_info(),
+            self.value.reflect_ref(),
+            default.reflect_ref(),
+        ) else {
+            return TypedReflectSerializer::new(self.value, self.registry).serialize(serializer);
+        };
+
+        let serialization_data = registration.data::<SerializationData>();
+        let fields = struct_info
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                !serialization_data.is_some_and(|data| data.is_field_skipped(*index))
+            })
+            .filter_map(|(_, field)| {
+                let value = value.field(field.name())?;
+                let is_default = default
+                    .field(field.name())
+                    .and_then(|default| value.reflect_partial_eq(default))
+                    .unwrap_or(false);
+                (!is_default).then_some((field.name(), value))
+            })
+            .collect::<Vec<_>>();
+
+        let mut state = serializer
+            .serialize_struct(struct_info.type_path_table().ident().unwrap(), fields.len())?;
+        for (name, value) in fields {
+            state.serialize_field(name, &TypedReflectSerializer::new(value, self.registry))?;
+        }
         state.end()
     }
 }
@@ -163,103 +597,670 @@ pub struct SceneMapSerializer<'a> {
     pub registry: &'a TypeRegistry,
 }
 
-impl<'a> Serialize for SceneMapSerializer<'a> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+impl<'a> Serialize for SceneMapSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.entries.len()))?;
+        let sorted_entries = {
+            let mut entries = self
+                .entries
+                .iter()
+                .map(|entry| {
+                    (
+                        entry.get_represented_type_info().unwrap().type_path(),
+                        entry.as_partial_reflect(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            entries.sort_by_key(|(type_path, _partial_reflect)| *type_path);
+            entries
+        };
+
+        for (type_path, partial_reflect) in sorted_entries {
+            state.serialize_entry(
+                type_path,
+                &TypedReflectSerializer::new(partial_reflect, self.registry),
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Handles serialization of the prefabs nested in a scene as a list.
+pub struct PrefabsSerializer<'a> {
+    /// The prefabs to serialize.
+    pub prefabs: &'a [DynamicPrefab],
+    /// Type registry in which the types of the override values of the prefabs are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for PrefabsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.prefabs.len()))?;
+        for prefab in self.prefabs {
+            state.serialize_element(&PrefabSerializer {
+                prefab,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+/// Handles serialization of a prefab as the path of its scene, its parent entity and its overrides.
+pub struct PrefabSerializer<'a> {
+    /// The prefab to serialize.
+    pub prefab: &'a DynamicPrefab,
+    /// Type registry in which the types of the override values of the prefab are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for PrefabSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let path = self
+            .prefab
+            .path
+            .as_ref()
+            .or_else(|| self.prefab.scene.path())
+            .ok_or_else(|| ser::Error::custom("prefab scene does not have an asset path"))?;
+
+        let mut state = serializer.serialize_struct(PREFAB_STRUCT, 3)?;
+        state.serialize_field(PREFAB_FIELD_SCENE, path)?;
+        state.serialize_field(PREFAB_FIELD_PARENT, &self.prefab.parent)?;
+        state.serialize_field(
+            PREFAB_FIELD_OVERRIDES,
+            &PrefabOverridesSerializer {
+                overrides: &self.prefab.overrides,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+/// Handles serialization of the overrides of a prefab as a list.
+pub struct PrefabOverridesSerializer<'a> {
+    /// The overrides to serialize.
+    pub overrides: &'a [PrefabOverride],
+    /// Type registry in which the types of the override values are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for PrefabOverridesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.overrides.len()))?;
+        for prefab_override in self.overrides {
+            state.serialize_element(&PrefabOverrideSerializer {
+                prefab_override,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+/// Handles serialization of a prefab override, with its value serialized along with its type path.
+pub struct PrefabOverrideSerializer<'a> {
+    /// The override to serialize.
+    pub prefab_override: &'a PrefabOverride,
+    /// Type registry in which the type of the override value is registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for PrefabOverrideSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(OVERRIDE_STRUCT, 4)?;
+        state.serialize_field(OVERRIDE_FIELD_ENTITY, &self.prefab_override.entity)?;
+        state.serialize_field(OVERRIDE_FIELD_COMPONENT, &self.prefab_override.component)?;
+        state.serialize_field(OVERRIDE_FIELD_PATH, &self.prefab_override.path)?;
+        state.serialize_field(
+            OVERRIDE_FIELD_VALUE,
+            &ReflectSerializer::new(self.prefab_override.value.as_ref(), self.registry),
+        )?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum SceneField {
+    Resources,
+    Entities,
+    Prefabs,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum PrefabField {
+    Scene,
+    Parent,
+    Overrides,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum OverrideField {
+    Entity,
+    Component,
+    Path,
+    Value,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum EntityField {
+    Components,
+}
+
+/// Handles scene deserialization.
+pub struct SceneDeserializer<'a> {
+    /// Type registry in which the components and resources types used in the scene to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            SCENE_STRUCT,
+            &[SCENE_RESOURCES, SCENE_ENTITIES, SCENE_PREFABS],
+            SceneVisitor {
+                type_registry: self.type_registry,
+                prefabs_required: false,
+            },
+        )
+    }
+}
+
+impl<'a> SceneDeserializer<'a> {
+    /// Deserializes a scene written by a [`BinarySceneSerializer`], which must include the list
+    /// of prefabs.
+    pub(crate) fn deserialize_with_prefabs<'de, D>(
+        self,
+        deserializer: D,
+    ) -> Result<DynamicScene, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            SCENE_STRUCT,
+            &[SCENE_RESOURCES, SCENE_ENTITIES, SCENE_PREFABS],
+            SceneVisitor {
+                type_registry: self.type_registry,
+                prefabs_required: true,
+            },
+        )
+    }
+}
+
+struct SceneVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+    /// Whether a scene written as a sequence must include the list of prefabs.
+    prefabs_required: bool,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
+    type Value = DynamicScene;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("scene struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let resources = seq
+            .next_element_seed(SceneMapDeserializer {
+                registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
+
+        let entities = seq
+            .next_element_seed(SceneEntitiesDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+
+        if self.prefabs_required {
+            let prefabs = seq
+                .next_element_seed(ScenePrefabsDeserializer {
+                    type_registry: self.type_registry,
+                })?
+                .ok_or_else(|| Error::missing_field(SCENE_PREFABS))?;
+            return Ok(DynamicScene {
+                resources,
+                entities,
+                prefabs,
+            });
+        }
+
+        // The prefabs are only written if there are some. Formats which don't know how many
+        // fields were written, like bincode, fail to start reading the missing list instead of
+        // ending the sequence, which is treated like the end of the sequence.
+        let started = Cell::new(false);
+        let prefabs = match seq.next_element_seed(TrailingPrefabsDeserializer {
+            type_registry: self.type_registry,
+            started: &started,
+        }) {
+            Ok(prefabs) => prefabs.unwrap_or_default(),
+            Err(_) if !started.get() => Vec::new(),
+            Err(err) => return Err(err),
+        };
+
+        Ok(DynamicScene {
+            resources,
+            entities,
+            prefabs,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut resources = None;
+        let mut entities = None;
+        let mut prefabs = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                SceneField::Resources => {
+                    if resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_RESOURCES));
+                    }
+                    resources = Some(map.next_value_seed(SceneMapDeserializer {
+                        registry: self.type_registry,
+                    })?);
+                }
+                SceneField::Entities => {
+                    if entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_ENTITIES));
+                    }
+                    entities = Some(map.next_value_seed(SceneEntitiesDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
+                SceneField::Prefabs => {
+                    if prefabs.is_some() {
+                        return Err(Error::duplicate_field(SCENE_PREFABS));
+                    }
+                    prefabs = Some(map.next_value_seed(ScenePrefabsDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
+            }
+        }
+
+        let resources = resources.ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
+        let entities = entities.ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+
+        Ok(DynamicScene {
+            resources,
+            entities,
+            prefabs: prefabs.unwrap_or_default(),
+        })
+    }
+}
+
+/// Handles deserialization for a collection of entities.
+pub struct SceneEntitiesDeserializer<'a> {
+    /// Type registry in which the component types used by the entities to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(SceneEntitiesVisitor {
+            type_registry: self.type_registry,
+        })
+    }
+}
+
+struct SceneEntitiesVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of entities")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entities = Vec::new();
+        let mut named_entities = HashMap::<Entity, String>::default();
+        while let Some(key) = map.next_key::<EntityKey>()? {
+            let (entity, name) = match key {
+                EntityKey::Entity(entity) => (entity, None),
+                EntityKey::Name(name) => {
+                    let entity = named_entity(&name);
+                    if let Some(other) = named_entities.insert(entity, name.clone()) {
+                        return Err(Error::custom(format_args!(
+                            "entity names `{other}` and `{name}` have the same stable id, \
+                            rename one of the entities"
+                        )));
+                    }
+                    (entity, Some(name))
+                }
+            };
+            let mut entity = map.next_value_seed(SceneEntityDeserializer {
+                entity,
+                type_registry: self.type_registry,
+            })?;
+            if let Some(name) = name {
+                entity.components.push(Box::new(Name::new(name)));
+            }
+            entities.push(entity);
+        }
+
+        Ok(entities)
+    }
+}
+
+/// The key of an entity in a serialized scene: its id, or its name in the readable format.
+enum EntityKey {
+    Entity(Entity),
+    Name(String),
+}
+
+impl<'de> Deserialize<'de> for EntityKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(EntityKeyVisitor)
+        } else {
+            Entity::deserialize(deserializer).map(EntityKey::Entity)
+        }
+    }
+}
+
+struct EntityKeyVisitor;
+
+impl<'de> Visitor<'de> for EntityKeyVisitor {
+    type Value = EntityKey;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("entity id or name")
+    }
+
+    fn visit_u64<E>(self, bits: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Entity::try_from_bits(bits)
+            .map(EntityKey::Entity)
+            .map_err(Error::custom)
+    }
+
+    fn visit_i64<E>(self, bits: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let bits = u64::try_from(bits).map_err(Error::custom)?;
+        self.visit_u64(bits)
+    }
+
+    fn visit_str<E>(self, key: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        // Formats with string keys, such as JSON, write entity ids as strings.
+        if let Ok(bits) = key.parse::<u64>() {
+            return self.visit_u64(bits);
+        }
+        if let Some(id) = key.strip_prefix('#') {
+            let id = id.parse::<u32>().map_err(Error::custom)?;
+            return Ok(EntityKey::Entity(Entity::from_raw(id)));
+        }
+        Ok(EntityKey::Name(key.to_string()))
+    }
+}
+
+/// Handle deserialization of an entity and its components.
+pub struct SceneEntityDeserializer<'a> {
+    /// Id of the deserialized entity.
+    pub entity: Entity,
+    /// Type registry in which the component types used by the entity to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
+    type Value = DynamicEntity;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            ENTITY_STRUCT,
+            &[ENTITY_FIELD_COMPONENTS],
+            SceneEntityVisitor {
+                entity: self.entity,
+                registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct SceneEntityVisitor<'a> {
+    pub entity: Entity,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
+    type Value = DynamicEntity;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("entities")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let components = seq
+            .next_element_seed(SceneMapDeserializer {
+                registry: self.registry,
+            })?
+            .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+
+        Ok(DynamicEntity {
+            entity: self.entity,
+            components,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut components = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                EntityField::Components => {
+                    if components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
+                    }
+
+                    components = Some(map.next_value_seed(SceneMapDeserializer {
+                        registry: self.registry,
+                    })?);
+                }
+            }
+        }
+
+        let components = components
+            .take()
+            .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+        Ok(DynamicEntity {
+            entity: self.entity,
+            components,
+        })
+    }
+}
+
+/// Handles deserialization of the prefabs nested in a scene.
+///
+/// The [`scene`](DynamicPrefab::scene) of the deserialized prefabs is a default handle, the
+/// scene should be loaded from their [`path`](DynamicPrefab::path).
+pub struct ScenePrefabsDeserializer<'a> {
+    /// Type registry in which the types of the override values of the prefabs are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ScenePrefabsDeserializer<'a> {
+    type Value = Vec<DynamicPrefab>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
-        S: Serializer,
+        D: Deserializer<'de>,
     {
-        let mut state = serializer.serialize_map(Some(self.entries.len()))?;
-        let sorted_entries = {
-            let mut entries = self
-                .entries
-                .iter()
-                .map(|entry| {
-                    (
-                        entry.get_represented_type_info().unwrap().type_path(),
-                        entry.as_partial_reflect(),
-                    )
-                })
-                .collect::<Vec<_>>();
-            entries.sort_by_key(|(type_path, _partial_reflect)| *type_path);
-            entries
-        };
+        deserializer.deserialize_seq(ScenePrefabsVisitor {
+            type_registry: self.type_registry,
+            started: None,
+        })
+    }
+}
 
-        for (type_path, partial_reflect) in sorted_entries {
-            state.serialize_entry(
-                type_path,
-                &TypedReflectSerializer::new(partial_reflect, self.registry),
-            )?;
-        }
-        state.end()
+/// Handles deserialization of the optional prefabs at the end of a scene written as a sequence,
+/// recording whether reading the list of prefabs started.
+struct TrailingPrefabsDeserializer<'a> {
+    type_registry: &'a TypeRegistry,
+    started: &'a Cell<bool>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for TrailingPrefabsDeserializer<'a> {
+    type Value = Vec<DynamicPrefab>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ScenePrefabsVisitor {
+            type_registry: self.type_registry,
+            started: Some(self.started),
+        })
     }
 }
 
-#[derive(Deserialize)]
-#[serde(field_identifier, rename_all = "lowercase")]
-enum SceneField {
-    Resources,
-    Entities,
+struct ScenePrefabsVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+    started: Option<&'a Cell<bool>>,
 }
 
-#[derive(Deserialize)]
-#[serde(field_identifier, rename_all = "lowercase")]
-enum EntityField {
-    Components,
+impl<'a, 'de> Visitor<'de> for ScenePrefabsVisitor<'a> {
+    type Value = Vec<DynamicPrefab>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("list of prefabs")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        if let Some(started) = self.started {
+            started.set(true);
+        }
+        let mut prefabs = Vec::new();
+        while let Some(prefab) = seq.next_element_seed(ScenePrefabDeserializer {
+            type_registry: self.type_registry,
+        })? {
+            prefabs.push(prefab);
+        }
+
+        Ok(prefabs)
+    }
 }
 
-/// Handles scene deserialization.
-pub struct SceneDeserializer<'a> {
-    /// Type registry in which the components and resources types used in the scene to deserialize are registered.
+/// Handles deserialization of a prefab nested in a scene.
+pub struct ScenePrefabDeserializer<'a> {
+    /// Type registry in which the types of the override values of the prefab are registered.
     pub type_registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
-    type Value = DynamicScene;
+impl<'a, 'de> DeserializeSeed<'de> for ScenePrefabDeserializer<'a> {
+    type Value = DynamicPrefab;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
         deserializer.deserialize_struct(
-            SCENE_STRUCT,
-            &[SCENE_RESOURCES, SCENE_ENTITIES],
-            SceneVisitor {
+            PREFAB_STRUCT,
+            &[
+                PREFAB_FIELD_SCENE,
+                PREFAB_FIELD_PARENT,
+                PREFAB_FIELD_OVERRIDES,
+            ],
+            ScenePrefabVisitor {
                 type_registry: self.type_registry,
             },
         )
     }
 }
 
-struct SceneVisitor<'a> {
+struct ScenePrefabVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
-    type Value = DynamicScene;
+impl<'a, 'de> Visitor<'de> for ScenePrefabVisitor<'a> {
+    type Value = DynamicPrefab;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
-        formatter.write_str("scene struct")
+        formatter.write_str("prefab struct")
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
-        let resources = seq
-            .next_element_seed(SceneMapDeserializer {
-                registry: self.type_registry,
-            })?
-            .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
-
-        let entities = seq
-            .next_element_seed(SceneEntitiesDeserializer {
+        let path = seq
+            .next_element::<AssetPath<'static>>()?
+            .ok_or_else(|| Error::missing_field(PREFAB_FIELD_SCENE))?;
+        let parent = seq
+            .next_element::<Option<Entity>>()?
+            .ok_or_else(|| Error::missing_field(PREFAB_FIELD_PARENT))?;
+        let overrides = seq
+            .next_element_seed(PrefabOverridesDeserializer {
                 type_registry: self.type_registry,
             })?
-            .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+            .ok_or_else(|| Error::missing_field(PREFAB_FIELD_OVERRIDES))?;
 
-        Ok(DynamicScene {
-            resources,
-            entities,
+        Ok(DynamicPrefab {
+            scene: Handle::default(),
+            path: Some(path),
+            parent,
+            overrides,
         })
     }
 
@@ -267,137 +1268,163 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
     where
         A: MapAccess<'de>,
     {
-        let mut resources = None;
-        let mut entities = None;
+        let mut path = None;
+        let mut parent = None;
+        let mut overrides = None;
         while let Some(key) = map.next_key()? {
             match key {
-                SceneField::Resources => {
-                    if resources.is_some() {
-                        return Err(Error::duplicate_field(SCENE_RESOURCES));
+                PrefabField::Scene => {
+                    if path.is_some() {
+                        return Err(Error::duplicate_field(PREFAB_FIELD_SCENE));
                     }
-                    resources = Some(map.next_value_seed(SceneMapDeserializer {
-                        registry: self.type_registry,
-                    })?);
+                    path = Some(map.next_value::<AssetPath<'static>>()?);
                 }
-                SceneField::Entities => {
-                    if entities.is_some() {
-                        return Err(Error::duplicate_field(SCENE_ENTITIES));
+                PrefabField::Parent => {
+                    if parent.is_some() {
+                        return Err(Error::duplicate_field(PREFAB_FIELD_PARENT));
                     }
-                    entities = Some(map.next_value_seed(SceneEntitiesDeserializer {
+                    parent = Some(map.next_value::<Option<Entity>>()?);
+                }
+                PrefabField::Overrides => {
+                    if overrides.is_some() {
+                        return Err(Error::duplicate_field(PREFAB_FIELD_OVERRIDES));
+                    }
+                    overrides = Some(map.next_value_seed(PrefabOverridesDeserializer {
                         type_registry: self.type_registry,
                     })?);
                 }
             }
         }
 
-        let resources = resources.ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
-        let entities = entities.ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
-
-        Ok(DynamicScene {
-            resources,
-            entities,
+        let path = path.ok_or_else(|| Error::missing_field(PREFAB_FIELD_SCENE))?;
+        Ok(DynamicPrefab {
+            scene: Handle::default(),
+            path: Some(path),
+            parent: parent.flatten(),
+            overrides: overrides.unwrap_or_default(),
         })
     }
 }
 
-/// Handles deserialization for a collection of entities.
-pub struct SceneEntitiesDeserializer<'a> {
-    /// Type registry in which the component types used by the entities to deserialize are registered.
+/// Handles deserialization of the overrides of a prefab.
+pub struct PrefabOverridesDeserializer<'a> {
+    /// Type registry in which the types of the override values are registered.
     pub type_registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
-    type Value = Vec<DynamicEntity>;
+impl<'a, 'de> DeserializeSeed<'de> for PrefabOverridesDeserializer<'a> {
+    type Value = Vec<PrefabOverride>;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_map(SceneEntitiesVisitor {
+        deserializer.deserialize_seq(PrefabOverridesVisitor {
             type_registry: self.type_registry,
         })
     }
 }
 
-struct SceneEntitiesVisitor<'a> {
+struct PrefabOverridesVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
-    type Value = Vec<DynamicEntity>;
+impl<'a, 'de> Visitor<'de> for PrefabOverridesVisitor<'a> {
+    type Value = Vec<PrefabOverride>;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
-        formatter.write_str("map of entities")
+        formatter.write_str("list of prefab overrides")
     }
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
-        A: MapAccess<'de>,
+        A: SeqAccess<'de>,
     {
-        let mut entities = Vec::new();
-        while let Some(entity) = map.next_key::<Entity>()? {
-            let entity = map.next_value_seed(SceneEntityDeserializer {
-                entity,
-                type_registry: self.type_registry,
-            })?;
-            entities.push(entity);
+        let mut overrides = Vec::new();
+        while let Some(prefab_override) = seq.next_element_seed(PrefabOverrideDeserializer {
+            type_registry: self.type_registry,
+        })? {
+            overrides.push(prefab_override);
         }
 
-        Ok(entities)
+        Ok(overrides)
     }
 }
 
-/// Handle deserialization of an entity and its components.
-pub struct SceneEntityDeserializer<'a> {
-    /// Id of the deserialized entity.
-    pub entity: Entity,
-    /// Type registry in which the component types used by the entity to deserialize are registered.
+/// Handles deserialization of a prefab override.
+pub struct PrefabOverrideDeserializer<'a> {
+    /// Type registry in which the type of the override value is registered.
     pub type_registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
-    type Value = DynamicEntity;
+impl<'a, 'de> DeserializeSeed<'de> for PrefabOverrideDeserializer<'a> {
+    type Value = PrefabOverride;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
         deserializer.deserialize_struct(
-            ENTITY_STRUCT,
-            &[ENTITY_FIELD_COMPONENTS],
-            SceneEntityVisitor {
-                entity: self.entity,
-                registry: self.type_registry,
+            OVERRIDE_STRUCT,
+            &[
+                OVERRIDE_FIELD_ENTITY,
+                OVERRIDE_FIELD_COMPONENT,
+                OVERRIDE_FIELD_PATH,
+                OVERRIDE_FIELD_VALUE,
+            ],
+            PrefabOverrideVisitor {
+                type_registry: self.type_registry,
             },
         )
     }
 }
 
-struct SceneEntityVisitor<'a> {
-    pub entity: Entity,
-    pub registry: &'a TypeRegistry,
+struct PrefabOverrideVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
-    type Value = DynamicEntity;
+impl<'a> PrefabOverrideVisitor<'a> {
+    /// Attempts to convert the deserialized value to its concrete type using `FromReflect`.
+    fn convert_value(&self, value: Box<dyn PartialReflect>) -> Box<dyn PartialReflect> {
+        value
+            .get_represented_type_info()
+            .and_then(|type_info| self.type_registry.get(type_info.type_id()))
+            .and_then(|registration| registration.data::<ReflectFromReflect>())
+            .and_then(|fr| fr.from_reflect(value.as_partial_reflect()))
+            .map(PartialReflect::into_partial_reflect)
+            .unwrap_or(value)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for PrefabOverrideVisitor<'a> {
+    type Value = PrefabOverride;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
-        formatter.write_str("entities")
+        formatter.write_str("prefab override struct")
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
-        let components = seq
-            .next_element_seed(SceneMapDeserializer {
-                registry: self.registry,
-            })?
-            .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
-
-        Ok(DynamicEntity {
-            entity: self.entity,
-            components,
+        let entity = seq
+            .next_element::<Entity>()?
+            .ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_ENTITY))?;
+        let component = seq
+            .next_element::<String>()?
+            .ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_COMPONENT))?;
+        let path = seq
+            .next_element::<String>()?
+            .ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_PATH))?;
+        let value = seq
+            .next_element_seed(ReflectDeserializer::new(self.type_registry))?
+            .ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_VALUE))?;
+
+        Ok(PrefabOverride {
+            entity,
+            component,
+            path,
+            value: self.convert_value(value),
         })
     }
 
@@ -405,27 +1432,48 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
     where
         A: MapAccess<'de>,
     {
-        let mut components = None;
+        let mut entity = None;
+        let mut component = None;
+        let mut path = None;
+        let mut value = None;
         while let Some(key) = map.next_key()? {
             match key {
-                EntityField::Components => {
-                    if components.is_some() {
-                        return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
+                OverrideField::Entity => {
+                    if entity.is_some() {
+                        return Err(Error::duplicate_field(OVERRIDE_FIELD_ENTITY));
                     }
-
-                    components = Some(map.next_value_seed(SceneMapDeserializer {
-                        registry: self.registry,
-                    })?);
+                    entity = Some(map.next_value::<Entity>()?);
+                }
+                OverrideField::Component => {
+                    if component.is_some() {
+                        return Err(Error::duplicate_field(OVERRIDE_FIELD_COMPONENT));
+                    }
+                    component = Some(map.next_value::<String>()?);
+                }
+                OverrideField::Path => {
+                    if path.is_some() {
+                        return Err(Error::duplicate_field(OVERRIDE_FIELD_PATH));
+                    }
+                    path = Some(map.next_value::<String>()?);
+                }
+                OverrideField::Value => {
+                    if value.is_some() {
+                        return Err(Error::duplicate_field(OVERRIDE_FIELD_VALUE));
+                    }
+                    value =
+                        Some(map.next_value_seed(ReflectDeserializer::new(self.type_registry))?);
                 }
             }
         }
 
-        let components = components
-            .take()
-            .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
-        Ok(DynamicEntity {
-            entity: self.entity,
-            components,
+        let entity = entity.ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_ENTITY))?;
+        let component = component.ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_COMPONENT))?;
+        let value = value.ok_or_else(|| Error::missing_field(OVERRIDE_FIELD_VALUE))?;
+        Ok(PrefabOverride {
+            entity,
+            component,
+            path: path.unwrap_or_default(),
+            value: self.convert_value(value),
         })
     }
 }
@@ -491,12 +1539,16 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
             let value =
                 map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
 
-            // Attempt to convert using FromReflect.
-            let value = self
-                .registry
-                .get(registration.type_id())
-                .and_then(|tr| tr.data::<ReflectFromReflect>())
+            // Attempt to convert using FromReflect, filling in the fields omitted by the readable
+            // format with their default value if needed.
+            let value = registration
+                .data::<ReflectFromReflect>()
                 .and_then(|fr| fr.from_reflect(value.as_partial_reflect()))
+                .or_else(|| {
+                    let mut default = registration.data::<ReflectDefault>()?.default();
+                    default.try_apply(value.as_partial_reflect()).ok()?;
+                    Some(default)
+                })
                 .map(PartialReflect::into_partial_reflect)
                 .unwrap_or(value);
 
@@ -512,8 +1564,10 @@ mod tests {
     use crate::{
         ron,
         serde::{SceneDeserializer, SceneSerializer},
-        DynamicScene, DynamicSceneBuilder,
+        DynamicPrefab, DynamicScene, DynamicSceneBuilder, PrefabOverride,
     };
+    use bevy_asset::Handle;
+    use bevy_core::Name;
     use bevy_ecs::{
         entity::{Entity, EntityHashMap, VisitEntities, VisitEntitiesMut},
         prelude::{Component, ReflectComponent, ReflectResource, Resource, World},
@@ -521,7 +1575,7 @@ mod tests {
         reflect::{AppTypeRegistry, ReflectMapEntities},
         world::FromWorld,
     };
-    use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
+    use bevy_reflect::{prelude::ReflectDefault, Reflect, ReflectDeserialize, ReflectSerialize};
     use bincode::Options;
     use serde::{de::DeserializeSeed, Deserialize, Serialize};
     use std::io::BufReader;
@@ -665,6 +1719,206 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component, Default, PartialEq)]
+    struct Stats {
+        health: u32,
+        speed: u32,
+    }
+
+    #[test]
+    fn should_roundtrip_readable() {
+        let mut world = create_world();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Stats>();
+            registry.register::<Name>();
+        }
+
+        let player = world
+            .spawn((
+                Name::new("Player"),
+                Stats {
+                    health: 10,
+                    speed: 0,
+                },
+            ))
+            .id();
+        world.spawn(MyEntityRef(player));
+        world.spawn((Name::new("Spawn"), Stats::default()));
+
+        let scene = DynamicScene::from_world(&world);
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let output = scene.serialize_readable(&registry).unwrap();
+        let expected = r##"(
+  resources: {},
+  entities: {
+    "Player": (
+      components: {
+        "bevy_scene::serde::tests::Stats": (
+          health: 10,
+        ),
+      },
+    ),
+    "Spawn": (
+      components: {
+        "bevy_scene::serde::tests::Stats": (),
+      },
+    ),
+    "#1": (
+      components: {
+        "bevy_scene::serde::tests::MyEntityRef": (6919617568),
+      },
+    ),
+  },
+)"##;
+        assert_eq!(expected, output);
+
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry,
+        };
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+
+        let mut dst_world = create_world();
+        dst_world.insert_resource(world.resource::<AppTypeRegistry>().clone());
+        scene
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+
+        let (player, stats) = dst_world
+            .query::<(Entity, &Name, &Stats)>()
+            .iter(&dst_world)
+            .find(|(_, name, _)| name.as_str() == "Player")
+            .map(|(entity, _, stats)| (entity, stats))
+            .unwrap();
+        assert_eq!(
+            &Stats {
+                health: 10,
+                speed: 0
+            },
+            stats
+        );
+        assert_eq!(
+            player,
+            dst_world.query::<&MyEntityRef>().single(&dst_world).0,
+            "entity references should be mapped to the named entity"
+        );
+        assert_eq!(2, dst_world.query::<&Stats>().iter(&dst_world).len());
+    }
+
+    #[test]
+    fn readable_keys_are_stable() {
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Stats>();
+        world.spawn(Stats::default());
+        world.spawn(Stats {
+            health: 1,
+            speed: 0,
+        });
+
+        let mut scene = DynamicScene::from_world(&world);
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let output = scene.serialize_readable(&registry).unwrap();
+        assert!(output.contains("\"#0\""));
+        assert!(output.contains("\"#1\""));
+
+        // Removing an entity doesn't change the key of the other ones.
+        scene.entities.remove(0);
+        let output = scene.serialize_readable(&registry).unwrap();
+        assert!(!output.contains("\"#0\""));
+        assert!(output.contains("\"#1\""));
+    }
+
+    #[test]
+    fn should_roundtrip_prefabs() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let scene = DynamicScene {
+            prefabs: vec![DynamicPrefab {
+                scene: Handle::default(),
+                path: Some("prefabs/tree.scn.ron".into()),
+                parent: Some(Entity::from_raw(1)),
+                overrides: vec![PrefabOverride::new::<Foo>(
+                    Entity::from_raw(0),
+                    ".0",
+                    Box::new(7i32),
+                )],
+            }],
+            ..Default::default()
+        };
+
+        let expected = r#"(
+  resources: {},
+  entities: {},
+  prefabs: [
+    (
+      scene: "prefabs/tree.scn.ron",
+      parent: Some(4294967297),
+      overrides: [
+        (
+          entity: 4294967296,
+          component: "bevy_scene::serde::tests::Foo",
+          path: ".0",
+          value: {
+            "i32": 7,
+          },
+        ),
+      ],
+    ),
+  ],
+)"#;
+        let output = scene.serialize(&registry).unwrap();
+        assert_eq!(expected, output);
+
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry,
+        };
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let ron_scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+
+        // binary formats write the prefabs after the entities
+        let scene_serializer = SceneSerializer::new(&scene, &registry);
+        let bincode_scene = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(
+                SceneDeserializer {
+                    type_registry: &registry,
+                },
+                &bincode::serialize(&scene_serializer).unwrap(),
+            )
+            .unwrap();
+        let postcard_scene = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut postcard::Deserializer::from_bytes(
+            &postcard::to_allocvec(&scene_serializer).unwrap(),
+        ))
+        .unwrap();
+
+        for scene in [ron_scene, bincode_scene, postcard_scene] {
+            let [prefab] = scene.prefabs.as_slice() else {
+                panic!("expected a single prefab");
+            };
+            assert_eq!(Some("prefabs/tree.scn.ron".into()), prefab.path);
+            assert_eq!(Some(Entity::from_raw(1)), prefab.parent);
+            let [prefab_override] = prefab.overrides.as_slice() else {
+                panic!("expected a single override");
+            };
+            assert_eq!(Entity::from_raw(0), prefab_override.entity);
+            assert_eq!(".0", prefab_override.path);
+            assert_eq!(
+                Some(&7),
+                prefab_override.value.try_downcast_ref::<i32>(),
+                "override value should be converted to its concrete type"
+            );
+        }
+    }
+
     #[test]
     fn should_deserialize() {
         let world = create_world();
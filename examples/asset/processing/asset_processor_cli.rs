@@ -0,0 +1,94 @@
+//! This example shows how to build a headless command line tool that processes assets ahead of time,
+//! for example as part of a build pipeline, using `AssetProcessorCli`.
+//!
+//! Run it with `cargo run --example asset_processor_cli --features asset_processor -- --help` to list
+//! its options. The tool exits once all assets are processed, with an error code if any of them
+//! failed, unless `--watch` is passed.
+
+use bevy::{
+    app::ScheduleRunnerPlugin,
+    asset::{
+        io::{Reader, Writer},
+        meta::AssetMeta,
+        processor::{AssetProcessorCli, Process, ProcessContext, ProcessError},
+        AssetLoader, AsyncWriteExt, LoadContext,
+    },
+    log::LogPlugin,
+    prelude::*,
+};
+
+fn main() -> AppExit {
+    let mut cli = AssetProcessorCli::from_env();
+    // This is just overriding the default paths to scope this to the correct example folder
+    // You can generally skip this in your own projects
+    cli.source_path
+        .get_or_insert_with(|| "examples/asset/processing/cli_assets".to_string());
+    cli.processed_path
+        .get_or_insert_with(|| "examples/asset/processing/cli_imported_assets/Default".to_string());
+
+    App::new()
+        .add_plugins((
+            // The processor doesn't need a window or a renderer, only a task pool and an app loop
+            MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(
+                core::time::Duration::from_millis(10),
+            )),
+            LogPlugin::default(),
+            cli.asset_plugin(),
+            cli,
+        ))
+        .init_asset::<Text>()
+        .register_asset_loader(TextLoader)
+        .register_asset_processor(UppercaseProcessor)
+        .set_default_asset_processor::<UppercaseProcessor>("txt")
+        .run()
+}
+
+#[derive(Asset, TypePath, Debug)]
+struct Text(String);
+
+#[derive(Default)]
+struct TextLoader;
+
+impl AssetLoader for TextLoader {
+    type Asset = Text;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Text, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(Text(String::from_utf8_lossy(&bytes).into_owned()))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["txt"]
+    }
+}
+
+/// Converts text files to uppercase.
+struct UppercaseProcessor;
+
+impl Process for UppercaseProcessor {
+    type Settings = ();
+    type OutputLoader = TextLoader;
+
+    async fn process(
+        &self,
+        context: &mut ProcessContext<'_>,
+        _meta: AssetMeta<(), Self>,
+        writer: &mut Writer,
+    ) -> Result<(), ProcessError> {
+        let text = core::str::from_utf8(context.asset_bytes())
+            .map_err(|err| ProcessError::AssetTransformError(err.into()))?;
+        writer
+            .write_all(text.to_uppercase().as_bytes())
+            .await
+            .map_err(|err| ProcessError::AssetSaveError(err.into()))?;
+        Ok(())
+    }
+}